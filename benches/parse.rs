@@ -0,0 +1,53 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Benchmarks of `from_str` by the size of the boarding pass, and of the nom parser behind `from_str`
+//! against the hand-written scanner behind `BcbpFixed`.
+
+extern crate criterion;
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use iata_bcbp::*;
+
+/// The IATA 792B two-leg example, with conditional data in both legs and security data.
+const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+
+/// Boarding passes of increasing size, named for the benchmark.
+const PASS_STRS: &[(&str, &str)] = &[
+    ("mandatory", "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100"),
+    ("conditional", "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010"),
+    ("two_legs_signed", PASS_STR),
+];
+
+fn from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    for &(name, pass_str) in PASS_STRS {
+        group.throughput(Throughput::Bytes(pass_str.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), pass_str, |b, pass_str| {
+            b.iter(|| Bcbp::from_str(black_box(pass_str)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn backends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backend");
+    group.bench_function(BenchmarkId::new("nom", "from_str"), |b| {
+        b.iter(|| Bcbp::from_str(black_box(PASS_STR)).unwrap())
+    });
+    group.bench_function(BenchmarkId::new("fixed", "parse"), |b| {
+        b.iter(|| BcbpFixed::<4>::parse(black_box(PASS_STR)).unwrap())
+    });
+    group.bench_function(BenchmarkId::new("fixed", "to_bcbp"), |b| {
+        b.iter(|| BcbpFixed::<4>::parse(black_box(PASS_STR)).unwrap().to_bcbp())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_str, backends);
+criterion_main!(benches);
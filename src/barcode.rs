@@ -0,0 +1,29 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use qrcode::render::svg;
+use qrcode::{EcLevel, QrCode};
+
+use crate::bcbp::Bcbp;
+use crate::error::{Error, Result};
+use crate::ser;
+
+/// Minimum width and height of a rendered barcode, in pixels.
+const MIN_DIMENSION: u32 = 200;
+
+impl Bcbp {
+    /// Encodes the boarding pass and renders it as a QR code, returned as an SVG document.
+    ///
+    /// Fails with `Error::CapacityExceeded` if the encoded pass does not fit within a QR code.
+    pub fn to_qr(&self) -> Result<String> {
+        let encoded = ser::to_string(self)?;
+        let code = QrCode::with_error_correction_level(encoded.as_bytes(), EcLevel::L)
+            .map_err(|_| Error::CapacityExceeded)?;
+        Ok(code
+            .render::<svg::Color>()
+            .min_dimensions(MIN_DIMENSION, MIN_DIMENSION)
+            .build())
+    }
+}
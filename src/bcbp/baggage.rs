@@ -0,0 +1,130 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt;
+
+use crate::bcbp::Bcbp;
+use crate::fixed_string::FixedString;
+
+/// A single 10-digit baggage tag number.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BaggageTagNumber {
+    digits: FixedString<10>,
+}
+
+impl BaggageTagNumber {
+    /// Returns the tag number if `digits` consists of exactly 10 ASCII digits.
+    fn new(digits: &str) -> Option<Self> {
+        if digits.len() != 10 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        FixedString::from(digits).ok().map(|digits| BaggageTagNumber { digits })
+    }
+
+    /// The tag number as a 10-digit string.
+    pub fn as_str(&self) -> &str {
+        &self.digits
+    }
+
+    /// '0' for an interline tag, '1' for a fall-back tag, '2' for an interline rush tag.
+    pub fn tag_type(&self) -> char {
+        char::from(self.digits.as_bytes()[0])
+    }
+
+    /// The three-digit numeric code of the carrier which issued the tag.
+    pub fn carrier_numeric_code(&self) -> &str {
+        &self.digits[1 .. 4]
+    }
+
+    /// The six-digit serial number of the tag, with leading zeroes.
+    pub fn serial_number(&self) -> &str {
+        &self.digits[4 ..]
+    }
+}
+
+impl fmt::Display for BaggageTagNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.digits)
+    }
+}
+
+/// A run of consecutive baggage tag numbers, encoded in a 13-character baggage tag license plate field.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BaggageTag {
+    first: BaggageTagNumber,
+    serials: (u32, u32),
+}
+
+impl BaggageTag {
+    /// Parses a baggage tag license plate field, returning `None` if it is blank or malformed.
+    ///
+    /// A count of zero is treated as a single tag, and the run ends no later than the largest
+    /// six-digit serial number.
+    fn new(field: &str) -> Option<Self> {
+        let first = field.get(.. 10).and_then(BaggageTagNumber::new)?;
+        let count: u32 = field.get(10 .. 13)?.parse().ok()?;
+        let serial: u32 = first.serial_number().parse().ok()?;
+        let last = (serial + count.max(1) - 1).min(999_999);
+        Some(BaggageTag { first, serials: (serial, last) })
+    }
+
+    /// Returns the tag number with the serial number `serial` in the run.
+    fn tag_number(&self, serial: u32) -> BaggageTagNumber {
+        let digits = format!("{}{:06}", &self.first.as_str()[.. 4], serial);
+        BaggageTagNumber::new(&digits).unwrap()
+    }
+
+    /// '0' for an interline tag, '1' for a fall-back tag, '2' for an interline rush tag.
+    pub fn tag_type(&self) -> char {
+        self.first.tag_type()
+    }
+
+    /// The three-digit numeric code of the carrier which issued the tags.
+    pub fn carrier_numeric_code(&self) -> &str {
+        self.first.carrier_numeric_code()
+    }
+
+    /// The first tag number in the run.
+    pub fn first_tag_number(&self) -> BaggageTagNumber {
+        self.first
+    }
+
+    /// The last tag number in the run, applying the count of consecutive bags.
+    pub fn last_tag_number(&self) -> BaggageTagNumber {
+        self.tag_number(self.serials.1)
+    }
+
+    /// The number of tags in the run, always at least one.
+    pub fn tag_count(&self) -> usize {
+        (self.serials.1 - self.serials.0 + 1) as usize
+    }
+
+    /// Iterates over each tag number in the run, in order.
+    pub fn tag_numbers(&self) -> impl Iterator<Item = BaggageTagNumber> {
+        let tag = *self;
+        (self.serials.0 ..= self.serials.1).map(move |serial| tag.tag_number(serial))
+    }
+}
+
+impl Bcbp {
+    /// Iterates over each run of baggage tags on the boarding pass.
+    ///
+    /// The baggage tag license plate numbers and both non-consecutive fields are visited in order.
+    /// Blank or malformed fields are skipped.
+    pub fn baggage_tags(&self) -> impl Iterator<Item = BaggageTag> + '_ {
+        self.baggage_tag_license_plate_numbers()
+            .into_iter()
+            .chain(self.first_non_consecutive_baggage_tag_license_plate_numbers())
+            .chain(self.second_non_consecutive_baggage_tag_license_plate_numbers())
+            .filter_map(BaggageTag::new)
+    }
+
+    /// Iterates over every baggage tag number on the boarding pass.
+    ///
+    /// Each run of tags in `baggage_tags` is expanded by its count of consecutive bags.
+    pub fn all_baggage_tags(&self) -> impl Iterator<Item = BaggageTagNumber> + '_ {
+        self.baggage_tags().flat_map(|tag| tag.tag_numbers())
+    }
+}
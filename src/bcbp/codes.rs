@@ -0,0 +1,180 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+use crate::fixed_string::FixedString;
+
+/// Returns `value` without trailing padding if it is between `min_len` and the length of the field
+/// and every character satisfies `is_valid`, otherwise the error describing the violation.
+fn checked(field_id: Field, value: &str, min_len: usize, is_valid: fn(u8) -> bool) -> Result<&str> {
+    let value = value.trim_end_matches(' ');
+    if value.len() > field_id.len() {
+        return Err(Error::LengthOverflow(field_id));
+    }
+    if value.len() < min_len || !value.bytes().all(is_valid) {
+        return Err(Error::InvalidFieldValue(field_id));
+    }
+    Ok(value)
+}
+
+fn is_upper_alphanumeric(b: u8) -> bool {
+    b.is_ascii_uppercase() || b.is_ascii_digit()
+}
+
+/// A three-letter IATA airport or city code, e.g. `YUL`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AirportCode(FixedString<3>);
+
+impl AirportCode {
+    /// The airport code.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AirportCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value = checked(Field::FromCityAirportCode, s, 3, |b| b.is_ascii_uppercase())?;
+        Ok(AirportCode(FixedString::from(value).unwrap()))
+    }
+}
+
+/// A two-character IATA or three-letter airline designator, e.g. `AC`.
+///
+/// Two-character designators may contain a digit but not consist only of digits.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct CarrierDesignator(FixedString<3>);
+
+impl CarrierDesignator {
+    /// The airline designator without padding.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for CarrierDesignator {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let field_id = Field::OperatingCarrierDesignator;
+        let value = checked(field_id, s, 2, is_upper_alphanumeric)?;
+        let is_valid = match value.len() {
+            2 => !value.bytes().all(|b| b.is_ascii_digit()),
+            _ => value.bytes().all(|b| b.is_ascii_uppercase()),
+        };
+        if !is_valid {
+            return Err(Error::InvalidFieldValue(field_id));
+        }
+        Ok(CarrierDesignator(FixedString::from(value).unwrap()))
+    }
+}
+
+/// A booking reference of up to seven letters and digits, e.g. `ABC123`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PnrCode(FixedString<7>);
+
+impl PnrCode {
+    /// The booking reference without padding.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PnrCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value = checked(Field::OperatingCarrierPnrCode, s, 1, is_upper_alphanumeric)?;
+        Ok(PnrCode(FixedString::from(value).unwrap()))
+    }
+}
+
+/// A day of the year between 1 and 366, encoded as three digits, e.g. `012`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct JulianDate(FixedString<3>);
+
+impl JulianDate {
+    /// The day of the year as three digits.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The day of the year, with January 1 being day 1.
+    pub fn day_of_year(&self) -> u16 {
+        self.0.parse().unwrap()
+    }
+}
+
+impl FromStr for JulianDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let field_id = Field::DateOfFlight;
+        let value = checked(field_id, s, 3, |b| b.is_ascii_digit())?;
+        match value.parse::<u16>() {
+            Ok(day) if (1 ..= 366).contains(&day) => Ok(JulianDate(FixedString::from(value).unwrap())),
+            _ => Err(Error::InvalidFieldValue(field_id)),
+        }
+    }
+}
+
+
+impl fmt::Display for AirportCode {
+    /// Formats the value left-aligned and space-padded to the length of the field.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<width$}", self.as_str(), width = Field::FromCityAirportCode.len())
+    }
+}
+
+impl AsRef<str> for AirportCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CarrierDesignator {
+    /// Formats the value left-aligned and space-padded to the length of the field.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<width$}", self.as_str(), width = Field::OperatingCarrierDesignator.len())
+    }
+}
+
+impl AsRef<str> for CarrierDesignator {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for PnrCode {
+    /// Formats the value left-aligned and space-padded to the length of the field.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<width$}", self.as_str(), width = Field::OperatingCarrierPnrCode.len())
+    }
+}
+
+impl AsRef<str> for PnrCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for JulianDate {
+    /// Formats the value left-aligned and space-padded to the length of the field.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<width$}", self.as_str(), width = Field::DateOfFlight.len())
+    }
+}
+
+impl AsRef<str> for JulianDate {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
@@ -0,0 +1,23 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::dynamic::non_blank;
+use crate::bcbp::Leg;
+
+impl Leg {
+    /// Returns `true` if the marketing carrier is set and differs from the operating carrier.
+    ///
+    /// Designators are compared without their padding. A leg is not considered a codeshare
+    /// if either carrier is not set, as the relationship between them cannot be determined.
+    pub fn is_codeshare(&self) -> bool {
+        let operating_carrier = non_blank(Some(self.operating_carrier_designator()));
+        let marketing_carrier = non_blank(self.marketing_carrier_designator());
+        match (operating_carrier, marketing_carrier) {
+            (Some(operating_carrier), Some(marketing_carrier)) =>
+                operating_carrier.trim_end() != marketing_carrier.trim_end(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, Leg};
+
+/// A date identified by the last digit of its year and its day of the year,
+/// the precision with which dates are encoded in a boarding pass.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FlightDate {
+    year_digit: u8,
+    day_of_year: u16,
+}
+
+impl FlightDate {
+    /// Returns the date with the last digit of its year `year_digit` on `day_of_year`,
+    /// or `None` if the digit exceeds 9 or the day is not in the range 1 through 366.
+    pub fn new(year_digit: u8, day_of_year: u16) -> Option<FlightDate> {
+        if year_digit <= 9 && (1 ..= 366).contains(&day_of_year) {
+            Some(FlightDate { year_digit, day_of_year })
+        } else {
+            None
+        }
+    }
+
+    /// Parses a date encoded as the last digit of the year followed by three digits of the day,
+    /// as the Date of Issue of Boarding Pass is.
+    pub(crate) fn parse(value: &str) -> Option<FlightDate> {
+        let year_digit = value.get(.. 1)?.parse::<u8>().ok()?;
+        let day_of_year = parse_day_of_year(value.get(1 ..)?)?;
+        Some(FlightDate { year_digit, day_of_year })
+    }
+
+    /// Returns the first date falling on `day_of_year` which is not before this date.
+    ///
+    /// A flight cannot depart before its boarding pass is issued, so a flight day on or after
+    /// the day of issue falls in the year of issue, and an earlier flight day falls in the
+    /// following year.
+    pub(crate) fn next_on_day(&self, day_of_year: u16) -> FlightDate {
+        let year_digit = if day_of_year >= self.day_of_year {
+            self.year_digit
+        } else {
+            (self.year_digit + 1) % 10
+        };
+        FlightDate { year_digit, day_of_year }
+    }
+
+    /// The last digit of the year.
+    pub fn year_digit(&self) -> u8 {
+        self.year_digit
+    }
+
+    /// The day of the year, with January 1 being day 1.
+    pub fn day_of_year(&self) -> u16 {
+        self.day_of_year
+    }
+
+    /// Returns the year ending in `year_digit` closest to `reference_year`,
+    /// preferring the earlier year where two are equally close.
+    pub fn year_near(&self, reference_year: i32) -> i32 {
+        let offset = (i32::from(self.year_digit) - reference_year).rem_euclid(10);
+        if offset < 5 {
+            reference_year + offset
+        } else {
+            reference_year + offset - 10
+        }
+    }
+
+    /// Returns the date in the year ending in `year_digit` closest to `reference_year`,
+    /// or `None` if the day does not exist in that year.
+    #[cfg(feature = "time")]
+    pub fn to_date(&self, reference_year: i32) -> Option<time::Date> {
+        time::Date::from_ordinal_date(self.year_near(reference_year), self.day_of_year).ok()
+    }
+}
+
+/// Parses a day of the year in the range 1 through 366 from exactly three digits.
+fn parse_day_of_year(value: &str) -> Option<u16> {
+    if value.len() != 3 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok().filter(|day| (1 ..= 366).contains(day))
+}
+
+impl Leg {
+    /// Resolves the year of the Date of Flight using the Date of Issue of Boarding Pass of `pass`.
+    ///
+    /// The flight falls on the first occurrence of its day on or after the day of issue.
+    /// Returns `None` if either date is not set or is malformed.
+    pub fn resolved_flight_date(&self, pass: &Bcbp) -> Option<FlightDate> {
+        let issue_date = FlightDate::parse(pass.date_of_issue_of_boarding_pass()?)?;
+        let flight_day = parse_day_of_year(self.date_of_flight())?;
+        Some(issue_date.next_on_day(flight_day))
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, Leg};
+
+impl Leg {
+    /// Returns `true` if `other` represents the same segment, either being identical
+    /// or sharing the operating carrier, flight number, date of flight and seat number.
+    pub fn is_duplicate_of(&self, other: &Leg) -> bool {
+        self == other || (
+            self.operating_carrier_designator == other.operating_carrier_designator
+                && self.flight_number == other.flight_number
+                && self.date_of_flight == other.date_of_flight
+                && self.seat_number == other.seat_number
+        )
+    }
+}
+
+impl Bcbp {
+    /// The indices of each leg duplicating an earlier leg, paired with the index of
+    /// the first leg it duplicates.
+    pub fn duplicate_legs(&self) -> Vec<(usize, usize)> {
+        self.legs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, leg)| {
+                self.legs[.. index]
+                    .iter()
+                    .position(|earlier| leg.is_duplicate_of(earlier))
+                    .map(|original| (index, original))
+            })
+            .collect()
+    }
+
+    /// Removes each leg duplicating an earlier leg, as reported by `duplicate_legs`,
+    /// and returns the number of legs removed.
+    pub fn dedup_legs(&mut self) -> usize {
+        let original_len = self.legs.len();
+        let mut index = 1;
+        while index < self.legs.len() {
+            if self.legs[.. index].iter().any(|earlier| self.legs[index].is_duplicate_of(earlier)) {
+                self.legs.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        let removed = original_len - self.legs.len();
+        if removed > 0 {
+            self.declared_leg_count = None;
+            self.security_data.clear_signed_payload();
+        }
+        removed
+    }
+}
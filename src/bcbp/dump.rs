@@ -0,0 +1,47 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt::Write;
+
+use crate::bcbp::Bcbp;
+use crate::de::field::Field;
+
+/// Appends a row for each populated field, aligning names to `name_width` characters.
+fn write_fields<'a, F>(output: &mut String, name_width: usize, value_of: F)
+where
+    F: Fn(Field) -> Option<&'a str>,
+{
+    for &field in Field::all() {
+        if let Some(value) = value_of(field) {
+            let _ = writeln!(
+                output,
+                "{:>3}  {:<width$}  '{}'",
+                field.item_number(),
+                field.name(),
+                value,
+                width = name_width
+            );
+        }
+    }
+}
+
+impl Bcbp {
+    /// Returns a table of the item number, name and value of every populated field,
+    /// in aligned columns, for inspecting boarding pass data.
+    ///
+    /// Fields unique to the boarding pass are listed first, followed by those of each leg
+    /// under a heading. Values are quoted to show their padding.
+    pub fn dump(&self) -> String {
+        let name_width = Field::all().iter().map(|field| field.name().len()).max().unwrap_or(0);
+
+        let mut output = String::new();
+        write_fields(&mut output, name_width, |field| self.field(field));
+        for (leg_index, leg) in self.legs().iter().enumerate() {
+            let _ = writeln!(output, "Leg {}", leg_index + 1);
+            write_fields(&mut output, name_width, |field| leg.field(field));
+        }
+        output
+    }
+}
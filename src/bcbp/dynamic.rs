@@ -0,0 +1,171 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+#[cfg(feature = "compat")]
+use std::collections::HashMap;
+use std::ops::Index;
+
+use crate::bcbp::{Bcbp, ConditionalMetadata, Leg, SecurityData};
+use crate::de::field::Field;
+
+/// Every ASCII character, indexed by its value, used to borrow single-character fields as strings.
+static ASCII_CHARACTERS: [u8; 128] = {
+    let mut characters = [0; 128];
+    let mut index = 0;
+    while index < characters.len() {
+        characters[index] = index as u8;
+        index += 1;
+    }
+    characters
+};
+
+/// Returns a single-character field value as a string slice.
+/// All field values are ASCII, any other character yields None.
+fn chr_str(c: char) -> Option<&'static str> {
+    let index = c as usize;
+    ASCII_CHARACTERS
+        .get(index .. index + 1)
+        .and_then(|bytes| std::str::from_utf8(bytes).ok())
+}
+
+/// Returns `value` unless it consists entirely of spaces, which indicates the field is not set.
+pub(crate) fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.filter(|value| !value.bytes().all(|b| b == b' '))
+}
+
+/// Returns the value of a field unique to a boarding pass from its constituent items, as in `Bcbp::field`.
+pub(crate) fn unique_field<'a>(
+    passenger_name: &'a str,
+    electronic_ticket_indicator: char,
+    metadata: &'a ConditionalMetadata,
+    security_data: &'a SecurityData,
+    field_id: Field,
+) -> Option<&'a str> {
+    match field_id {
+        Field::PassengerName => Some(passenger_name),
+        Field::ElectronicTicketIndicator => chr_str(electronic_ticket_indicator),
+        Field::VersionNumber => metadata.version_number.and_then(chr_str),
+        Field::PassengerDescription => metadata.passenger_description.and_then(chr_str),
+        Field::SourceOfCheckIn => metadata.source_of_check_in.and_then(chr_str),
+        Field::SourceOfBoardingPassIssuance => metadata.source_of_boarding_pass_issuance.and_then(chr_str),
+        Field::DateOfIssueOfBoardingPass => metadata.date_of_issue_of_boarding_pass(),
+        Field::DocumentType => metadata.document_type.and_then(chr_str),
+        Field::AirlineDesignatorOfBoardingPassIssuer => metadata.airline_designator_of_boarding_pass_issuer(),
+        Field::BaggageTagLicensePlateNumbers => metadata.baggage_tag_license_plate_numbers(),
+        Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers => metadata.first_non_consecutive_baggage_tag_license_plate_numbers(),
+        Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers => metadata.second_non_consecutive_baggage_tag_license_plate_numbers(),
+        Field::TypeOfSecurityData => security_data.type_of_security_data.and_then(chr_str),
+        Field::SecurityData => security_data.security_data(),
+        _ => None,
+    }
+}
+
+impl Leg {
+    /// The value of a field encoded in the leg, identified dynamically.
+    ///
+    /// Returns None if the field is not present in the leg, or if it is not an item encoded
+    /// within a leg. Structural items such as field sizes are not available.
+    pub fn field(&self, field_id: Field) -> Option<&str> {
+        match field_id {
+            Field::OperatingCarrierPnrCode => Some(self.operating_carrier_pnr_code()),
+            Field::FromCityAirportCode => Some(self.from_city_airport_code()),
+            Field::ToCityAirportCode => Some(self.to_city_airport_code()),
+            Field::OperatingCarrierDesignator => Some(self.operating_carrier_designator()),
+            Field::FlightNumber => Some(self.flight_number()),
+            Field::DateOfFlight => Some(self.date_of_flight()),
+            Field::CompartmentCode => chr_str(self.compartment_code),
+            Field::SeatNumber => Some(self.seat_number()),
+            Field::CheckInSequenceNumber => Some(self.check_in_sequence_number()),
+            Field::PassengerStatus => chr_str(self.passenger_status),
+            Field::AirlineNumericCode => self.airline_numeric_code(),
+            Field::DocumentFormSerialNumber => self.document_form_serial_number(),
+            Field::SelecteeIndicator => self.selectee_indicator.and_then(chr_str),
+            Field::InternationalDocumentVerification => self.international_document_verification.and_then(chr_str),
+            Field::MarketingCarrierDesignator => self.marketing_carrier_designator(),
+            Field::FrequentFlyerAirlineDesignator => self.frequent_flyer_airline_designator(),
+            Field::FrequentFlyerNumber => self.frequent_flyer_number(),
+            Field::IdAdIndicator => self.id_ad_indicator.and_then(chr_str),
+            Field::FreeBaggageAllowance => self.free_baggage_allowance(),
+            Field::FastTrack => self.fast_track.and_then(chr_str),
+            Field::AirlineIndividualUse => self.airline_individual_use(),
+            _ => None,
+        }
+    }
+
+    /// The value of a field encoded in the leg as in `field`, or None if it consists
+    /// entirely of spaces. Padding is preserved for fields which are set.
+    pub fn field_value(&self, field_id: Field) -> Option<&str> {
+        non_blank(self.field(field_id))
+    }
+
+    /// All fields present in the leg, keyed by field.
+    #[cfg(feature = "compat")]
+    pub fn to_field_map(&self) -> HashMap<Field, String> {
+        Field::all()
+            .iter()
+            .filter_map(|&field_id| self.field(field_id).map(|value| (field_id, String::from(value))))
+            .collect()
+    }
+}
+
+impl Bcbp {
+    /// The value of a field unique to the boarding pass, identified dynamically.
+    ///
+    /// Returns None if the field is not present, or if it is an item encoded within each leg.
+    /// Structural items such as field sizes are not available.
+    pub fn field(&self, field_id: Field) -> Option<&str> {
+        unique_field(
+            &self.passenger_name,
+            self.electronic_ticket_indicator,
+            &self.metadata,
+            &self.security_data,
+            field_id,
+        )
+    }
+
+    /// The value of a field unique to the boarding pass as in `field`, or None if it consists
+    /// entirely of spaces. Padding is preserved for fields which are set.
+    pub fn field_value(&self, field_id: Field) -> Option<&str> {
+        non_blank(self.field(field_id))
+    }
+
+    /// All fields unique to the boarding pass which are present, keyed by field.
+    /// Fields encoded within each leg are available through `Leg::to_field_map`.
+    #[cfg(feature = "compat")]
+    pub fn to_field_map(&self) -> HashMap<Field, String> {
+        Field::all()
+            .iter()
+            .filter_map(|&field_id| self.field(field_id).map(|value| (field_id, String::from(value))))
+            .collect()
+    }
+}
+
+impl Index<Field> for Leg {
+    type Output = str;
+
+    /// Returns the value of the field as in `Leg::field`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is not present in the leg.
+    fn index(&self, field_id: Field) -> &str {
+        self.field(field_id)
+            .unwrap_or_else(|| panic!("{} is not present in the leg", field_id))
+    }
+}
+
+impl Index<Field> for Bcbp {
+    type Output = str;
+
+    /// Returns the value of the field as in `Bcbp::field`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is not present in the boarding pass.
+    fn index(&self, field_id: Field) -> &str {
+        self.field(field_id)
+            .unwrap_or_else(|| panic!("{} is not present in the boarding pass", field_id))
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, Leg};
+
+/// The Passenger Description of an infant.
+const INFANT_PASSENGER_DESCRIPTION: char = '4';
+
+impl Leg {
+    /// Returns `true` if the seat number follows the 'INF ' convention for an infant
+    /// travelling on the lap of an adult, in any case.
+    pub fn is_infant_seating(&self) -> bool {
+        self.seat_number().trim_end().eq_ignore_ascii_case("INF")
+    }
+}
+
+impl Bcbp {
+    /// Returns `true` if the passenger is an infant, either by the Passenger Description
+    /// or by infant seating in any leg.
+    ///
+    /// An adult travelling with an infant is described separately and is not an infant passenger.
+    pub fn is_infant_passenger(&self) -> bool {
+        self.passenger_description() == Some(INFANT_PASSENGER_DESCRIPTION)
+            || self.legs().iter().any(Leg::is_infant_seating)
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::cmp::Ordering;
+
+use crate::bcbp::{Bcbp, Leg};
+
+/// Returns the key ordering `leg` by date of flight within `pass`, with resolved dates
+/// ordered by whether they fall in the year following the date of issue, then by day.
+fn date_key<'a>(leg: &'a Leg, pass: &Bcbp) -> (bool, Option<(bool, u16)>, &'a str) {
+    let issue_year_digit = pass
+        .date_of_issue_of_boarding_pass()
+        .and_then(|date| date.get(.. 1))
+        .and_then(|digit| digit.parse::<u8>().ok());
+    let resolved = leg
+        .resolved_flight_date(pass)
+        .map(|date| (Some(date.year_digit()) != issue_year_digit, date.day_of_year()));
+    (resolved.is_none(), resolved, leg.date_of_flight())
+}
+
+impl Leg {
+    /// Compares legs by itinerary order within the boarding pass `pass`.
+    ///
+    /// Legs are ordered by date of flight, resolved against the date of issue to account for
+    /// flights in the following year, then by flight number and finally by origin airport code.
+    /// Legs whose date cannot be resolved are ordered after all others by their raw date.
+    pub fn cmp_itinerary(&self, other: &Leg, pass: &Bcbp) -> Ordering {
+        date_key(self, pass).cmp(&date_key(other, pass))
+            .then_with(|| self.flight_number().cmp(other.flight_number()))
+            .then_with(|| self.from_city_airport_code().cmp(other.from_city_airport_code()))
+    }
+}
+
+impl Bcbp {
+    /// The legs of the boarding pass in itinerary order as defined by `Leg::cmp_itinerary`,
+    /// regardless of the order in which they are encoded.
+    ///
+    /// Legs which compare equal retain their encoded order.
+    pub fn legs_sorted(&self) -> Vec<&Leg> {
+        let mut legs: Vec<&Leg> = self.legs().iter().collect();
+        legs.sort_by(|a, b| a.cmp_itinerary(b, self));
+        legs
+    }
+}
+
+/// The legs of a boarding pass in itinerary order.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Itinerary<'a> {
+    legs: Vec<&'a Leg>,
+}
+
+/// A pair of consecutive legs in which the passenger arrives at the airport the next leg departs from.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Connection<'a> {
+    arriving: &'a Leg,
+    departing: &'a Leg,
+}
+
+/// Parses a Julian date of flight.
+fn day_of_flight(leg: &Leg) -> Option<u16> {
+    let date = leg.date_of_flight();
+    if date.len() == 3 && date.bytes().all(|b| b.is_ascii_digit()) {
+        date.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl<'a> Connection<'a> {
+    /// The leg arriving at the connecting airport.
+    pub fn arriving(&self) -> &'a Leg {
+        self.arriving
+    }
+
+    /// The leg departing from the connecting airport.
+    pub fn departing(&self) -> &'a Leg {
+        self.departing
+    }
+
+    /// The airport code of the connecting airport.
+    pub fn airport(&self) -> &'a str {
+        self.departing.from_city_airport_code()
+    }
+
+    /// The number of days between the dates of flight of the legs.
+    ///
+    /// A departure day earlier than the arrival day is taken to be in the following year,
+    /// assumed to follow a year of 365 days unless the arrival is on day 366.
+    /// Returns `None` if either date of flight is not set.
+    pub fn day_gap(&self) -> Option<u16> {
+        let arrival_day = day_of_flight(self.arriving)?;
+        let departure_day = day_of_flight(self.departing)?;
+        if departure_day >= arrival_day {
+            Some(departure_day - arrival_day)
+        } else {
+            Some(departure_day + arrival_day.max(365) - arrival_day)
+        }
+    }
+
+    /// Returns `true` unless the departing leg is on the same or the next day as the arriving leg,
+    /// suggesting a stopover or an error in the boarding pass rather than a connection.
+    pub fn is_suspicious(&self) -> bool {
+        self.day_gap().map_or(true, |gap| gap > 1)
+    }
+}
+
+impl<'a> Itinerary<'a> {
+    /// The legs in itinerary order as defined by `Leg::cmp_itinerary`.
+    pub fn legs(&self) -> &[&'a Leg] {
+        &self.legs
+    }
+
+    /// The consecutive pairs of legs in which the destination of the first leg
+    /// is the origin of the second.
+    pub fn connections(&self) -> Vec<Connection<'a>> {
+        self.legs
+            .windows(2)
+            .filter(|pair| pair[0].to_city_airport_code() == pair[1].from_city_airport_code())
+            .map(|pair| Connection { arriving: pair[0], departing: pair[1] })
+            .collect()
+    }
+}
+
+impl Bcbp {
+    /// The legs of the boarding pass as an itinerary, in the order of `legs_sorted`.
+    pub fn itinerary(&self) -> Itinerary<'_> {
+        Itinerary { legs: self.legs_sorted() }
+    }
+}
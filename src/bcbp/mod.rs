@@ -3,29 +3,60 @@
 // This software may be modified and distributed under the terms
 // of the MIT license.  See the LICENSE file for details.
 
-use arrayvec::ArrayString;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+use crate::fixed_string::FixedString;
+
+mod baggage;
+mod codes;
+mod codeshare;
+mod date;
+mod dedup;
+pub(crate) mod dynamic;
+mod dump;
+mod infant;
+mod itinerary;
+mod normalize;
+mod optional;
+mod screening;
+pub(crate) mod setters;
+mod status;
+mod ticket;
+
+pub use self::baggage::{BaggageTag, BaggageTagNumber};
+pub use self::codes::{AirportCode, CarrierDesignator, JulianDate, PnrCode};
+pub use self::date::FlightDate;
+pub use self::itinerary::{Connection, Itinerary};
+pub use self::normalize::Normalization;
+pub use self::screening::{DocumentVerification, ScreeningSummary, SelecteeIndicator};
+
+/// The maximum number of legs a boarding pass may contain as defined in Resolution 792.
+pub(crate) const MAX_NUMBER_OF_LEGS: usize = 4;
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub struct Leg {
-    pub(crate) operating_carrier_pnr_code: ArrayString<[u8; 7]>,
-    pub(crate) from_city_airport_code: ArrayString<[u8; 3]>,
-    pub(crate) to_city_airport_code: ArrayString<[u8; 3]>,
-    pub(crate) operating_carrier_designator: ArrayString<[u8; 3]>,
-    pub(crate) flight_number: ArrayString<[u8; 5]>,
-    pub(crate) date_of_flight: ArrayString<[u8; 3]>,
+    pub(crate) operating_carrier_pnr_code: FixedString<7>,
+    pub(crate) from_city_airport_code: FixedString<4>,
+    pub(crate) to_city_airport_code: FixedString<4>,
+    pub(crate) operating_carrier_designator: FixedString<3>,
+    pub(crate) flight_number: FixedString<5>,
+    pub(crate) date_of_flight: FixedString<3>,
     pub(crate) compartment_code: char,
-    pub(crate) seat_number: ArrayString<[u8; 4]>,
-    pub(crate) check_in_sequence_number: ArrayString<[u8; 5]>,
+    pub(crate) seat_number: FixedString<4>,
+    pub(crate) check_in_sequence_number: FixedString<5>,
     pub(crate) passenger_status: char,
-    pub(crate) airline_numeric_code: Option<ArrayString<[u8; 3]>>,
-    pub(crate) document_form_serial_number: Option<ArrayString<[u8; 10]>>,
+    pub(crate) airline_numeric_code: Option<FixedString<3>>,
+    pub(crate) document_form_serial_number: Option<FixedString<10>>,
     pub(crate) selectee_indicator: Option<char>,
     pub(crate) international_document_verification: Option<char>,
-    pub(crate) marketing_carrier_designator: Option<ArrayString<[u8; 3]>>,
-    pub(crate) frequent_flyer_airline_designator: Option<ArrayString<[u8; 3]>>,
-    pub(crate) frequent_flyer_number: Option<ArrayString<[u8; 16]>>,
+    pub(crate) marketing_carrier_designator: Option<FixedString<3>>,
+    pub(crate) frequent_flyer_airline_designator: Option<FixedString<3>>,
+    pub(crate) frequent_flyer_number: Option<FixedString<16>>,
     pub(crate) id_ad_indicator: Option<char>,
-    pub(crate) free_baggage_allowance: Option<ArrayString<[u8; 3]>>,
+    pub(crate) free_baggage_allowance: Option<FixedString<3>>,
     pub(crate) fast_track: Option<char>,
     pub(crate) airline_individual_use: Option<String>,
 }
@@ -38,14 +69,16 @@ impl Leg {
         &self.operating_carrier_pnr_code
     }
 
-    /// Three-letter or four-letter IATA code of the origin airport.
+    /// Three-letter IATA code of the origin airport.
+    /// Four-letter codes are only present when parsed tolerating `Quirk::FourCharacterAirportCodes`.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
     pub fn from_city_airport_code(&self) -> &str {
         &self.from_city_airport_code
     }
 
-    /// Three-letter or four-letter IATA code of the destination airport.
+    /// Three-letter IATA code of the destination airport.
+    /// Four-letter codes are only present when parsed tolerating `Quirk::FourCharacterAirportCodes`.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
     pub fn to_city_airport_code(&self) -> &str {
@@ -57,10 +90,9 @@ impl Leg {
     /// are permitted and the string is left-justified and space padded.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::MarketingCarrierDesignator)` returns None.
     pub fn marketing_carrier_designator(&self) -> Option<&str> {
-        self.marketing_carrier_designator
-            .as_ref()
-            .map(|x| x.as_str())
+        self.marketing_carrier_designator.as_deref()
     }
 
     /// Airline code associated with the frequent flyer number.
@@ -68,25 +100,24 @@ impl Leg {
     /// are permitted and the string is left-justified and space padded.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::FrequentFlyerAirlineDesignator)` returns None.
     pub fn frequent_flyer_airline_designator(&self) -> Option<&str> {
-        self.frequent_flyer_airline_designator
-            .as_ref()
-            .map(|x| x.as_str())
+        self.frequent_flyer_airline_designator.as_deref()
     }
 
     /// 2 character or 3 letter airline designator followed by up to 13 numerics or
     /// alphanumerics, or 16 numerics if the FFN is 16 digits.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::FrequentFlyerNumber)` returns None.
     pub fn frequent_flyer_number(&self) -> Option<&str> {
-        self.frequent_flyer_number
-            .as_ref()
-            .map(|x| x.as_str())
+        self.frequent_flyer_number.as_deref()
     }
 
     /// Values are defined in Resolution 792.
     /// Spaces indicate the field is not set.
     /// Any other values are invalid.
+    /// A space is returned as parsed, whereas `field_value(Field::IdAdIndicator)` returns None.
     pub fn id_ad_indicator(&self) -> Option<char> {
         self.id_ad_indicator
     }
@@ -147,19 +178,17 @@ impl Leg {
     /// The three-digit airline numeric code.
     /// This is also the first three digits of the eTicket number.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::AirlineNumericCode)` returns None.
     pub fn airline_numeric_code(&self) -> Option<&str> {
-        self.airline_numeric_code
-            .as_ref()
-            .map(|x| x.as_str())
+        self.airline_numeric_code.as_deref()
     }
 
     /// The ten-digit DSN.
     /// This is also the last ten digits of the eTicket number.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::DocumentFormSerialNumber)` returns None.
     pub fn document_form_serial_number(&self) -> Option<&str> {
-        self.document_form_serial_number
-            .as_ref()
-            .map(|x| x.as_str())
+        self.document_form_serial_number.as_deref()
     }
 
     /// This field is used by certain agencies to demarcate individuals requiring extra screening.
@@ -167,12 +196,14 @@ impl Leg {
     /// travel involves the United States. Values '0', '1', or '3' determine the type
     /// of screening the passenger will receive at US airports.
     /// A space indicates the field is not set.
+    /// A space is returned as parsed, whereas `field_value(Field::SelecteeIndicator)` returns None.
     pub fn selectee_indicator(&self) -> Option<char> {
         self.selectee_indicator
     }
 
     /// This field is used by carriers to identify passengers requiring document verification.
     /// Connected to the display of the 'DOCS OK' string on international boarding passes.
+    /// A space is returned as parsed, whereas `field_value(Field::InternationalDocumentVerification)` returns None.
     pub fn international_document_verification(&self) -> Option<char> {
         self.international_document_verification
     }
@@ -180,6 +211,7 @@ impl Leg {
     /// Indicates if the passenger is eligible for fast track.
     /// If 'Y', the passenger is eligible, 'N' if not, ' ' if not set.
     /// Any other values are invalid.
+    /// A space is returned as parsed, whereas `field_value(Field::FastTrack)` returns None.
     pub fn fast_track(&self) -> Option<char> {
         self.fast_track
     }
@@ -187,28 +219,59 @@ impl Leg {
     /// Three characters, unstructured, left-aligned and space padded,
     /// indicating how much baggage passengers are able to take with them free of charge.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::FreeBaggageAllowance)` returns None.
     pub fn free_baggage_allowance(&self) -> Option<&str> {
-        self.free_baggage_allowance
-            .as_ref()
-            .map(|x| x.as_str())
+        self.free_baggage_allowance.as_deref()
     }
 
     /// Optional unstructured data for airline individual use.
     /// Content frequently includes frequent flyer tier, passenger preferences, etc.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::AirlineIndividualUse)` returns None.
     pub fn airline_individual_use(&self) -> Option<&str> {
-        self.airline_individual_use
-            .as_ref()
-            .map(|x| x.as_str())
+        self.airline_individual_use.as_deref()
+    }
+
+    /// The airline individual use data as raw bytes, exactly as parsed and as it is encoded.
+    /// Data is only ever ASCII, but may include control characters.
+    pub fn airline_individual_use_bytes(&self) -> Option<&[u8]> {
+        self.airline_individual_use().map(str::as_bytes)
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+/// Security data is compared and hashed by its items alone, regardless of how the pass was obtained.
+#[derive(Clone, Eq, Debug, Default)]
 pub struct SecurityData {
     pub(crate) type_of_security_data: Option<char>,
+    pub(crate) length_of_security_data: Option<usize>,
     pub(crate) security_data: Option<String>,
+    pub(crate) signed_range: Option<Range<usize>>,
+    pub(crate) signed_payload: Option<String>,
+}
+
+impl PartialEq for SecurityData {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_of_security_data == other.type_of_security_data
+            && self.length_of_security_data == other.length_of_security_data
+            && self.security_data == other.security_data
+    }
+}
+
+impl Hash for SecurityData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_of_security_data.hash(state);
+        self.length_of_security_data.hash(state);
+        self.security_data.hash(state);
+    }
 }
 
 impl SecurityData {
+    /// Discards the record of the parsed data covered by the security data.
+    /// Called whenever a boarding pass is modified, as the parsed data no longer represents it.
+    pub(crate) fn clear_signed_payload(&mut self) {
+        self.signed_range = None;
+        self.signed_payload = None;
+    }
+
     /// Vendor specific flag indicating the type of the security data which follows.
     pub fn type_of_security_data(&self) -> Option<char> {
         self.type_of_security_data
@@ -216,35 +279,142 @@ impl SecurityData {
 
     /// Security data used to verify the boarding pass was not tampered with.
     pub fn security_data(&self) -> Option<&str> {
-        self.security_data
-            .as_ref()
-            .map(|x| x.as_str())
+        self.security_data.as_deref()
+    }
+
+    /// The length of the security data as declared in the Length of Security Data field.
+    /// None indicates the boarding pass does not contain a security data section.
+    pub fn declared_len(&self) -> Option<usize> {
+        self.length_of_security_data
+    }
+
+    /// The length of the security data actually present.
+    /// None indicates the boarding pass does not contain a security data section.
+    pub fn actual_len(&self) -> Option<usize> {
+        self.length_of_security_data
+            .map(|_| self.security_data.as_deref().map_or(0, str::len))
+    }
+
+    /// The range of bytes within the parsed boarding pass covered by the security data.
+    /// This spans all data preceding the Beginning of Security Data indicator.
+    /// None indicates the boarding pass does not contain a security data section.
+    pub fn signed_range(&self) -> Option<Range<usize>> {
+        self.signed_range.clone()
+    }
+
+    /// Returns `true` if the security data present is shorter than its declared length,
+    /// which is only possible when parsing tolerates `Quirk::TruncatedSecurityData`.
+    pub fn is_truncated(&self) -> bool {
+        match (self.declared_len(), self.actual_len()) {
+            (Some(declared_len), Some(actual_len)) => actual_len < declared_len,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the declared length matches the length of the security data present.
+    /// A boarding pass without a security data section is considered consistent.
+    pub fn is_length_consistent(&self) -> bool {
+        self.declared_len() == self.actual_len()
     }
 }
 
+/// Conditional items unique to the boarding pass, describing how and by whom it was issued.
+/// These are encoded in the conditional section of the first leg.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
-pub(crate) struct ConditionalMetadata {
+pub struct ConditionalMetadata {
     pub(crate) version_number: Option<char>,
     pub(crate) passenger_description: Option<char>,
     pub(crate) source_of_check_in: Option<char>,
     pub(crate) source_of_boarding_pass_issuance: Option<char>,
-    pub(crate) date_of_issue_of_boarding_pass: Option<ArrayString<[u8; 4]>>,
+    pub(crate) date_of_issue_of_boarding_pass: Option<FixedString<4>>,
     pub(crate) document_type: Option<char>,
-    pub(crate) airline_designator_of_boarding_pass_issuer: Option<ArrayString<[u8; 3]>>,
-    pub(crate) baggage_tag_license_plate_numbers: Option<ArrayString<[u8; 13]>>,
-    pub(crate) first_non_consecutive_baggage_tag_license_plate_numbers: Option<ArrayString<[u8; 13]>>,
-    pub(crate) second_non_consecutive_baggage_tag_license_plate_numbers: Option<ArrayString<[u8; 13]>>,
+    pub(crate) airline_designator_of_boarding_pass_issuer: Option<FixedString<3>>,
+    pub(crate) baggage_tag_license_plate_numbers: Option<FixedString<13>>,
+    pub(crate) first_non_consecutive_baggage_tag_license_plate_numbers: Option<FixedString<13>>,
+    pub(crate) second_non_consecutive_baggage_tag_license_plate_numbers: Option<FixedString<13>>,
 }
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+impl ConditionalMetadata {
+    /// Returns `true` if none of the conditional items unique to the boarding pass are present.
+    pub fn is_empty(&self) -> bool {
+        *self == ConditionalMetadata::default()
+    }
+
+    /// The version number of the BCBP object. See `Bcbp::version_number`.
+    pub fn version_number(&self) -> Option<char> {
+        self.version_number
+    }
+
+    /// The passenger description. See `Bcbp::passenger_description`.
+    pub fn passenger_description(&self) -> Option<char> {
+        self.passenger_description
+    }
+
+    /// The channel in which check-in was initiated. See `Bcbp::source_of_check_in`.
+    pub fn source_of_check_in(&self) -> Option<char> {
+        self.source_of_check_in
+    }
+
+    /// The channel which issued the boarding pass. See `Bcbp::source_of_boarding_pass_issuance`.
+    pub fn source_of_boarding_pass_issuance(&self) -> Option<char> {
+        self.source_of_boarding_pass_issuance
+    }
+
+    /// The 4-digit Julian date of issue. See `Bcbp::date_of_issue_of_boarding_pass`.
+    pub fn date_of_issue_of_boarding_pass(&self) -> Option<&str> {
+        self.date_of_issue_of_boarding_pass.as_deref()
+    }
+
+    /// The type of the document. See `Bcbp::document_type`.
+    pub fn document_type(&self) -> Option<char> {
+        self.document_type
+    }
+
+    /// Airline code of the boarding pass issuer. See `Bcbp::airline_designator_of_boarding_pass_issuer`.
+    pub fn airline_designator_of_boarding_pass_issuer(&self) -> Option<&str> {
+        self.airline_designator_of_boarding_pass_issuer.as_deref()
+    }
+
+    /// Baggage tag numbers. See `Bcbp::baggage_tag_license_plate_numbers`.
+    pub fn baggage_tag_license_plate_numbers(&self) -> Option<&str> {
+        self.baggage_tag_license_plate_numbers.as_deref()
+    }
+
+    /// Second set of baggage tag numbers. See `Bcbp::first_non_consecutive_baggage_tag_license_plate_numbers`.
+    pub fn first_non_consecutive_baggage_tag_license_plate_numbers(&self) -> Option<&str> {
+        self.first_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
+    }
+
+    /// Third set of baggage tag numbers. See `Bcbp::second_non_consecutive_baggage_tag_license_plate_numbers`.
+    pub fn second_non_consecutive_baggage_tag_license_plate_numbers(&self) -> Option<&str> {
+        self.second_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Bcbp {
-    pub(crate) passenger_name: ArrayString<[u8; 20]>,
+    pub(crate) passenger_name: FixedString<20>,
     pub(crate) electronic_ticket_indicator: char,
     pub(crate) metadata: ConditionalMetadata,
     pub(crate) legs: Vec<Leg>,
+    pub(crate) declared_leg_count: Option<u8>,
     pub(crate) security_data: SecurityData,
 }
 
+impl Default for Bcbp {
+    /// A boarding pass with a single leg and all fields unset.
+    fn default() -> Self {
+        Bcbp {
+            passenger_name: Default::default(),
+            electronic_ticket_indicator: Default::default(),
+            metadata: Default::default(),
+            legs: vec![Default::default()],
+            declared_leg_count: None,
+            security_data: Default::default(),
+        }
+    }
+}
+
 impl Bcbp {
     /// All legs encoded into the boarding pass.
     /// At least one needs to be present to form a valid boarding pass.
@@ -252,11 +422,66 @@ impl Bcbp {
         &self.legs
     }
 
+    /// The first leg encoded into the boarding pass.
+    /// Every boarding pass contains at least one leg.
+    pub fn first_leg(&self) -> &Leg {
+        &self.legs[0]
+    }
+
+    /// The leg at `index` in encoded order, or None if there is no such leg.
+    pub fn leg(&self, index: usize) -> Option<&Leg> {
+        self.legs.get(index)
+    }
+
+    /// The number of legs encoded into the boarding pass, always at least one.
+    pub fn leg_count(&self) -> usize {
+        self.legs.len()
+    }
+
+    /// The Number of Legs Encoded declared by the parsed boarding pass.
+    ///
+    /// This differs from `leg_count` only when parsed tolerating `Quirk::MissingLegs`,
+    /// until legs are added or removed.
+    pub fn declared_leg_count(&self) -> usize {
+        self.declared_leg_count.map_or(self.legs.len(), usize::from)
+    }
+
+    /// Mutable access to the legs encoded into the boarding pass.
+    /// Legs may be modified or reordered, but not added or removed.
+    pub fn legs_mut(&mut self) -> &mut [Leg] {
+        self.security_data.clear_signed_payload();
+        &mut self.legs
+    }
+
+    /// Consumes the boarding pass, returning the legs encoded into it.
+    pub fn into_legs(self) -> Vec<Leg> {
+        self.legs
+    }
+
+    /// Appends a leg to the boarding pass.
+    /// Fails if the boarding pass already contains the maximum of 4 legs.
+    pub fn push_leg(&mut self, leg: Leg) -> Result<()> {
+        if self.legs.len() >= MAX_NUMBER_OF_LEGS {
+            return Err(Error::LengthOverflow(Field::NumberOfLegsEncoded));
+        }
+
+        self.legs.push(leg);
+        self.declared_leg_count = None;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
     /// A reference to the optional security data used to verify a boarding pass was not tamptered with.
     pub fn security_data(&self) -> &SecurityData {
         &self.security_data
     }
 
+    /// The conditional items unique to the boarding pass grouped together,
+    /// including the version number, issuance details and baggage tag numbers.
+    pub fn issuance_metadata(&self) -> &ConditionalMetadata {
+        &self.metadata
+    }
+
     /// Used to differentiate between an electronic ticket ('E') and another type of travel document.
     /// Values are defined in Resolution 792.
     /// A space indicates the field is not set.
@@ -265,7 +490,7 @@ impl Bcbp {
     }
 
     /// Indicates the version number of the BCBP object.
-    /// Values are defined in Resolution 792.
+    /// Values are defined in Resolution 792, but any character is accepted as parsed.
     /// None indicates the value was not specified in the object.
     /// Some space literal indicates the field existed in the object but was not set.
     pub fn version_number(&self) -> Option<char> {
@@ -275,6 +500,7 @@ impl Bcbp {
     /// This describes the passenger.
     /// Values are defined in Resolution 792.
     /// Spaces indicate the field is not set.
+    /// A space is returned as parsed, whereas `field_value(Field::PassengerDescription)` returns None.
     pub fn passenger_description(&self) -> Option<char> {
         self.metadata.passenger_description
     }
@@ -292,6 +518,7 @@ impl Bcbp {
     /// This field reflects channel in which the customer initiated check-in.
     /// Values are defined in Resolution 792 Attachment C.
     /// Spaces indicate the field is not set.
+    /// A space is returned as parsed, whereas `field_value(Field::SourceOfCheckIn)` returns None.
     pub fn source_of_check_in(&self) -> Option<char> {
         self.metadata.source_of_check_in
     }
@@ -299,6 +526,7 @@ impl Bcbp {
     /// This field reflects channel which issued the boarding pass.
     /// Values are defined in Resolution 792.
     /// Spaces indicate the field is not set.
+    /// A space is returned as parsed, whereas `field_value(Field::SourceOfBoardingPassIssuance)` returns None.
     pub fn source_of_boarding_pass_issuance(&self) -> Option<char> {
         self.metadata.source_of_boarding_pass_issuance
     }
@@ -310,15 +538,15 @@ impl Bcbp {
     ///   "6001" represents January 1, 2016.
     ///   "6366" represents December 31, 2016 (a leap year).
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::DateOfIssueOfBoardingPass)` returns None.
     pub fn date_of_issue_of_boarding_pass(&self) -> Option<&str> {
         self.metadata
-            .date_of_issue_of_boarding_pass
-            .as_ref()
-            .map(|x| x.as_str())
+            .date_of_issue_of_boarding_pass.as_deref()
     }
 
     /// The type of the document, 'B' indicating a boarding pass.
     /// Spaces indicate the field is not set.
+    /// A space is returned as parsed, whereas `field_value(Field::DocumentType)` returns None.
     pub fn document_type(&self) -> Option<char> {
         self.metadata.document_type
     }
@@ -327,11 +555,10 @@ impl Bcbp {
     /// Two-character and three-letter IATA carrier designators
     /// are permitted and the string is left-justified and space padded.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::AirlineDesignatorOfBoardingPassIssuer)` returns None.
     pub fn airline_designator_of_boarding_pass_issuer(&self) -> Option<&str> {
         self.metadata
-            .airline_designator_of_boarding_pass_issuer
-            .as_ref()
-            .map(|x| x.as_str())
+            .airline_designator_of_boarding_pass_issuer.as_deref()
     }
 
     /// This field allows carriers to populate baggage tag numbers and the number
@@ -341,30 +568,77 @@ impl Bcbp {
     ///    5...10: carrier initial tag number with leading zeroes.
     ///   11...13: number of consecutive bags (up to 999).
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::BaggageTagLicensePlateNumbers)` returns None.
     pub fn baggage_tag_license_plate_numbers(&self) -> Option<&str> {
         self.metadata
-            .baggage_tag_license_plate_numbers
-            .as_ref()
-            .map(|x| x.as_str())
+            .baggage_tag_license_plate_numbers.as_deref()
     }
 
     /// This field allows carriers who handle non-sequential bags to include a second set of them
     /// in the boarding pass data in in the same format as `baggage_tag_license_plate_numbers`.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers)` returns None.
     pub fn first_non_consecutive_baggage_tag_license_plate_numbers(&self) -> Option<&str> {
         self.metadata
-            .first_non_consecutive_baggage_tag_license_plate_numbers
-            .as_ref()
-            .map(|x| x.as_str())
+            .first_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
     }
 
     /// This field allows carriers who handle non-sequential bags to include a third set of them
     /// in the boarding pass data in in the same format as `baggage_tag_license_plate_numbers`.
     /// Spaces indicate the field is not set.
+    /// A value of spaces is returned as parsed, whereas `field_value(Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers)` returns None.
     pub fn second_non_consecutive_baggage_tag_license_plate_numbers(&self) -> Option<&str> {
         self.metadata
-            .second_non_consecutive_baggage_tag_license_plate_numbers
-            .as_ref()
-            .map(|x| x.as_str())
+            .second_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
     }
 }
+
+/// The capacity of the storage of a field, identified by a projection from its container.
+const fn capacity<S, const N: usize>(_: fn(&S) -> &FixedString<N>) -> usize {
+    N
+}
+
+/// The capacity of the storage of an optional field, identified by a projection from its container.
+const fn optional_capacity<S, const N: usize>(_: fn(&S) -> &Option<FixedString<N>>) -> usize {
+    N
+}
+
+// The storage of each fixed-length field must match its length exactly for the parser to fill it.
+// Airport codes are stored with an additional character for `Quirk::FourCharacterAirportCodes`.
+const _: () = {
+    assert!(capacity(|leg: &Leg| &leg.operating_carrier_pnr_code) == Field::OperatingCarrierPnrCode.len());
+    assert!(capacity(|leg: &Leg| &leg.from_city_airport_code) == Field::FromCityAirportCode.len() + 1);
+    assert!(capacity(|leg: &Leg| &leg.to_city_airport_code) == Field::ToCityAirportCode.len() + 1);
+    assert!(capacity(|leg: &Leg| &leg.operating_carrier_designator) == Field::OperatingCarrierDesignator.len());
+    assert!(capacity(|leg: &Leg| &leg.flight_number) == Field::FlightNumber.len());
+    assert!(capacity(|leg: &Leg| &leg.date_of_flight) == Field::DateOfFlight.len());
+    assert!(capacity(|leg: &Leg| &leg.seat_number) == Field::SeatNumber.len());
+    assert!(capacity(|leg: &Leg| &leg.check_in_sequence_number) == Field::CheckInSequenceNumber.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.airline_numeric_code) == Field::AirlineNumericCode.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.document_form_serial_number) == Field::DocumentFormSerialNumber.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.marketing_carrier_designator) == Field::MarketingCarrierDesignator.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.frequent_flyer_airline_designator) == Field::FrequentFlyerAirlineDesignator.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.frequent_flyer_number) == Field::FrequentFlyerNumber.len());
+    assert!(optional_capacity(|leg: &Leg| &leg.free_baggage_allowance) == Field::FreeBaggageAllowance.len());
+    assert!(capacity(|pass: &Bcbp| &pass.passenger_name) == Field::PassengerName.len());
+    assert!(
+        optional_capacity(|metadata: &ConditionalMetadata| &metadata.date_of_issue_of_boarding_pass)
+            == Field::DateOfIssueOfBoardingPass.len()
+    );
+    assert!(
+        optional_capacity(|metadata: &ConditionalMetadata| &metadata.airline_designator_of_boarding_pass_issuer)
+            == Field::AirlineDesignatorOfBoardingPassIssuer.len()
+    );
+    assert!(
+        optional_capacity(|metadata: &ConditionalMetadata| &metadata.baggage_tag_license_plate_numbers)
+            == Field::BaggageTagLicensePlateNumbers.len()
+    );
+    assert!(
+        optional_capacity(|metadata: &ConditionalMetadata| &metadata.first_non_consecutive_baggage_tag_license_plate_numbers)
+            == Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers.len()
+    );
+    assert!(
+        optional_capacity(|metadata: &ConditionalMetadata| &metadata.second_non_consecutive_baggage_tag_license_plate_numbers)
+            == Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers.len()
+    );
+};
@@ -0,0 +1,160 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, ConditionalMetadata, Leg};
+use crate::de::field::Field;
+use crate::fixed_string::FixedString;
+
+/// A change made to the value of a field by `Bcbp::normalized`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Normalization {
+    field: Field,
+    leg_index: Option<usize>,
+    original: String,
+    normalized: String,
+}
+
+impl Normalization {
+    /// The field which was changed.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The index of the leg containing the field, or `None` for fields of the boarding pass.
+    pub fn leg_index(&self) -> Option<usize> {
+        self.leg_index
+    }
+
+    /// The value of the field before normalization.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    /// The value of the field after normalization.
+    pub fn normalized(&self) -> &str {
+        &self.normalized
+    }
+}
+
+/// Normalizes field values in place, recording each change made.
+struct Normalizer {
+    leg_index: Option<usize>,
+    changes: Vec<Normalization>,
+}
+
+impl Normalizer {
+    /// Records a change to `field` if `normalized` differs from `original`.
+    fn record(&mut self, field: Field, original: &str, normalized: &str) -> bool {
+        if original == normalized {
+            return false;
+        }
+        self.changes.push(Normalization {
+            field,
+            leg_index: self.leg_index,
+            original: String::from(original),
+            normalized: String::from(normalized),
+        });
+        true
+    }
+
+    /// Uppercases `value` and pads it with spaces to the length of `field`.
+    fn str_field<const N: usize>(&mut self, field: Field, value: &mut FixedString<N>) {
+        let mut normalized = FixedString::<N>::new();
+        normalized.push_str(&value.to_ascii_uppercase());
+        while normalized.len() < field.len() {
+            normalized.push(' ');
+        }
+        if self.record(field, value, &normalized) {
+            *value = normalized;
+        }
+    }
+
+    /// Normalizes an optional `value` as in `str_field`.
+    fn optional_str_field<const N: usize>(&mut self, field: Field, value: &mut Option<FixedString<N>>) {
+        if let Some(value) = value {
+            self.str_field(field, value);
+        }
+    }
+
+    /// Uppercases `value`, replacing an unset NUL character with a space.
+    fn chr_field(&mut self, field: Field, value: &mut char) {
+        let normalized = if *value == '\0' { ' ' } else { value.to_ascii_uppercase() };
+        if self.record(field, &value.to_string(), &normalized.to_string()) {
+            *value = normalized;
+        }
+    }
+
+    /// Normalizes an optional `value` as in `chr_field`.
+    fn optional_chr_field(&mut self, field: Field, value: &mut Option<char>) {
+        if let Some(value) = value {
+            self.chr_field(field, value);
+        }
+    }
+
+    fn metadata(&mut self, metadata: &mut ConditionalMetadata) {
+        self.optional_chr_field(Field::VersionNumber, &mut metadata.version_number);
+        self.optional_chr_field(Field::PassengerDescription, &mut metadata.passenger_description);
+        self.optional_chr_field(Field::SourceOfCheckIn, &mut metadata.source_of_check_in);
+        self.optional_chr_field(Field::SourceOfBoardingPassIssuance, &mut metadata.source_of_boarding_pass_issuance);
+        self.optional_str_field(Field::DateOfIssueOfBoardingPass, &mut metadata.date_of_issue_of_boarding_pass);
+        self.optional_chr_field(Field::DocumentType, &mut metadata.document_type);
+        self.optional_str_field(Field::AirlineDesignatorOfBoardingPassIssuer, &mut metadata.airline_designator_of_boarding_pass_issuer);
+        self.optional_str_field(Field::BaggageTagLicensePlateNumbers, &mut metadata.baggage_tag_license_plate_numbers);
+        self.optional_str_field(Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers, &mut metadata.first_non_consecutive_baggage_tag_license_plate_numbers);
+        self.optional_str_field(Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers, &mut metadata.second_non_consecutive_baggage_tag_license_plate_numbers);
+    }
+
+    fn leg(&mut self, leg: &mut Leg) {
+        self.str_field(Field::OperatingCarrierPnrCode, &mut leg.operating_carrier_pnr_code);
+        self.str_field(Field::FromCityAirportCode, &mut leg.from_city_airport_code);
+        self.str_field(Field::ToCityAirportCode, &mut leg.to_city_airport_code);
+        self.str_field(Field::OperatingCarrierDesignator, &mut leg.operating_carrier_designator);
+        self.str_field(Field::FlightNumber, &mut leg.flight_number);
+        self.str_field(Field::DateOfFlight, &mut leg.date_of_flight);
+        self.chr_field(Field::CompartmentCode, &mut leg.compartment_code);
+        self.str_field(Field::SeatNumber, &mut leg.seat_number);
+        self.str_field(Field::CheckInSequenceNumber, &mut leg.check_in_sequence_number);
+        self.chr_field(Field::PassengerStatus, &mut leg.passenger_status);
+        self.optional_str_field(Field::AirlineNumericCode, &mut leg.airline_numeric_code);
+        self.optional_str_field(Field::DocumentFormSerialNumber, &mut leg.document_form_serial_number);
+        self.optional_chr_field(Field::SelecteeIndicator, &mut leg.selectee_indicator);
+        self.optional_chr_field(Field::InternationalDocumentVerification, &mut leg.international_document_verification);
+        self.optional_str_field(Field::MarketingCarrierDesignator, &mut leg.marketing_carrier_designator);
+        self.optional_str_field(Field::FrequentFlyerAirlineDesignator, &mut leg.frequent_flyer_airline_designator);
+        self.optional_str_field(Field::FrequentFlyerNumber, &mut leg.frequent_flyer_number);
+        self.optional_chr_field(Field::IdAdIndicator, &mut leg.id_ad_indicator);
+        self.optional_str_field(Field::FreeBaggageAllowance, &mut leg.free_baggage_allowance);
+        self.optional_chr_field(Field::FastTrack, &mut leg.fast_track);
+    }
+}
+
+impl Bcbp {
+    /// Returns a canonical copy of the boarding pass along with the changes made to produce it.
+    ///
+    /// Field values are uppercased and space-padded to the width defined by Resolution 792,
+    /// and unset single-character fields are replaced with spaces. Airline individual use
+    /// and security data are opaque and left unchanged.
+    pub fn normalized(&self) -> (Bcbp, Vec<Normalization>) {
+        let mut boarding_pass = self.clone();
+        let mut normalizer = Normalizer {
+            leg_index: None,
+            changes: Vec::new(),
+        };
+
+        normalizer.str_field(Field::PassengerName, &mut boarding_pass.passenger_name);
+        normalizer.chr_field(Field::ElectronicTicketIndicator, &mut boarding_pass.electronic_ticket_indicator);
+        normalizer.metadata(&mut boarding_pass.metadata);
+        for (leg_index, leg) in boarding_pass.legs.iter_mut().enumerate() {
+            normalizer.leg_index = Some(leg_index);
+            normalizer.leg(leg);
+        }
+
+        if !normalizer.changes.is_empty() {
+            boarding_pass.security_data.clear_signed_payload();
+        }
+
+        (boarding_pass, normalizer.changes)
+    }
+}
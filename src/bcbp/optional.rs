@@ -0,0 +1,49 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::dynamic::non_blank;
+use crate::bcbp::Leg;
+
+/// Returns `value` unless it is a space, which indicates the field is not set.
+fn non_blank_char(value: char) -> Option<char> {
+    Some(value).filter(|&c| c != ' ')
+}
+
+impl Leg {
+    /// The operating carrier designator, or None if the field is not set.
+    pub fn operating_carrier_designator_opt(&self) -> Option<&str> {
+        non_blank(Some(self.operating_carrier_designator()))
+    }
+
+    /// The flight number, or None if the field is not set.
+    pub fn flight_number_opt(&self) -> Option<&str> {
+        non_blank(Some(self.flight_number()))
+    }
+
+    /// The Julian date code for the flight, or None if the field is not set.
+    pub fn date_of_flight_opt(&self) -> Option<&str> {
+        non_blank(Some(self.date_of_flight()))
+    }
+
+    /// The compartment code, or None if the field is not set.
+    pub fn compartment_code_opt(&self) -> Option<char> {
+        non_blank_char(self.compartment_code())
+    }
+
+    /// The seat number of the passenger, or None if the field is not set.
+    pub fn seat_number_opt(&self) -> Option<&str> {
+        non_blank(Some(self.seat_number()))
+    }
+
+    /// The check-in sequence number, or None if the field is not set.
+    pub fn check_in_sequence_number_opt(&self) -> Option<&str> {
+        non_blank(Some(self.check_in_sequence_number()))
+    }
+
+    /// The passenger status, or None if the field is not set.
+    pub fn passenger_status_opt(&self) -> Option<char> {
+        non_blank_char(self.passenger_status())
+    }
+}
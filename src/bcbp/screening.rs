@@ -0,0 +1,125 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Leg;
+
+/// Selectee Indicator, Item 18.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum SelecteeIndicator {
+    NotSelectee,
+    Selectee,
+    KnownPassenger,
+    Other(char),
+}
+
+impl From<char> for SelecteeIndicator {
+    fn from(code: char) -> Self {
+        match code {
+            '0' => SelecteeIndicator::NotSelectee,
+            '1' => SelecteeIndicator::Selectee,
+            '3' => SelecteeIndicator::KnownPassenger,
+            other => SelecteeIndicator::Other(other),
+        }
+    }
+}
+
+impl From<SelecteeIndicator> for char {
+    fn from(indicator: SelecteeIndicator) -> Self {
+        match indicator {
+            SelecteeIndicator::NotSelectee => '0',
+            SelecteeIndicator::Selectee => '1',
+            SelecteeIndicator::KnownPassenger => '3',
+            SelecteeIndicator::Other(other) => other,
+        }
+    }
+}
+
+/// International Document Verification, Item 108.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum DocumentVerification {
+    NotRequired,
+    Required,
+    Completed,
+    Other(char),
+}
+
+impl From<char> for DocumentVerification {
+    fn from(code: char) -> Self {
+        match code {
+            '0' => DocumentVerification::NotRequired,
+            '1' => DocumentVerification::Required,
+            '2' => DocumentVerification::Completed,
+            other => DocumentVerification::Other(other),
+        }
+    }
+}
+
+impl From<DocumentVerification> for char {
+    fn from(verification: DocumentVerification) -> Self {
+        match verification {
+            DocumentVerification::NotRequired => '0',
+            DocumentVerification::Required => '1',
+            DocumentVerification::Completed => '2',
+            DocumentVerification::Other(other) => other,
+        }
+    }
+}
+
+/// The security screening items of a leg, with fields which are not set reported as `None`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ScreeningSummary {
+    selectee_indicator: Option<SelecteeIndicator>,
+    international_document_verification: Option<DocumentVerification>,
+    fast_track: Option<bool>,
+}
+
+impl ScreeningSummary {
+    /// The type of screening the passenger will receive at US airports.
+    pub fn selectee_indicator(&self) -> Option<SelecteeIndicator> {
+        self.selectee_indicator
+    }
+
+    /// Whether the passenger requires document verification.
+    pub fn international_document_verification(&self) -> Option<DocumentVerification> {
+        self.international_document_verification
+    }
+
+    /// Whether the passenger is eligible for fast track.
+    /// Values other than 'Y' and 'N' are reported as `None`.
+    pub fn fast_track(&self) -> Option<bool> {
+        self.fast_track
+    }
+
+    /// Returns `true` if the passenger has been selected for additional screening.
+    pub fn requires_additional_screening(&self) -> bool {
+        self.selectee_indicator == Some(SelecteeIndicator::Selectee)
+    }
+}
+
+/// Returns `value` unless it is a space, which indicates the field is not set.
+fn set(value: Option<char>) -> Option<char> {
+    value.filter(|&c| c != ' ')
+}
+
+impl Leg {
+    /// The International Document Verification item, or `None` if it is not set.
+    /// `DocumentVerification::Completed` corresponds to the display of 'DOCS OK'.
+    pub fn document_verification(&self) -> Option<DocumentVerification> {
+        set(self.international_document_verification()).map(DocumentVerification::from)
+    }
+
+    /// The selectee indicator, international document verification and fast track items combined.
+    pub fn screening_summary(&self) -> ScreeningSummary {
+        ScreeningSummary {
+            selectee_indicator: set(self.selectee_indicator()).map(SelecteeIndicator::from),
+            international_document_verification: self.document_verification(),
+            fast_track: match self.fast_track() {
+                Some('Y') => Some(true),
+                Some('N') => Some(false),
+                _ => None,
+            },
+        }
+    }
+}
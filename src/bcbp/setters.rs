@@ -0,0 +1,367 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, Leg};
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+use crate::fixed_string::FixedString;
+
+/// Returns `true` if `c` conforms to the data format character `format`, or is a space.
+fn conforms(format: char, c: char) -> bool {
+    match format {
+        'N' => c.is_ascii_digit() || c == ' ',
+        'a' => c.is_ascii_alphabetic() || c == ' ',
+        _ => true,
+    }
+}
+
+/// Validates `value` against the length and data format of the specified field.
+///
+/// Values must be ASCII and no longer than the field. Each character must match the data
+/// format at its position or be a space, with a single-character format applying to all
+/// characters. The seat number of an infant may also be 'INF'.
+pub(crate) fn validate(field_id: Field, value: &str) -> Result<()> {
+    if !value.is_ascii() {
+        return Err(Error::InvalidCharacters);
+    }
+    if field_id.len() > 0 && value.len() > field_id.len() {
+        return Err(Error::LengthOverflow(field_id));
+    }
+    if field_id == Field::SeatNumber && value.trim_end().eq_ignore_ascii_case("INF") {
+        return Ok(());
+    }
+
+    // Brackets only mark a trailing character as optional, which a space already satisfies.
+    let mut format = field_id.data_format().chars().filter(|&c| c != '[' && c != ']');
+    let mut current_format = 'f';
+    let is_valid = value.chars().all(|c| {
+        if let Some(next_format) = format.next() {
+            current_format = next_format;
+        }
+        conforms(current_format, c)
+    });
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidFieldValue(field_id))
+    }
+}
+
+/// Validates `value` and returns it left-aligned and space-padded to the length of the field.
+fn str_field<const N: usize>(field_id: Field, value: &str) -> Result<FixedString<N>> {
+    validate(field_id, value)?;
+
+    let mut field_value = FixedString::new();
+    field_value.push_str(value);
+    while field_value.len() < field_id.len() {
+        field_value.push(' ');
+    }
+
+    Ok(field_value)
+}
+
+/// Validates an optional `value` as in `str_field`.
+fn optional_str_field<const N: usize>(field_id: Field, value: Option<&str>) -> Result<Option<FixedString<N>>> {
+    value.map(|value| str_field(field_id, value)).transpose()
+}
+
+/// Validates a single-character `value` against the data format of the field.
+fn chr_field(field_id: Field, value: char) -> Result<char> {
+    let mut buffer = [0; 4];
+    validate(field_id, value.encode_utf8(&mut buffer))?;
+    Ok(value)
+}
+
+/// Validates an optional single-character `value` as in `chr_field`.
+fn optional_chr_field(field_id: Field, value: Option<char>) -> Result<Option<char>> {
+    value.map(|value| chr_field(field_id, value)).transpose()
+}
+
+impl Leg {
+    /// Sets the operating carrier PNR code, up to 7 characters, from a string or a `PnrCode`.
+    pub fn set_operating_carrier_pnr_code<V: AsRef<str>>(&mut self, value: V) -> Result<()> {
+        self.operating_carrier_pnr_code = str_field(Field::OperatingCarrierPnrCode, value.as_ref())?;
+        Ok(())
+    }
+
+    /// Sets the origin airport code, up to 3 alphabetic characters, from a string or an `AirportCode`.
+    pub fn set_from_city_airport_code<V: AsRef<str>>(&mut self, value: V) -> Result<()> {
+        self.from_city_airport_code = str_field(Field::FromCityAirportCode, value.as_ref())?;
+        Ok(())
+    }
+
+    /// Sets the destination airport code, up to 3 alphabetic characters, from a string or an `AirportCode`.
+    pub fn set_to_city_airport_code<V: AsRef<str>>(&mut self, value: V) -> Result<()> {
+        self.to_city_airport_code = str_field(Field::ToCityAirportCode, value.as_ref())?;
+        Ok(())
+    }
+
+    /// Sets the operating carrier designator, up to 3 characters, from a string or a `CarrierDesignator`.
+    pub fn set_operating_carrier_designator<V: AsRef<str>>(&mut self, value: V) -> Result<()> {
+        self.operating_carrier_designator = str_field(Field::OperatingCarrierDesignator, value.as_ref())?;
+        Ok(())
+    }
+
+    /// Sets the flight number, up to 5 characters.
+    pub fn set_flight_number(&mut self, value: &str) -> Result<()> {
+        self.flight_number = str_field(Field::FlightNumber, value)?;
+        Ok(())
+    }
+
+    /// Sets the Julian date of the flight, up to 3 numeric characters, from a string or a `JulianDate`.
+    pub fn set_date_of_flight<V: AsRef<str>>(&mut self, value: V) -> Result<()> {
+        self.date_of_flight = str_field(Field::DateOfFlight, value.as_ref())?;
+        Ok(())
+    }
+
+    /// Sets the compartment code, an alphabetic character or a space.
+    pub fn set_compartment_code(&mut self, value: char) -> Result<()> {
+        self.compartment_code = chr_field(Field::CompartmentCode, value)?;
+        Ok(())
+    }
+
+    /// Sets the seat number, up to 4 characters.
+    pub fn set_seat_number(&mut self, value: &str) -> Result<()> {
+        self.seat_number = str_field(Field::SeatNumber, value)?;
+        Ok(())
+    }
+
+    /// Sets the check-in sequence number, up to 5 characters.
+    pub fn set_check_in_sequence_number(&mut self, value: &str) -> Result<()> {
+        self.check_in_sequence_number = str_field(Field::CheckInSequenceNumber, value)?;
+        Ok(())
+    }
+
+    /// Sets the passenger status.
+    pub fn set_passenger_status(&mut self, value: char) -> Result<()> {
+        self.passenger_status = chr_field(Field::PassengerStatus, value)?;
+        Ok(())
+    }
+
+    /// Sets the airline numeric code, up to 3 numeric characters.
+    pub fn set_airline_numeric_code(&mut self, value: Option<&str>) -> Result<()> {
+        self.airline_numeric_code = optional_str_field(Field::AirlineNumericCode, value)?;
+        Ok(())
+    }
+
+    /// Sets the document form/serial number, up to 10 characters.
+    pub fn set_document_form_serial_number(&mut self, value: Option<&str>) -> Result<()> {
+        self.document_form_serial_number = optional_str_field(Field::DocumentFormSerialNumber, value)?;
+        Ok(())
+    }
+
+    /// Sets the selectee indicator.
+    pub fn set_selectee_indicator(&mut self, value: Option<char>) -> Result<()> {
+        self.selectee_indicator = optional_chr_field(Field::SelecteeIndicator, value)?;
+        Ok(())
+    }
+
+    /// Sets the international document verification indicator.
+    pub fn set_international_document_verification(&mut self, value: Option<char>) -> Result<()> {
+        self.international_document_verification = optional_chr_field(Field::InternationalDocumentVerification, value)?;
+        Ok(())
+    }
+
+    /// Sets the marketing carrier designator, up to 3 characters.
+    pub fn set_marketing_carrier_designator(&mut self, value: Option<&str>) -> Result<()> {
+        self.marketing_carrier_designator = optional_str_field(Field::MarketingCarrierDesignator, value)?;
+        Ok(())
+    }
+
+    /// Sets the frequent flyer airline designator, up to 3 characters.
+    pub fn set_frequent_flyer_airline_designator(&mut self, value: Option<&str>) -> Result<()> {
+        self.frequent_flyer_airline_designator = optional_str_field(Field::FrequentFlyerAirlineDesignator, value)?;
+        Ok(())
+    }
+
+    /// Sets the frequent flyer number, up to 16 characters.
+    pub fn set_frequent_flyer_number(&mut self, value: Option<&str>) -> Result<()> {
+        self.frequent_flyer_number = optional_str_field(Field::FrequentFlyerNumber, value)?;
+        Ok(())
+    }
+
+    /// Sets the ID/AD indicator.
+    pub fn set_id_ad_indicator(&mut self, value: Option<char>) -> Result<()> {
+        self.id_ad_indicator = optional_chr_field(Field::IdAdIndicator, value)?;
+        Ok(())
+    }
+
+    /// Sets the free baggage allowance, up to 3 characters.
+    pub fn set_free_baggage_allowance(&mut self, value: Option<&str>) -> Result<()> {
+        self.free_baggage_allowance = optional_str_field(Field::FreeBaggageAllowance, value)?;
+        Ok(())
+    }
+
+    /// Sets the fast track indicator.
+    pub fn set_fast_track(&mut self, value: Option<char>) -> Result<()> {
+        self.fast_track = optional_chr_field(Field::FastTrack, value)?;
+        Ok(())
+    }
+
+    /// Sets the unstructured data for airline individual use.
+    /// An empty string is equivalent to None.
+    pub fn set_airline_individual_use(&mut self, value: Option<&str>) -> Result<()> {
+        if let Some(value) = value {
+            validate(Field::AirlineIndividualUse, value)?;
+        }
+        self.airline_individual_use = value
+            .filter(|value| !value.is_empty())
+            .map(String::from);
+        Ok(())
+    }
+}
+
+impl Bcbp {
+    /// Sets the passenger name, up to 20 characters.
+    pub fn set_passenger_name(&mut self, value: &str) -> Result<()> {
+        self.passenger_name = str_field(Field::PassengerName, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the electronic ticket indicator.
+    pub fn set_electronic_ticket_indicator(&mut self, value: char) -> Result<()> {
+        self.electronic_ticket_indicator = chr_field(Field::ElectronicTicketIndicator, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the version number.
+    pub fn set_version_number(&mut self, value: Option<char>) -> Result<()> {
+        self.metadata.version_number = optional_chr_field(Field::VersionNumber, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the passenger description.
+    pub fn set_passenger_description(&mut self, value: Option<char>) -> Result<()> {
+        self.metadata.passenger_description = optional_chr_field(Field::PassengerDescription, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the source of check-in.
+    pub fn set_source_of_check_in(&mut self, value: Option<char>) -> Result<()> {
+        self.metadata.source_of_check_in = optional_chr_field(Field::SourceOfCheckIn, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the source of boarding pass issuance.
+    pub fn set_source_of_boarding_pass_issuance(&mut self, value: Option<char>) -> Result<()> {
+        self.metadata.source_of_boarding_pass_issuance = optional_chr_field(Field::SourceOfBoardingPassIssuance, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the 4-digit Julian date of issue of the boarding pass.
+    pub fn set_date_of_issue_of_boarding_pass(&mut self, value: Option<&str>) -> Result<()> {
+        self.metadata.date_of_issue_of_boarding_pass = optional_str_field(Field::DateOfIssueOfBoardingPass, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the document type.
+    pub fn set_document_type(&mut self, value: Option<char>) -> Result<()> {
+        self.metadata.document_type = optional_chr_field(Field::DocumentType, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the airline designator of the boarding pass issuer, up to 3 characters.
+    pub fn set_airline_designator_of_boarding_pass_issuer(&mut self, value: Option<&str>) -> Result<()> {
+        self.metadata.airline_designator_of_boarding_pass_issuer = optional_str_field(Field::AirlineDesignatorOfBoardingPassIssuer, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the baggage tag license plate number(s), up to 13 characters.
+    pub fn set_baggage_tag_license_plate_numbers(&mut self, value: Option<&str>) -> Result<()> {
+        self.metadata.baggage_tag_license_plate_numbers = optional_str_field(Field::BaggageTagLicensePlateNumbers, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the first non-consecutive baggage tag license plate number, up to 13 characters.
+    pub fn set_first_non_consecutive_baggage_tag_license_plate_numbers(&mut self, value: Option<&str>) -> Result<()> {
+        self.metadata.first_non_consecutive_baggage_tag_license_plate_numbers = optional_str_field(Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+
+    /// Sets the second non-consecutive baggage tag license plate number, up to 13 characters.
+    pub fn set_second_non_consecutive_baggage_tag_license_plate_numbers(&mut self, value: Option<&str>) -> Result<()> {
+        self.metadata.second_non_consecutive_baggage_tag_license_plate_numbers = optional_str_field(Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers, value)?;
+        self.security_data.clear_signed_payload();
+        Ok(())
+    }
+}
+
+/// Returns the single character of `value`, as required of single-character fields.
+pub(crate) fn single_chr(field_id: Field, value: &str) -> Result<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(Error::InvalidFieldValue(field_id)),
+    }
+}
+
+impl Leg {
+    /// Sets a field encoded in the leg, identified dynamically, through its setter.
+    /// Mandatory fields cannot be unset.
+    pub fn set_field(&mut self, field_id: Field, value: Option<&str>) -> Result<()> {
+        let chr = |value: Option<&str>| value.map(|value| single_chr(field_id, value)).transpose();
+        let mandatory = || value.ok_or(Error::MissingField(field_id));
+        match field_id {
+            Field::OperatingCarrierPnrCode => self.set_operating_carrier_pnr_code(mandatory()?),
+            Field::FromCityAirportCode => self.set_from_city_airport_code(mandatory()?),
+            Field::ToCityAirportCode => self.set_to_city_airport_code(mandatory()?),
+            Field::OperatingCarrierDesignator => self.set_operating_carrier_designator(mandatory()?),
+            Field::FlightNumber => self.set_flight_number(mandatory()?),
+            Field::DateOfFlight => self.set_date_of_flight(mandatory()?),
+            Field::CompartmentCode => self.set_compartment_code(single_chr(field_id, mandatory()?)?),
+            Field::SeatNumber => self.set_seat_number(mandatory()?),
+            Field::CheckInSequenceNumber => self.set_check_in_sequence_number(mandatory()?),
+            Field::PassengerStatus => self.set_passenger_status(single_chr(field_id, mandatory()?)?),
+            Field::AirlineNumericCode => self.set_airline_numeric_code(value),
+            Field::DocumentFormSerialNumber => self.set_document_form_serial_number(value),
+            Field::SelecteeIndicator => self.set_selectee_indicator(chr(value)?),
+            Field::InternationalDocumentVerification => self.set_international_document_verification(chr(value)?),
+            Field::MarketingCarrierDesignator => self.set_marketing_carrier_designator(value),
+            Field::FrequentFlyerAirlineDesignator => self.set_frequent_flyer_airline_designator(value),
+            Field::FrequentFlyerNumber => self.set_frequent_flyer_number(value),
+            Field::IdAdIndicator => self.set_id_ad_indicator(chr(value)?),
+            Field::FreeBaggageAllowance => self.set_free_baggage_allowance(value),
+            Field::FastTrack => self.set_fast_track(chr(value)?),
+            Field::AirlineIndividualUse => self.set_airline_individual_use(value),
+            _ => Err(Error::UnknownField),
+        }
+    }
+}
+
+impl Bcbp {
+    /// Sets a field unique to the boarding pass, identified dynamically, through its setter.
+    /// Mandatory fields cannot be unset, and the security data is set with `set_security_data`.
+    pub fn set_field(&mut self, field_id: Field, value: Option<&str>) -> Result<()> {
+        let chr = |value: Option<&str>| value.map(|value| single_chr(field_id, value)).transpose();
+        let mandatory = || value.ok_or(Error::MissingField(field_id));
+        match field_id {
+            Field::PassengerName => self.set_passenger_name(mandatory()?),
+            Field::ElectronicTicketIndicator => self.set_electronic_ticket_indicator(single_chr(field_id, mandatory()?)?),
+            Field::VersionNumber => self.set_version_number(chr(value)?),
+            Field::PassengerDescription => self.set_passenger_description(chr(value)?),
+            Field::SourceOfCheckIn => self.set_source_of_check_in(chr(value)?),
+            Field::SourceOfBoardingPassIssuance => self.set_source_of_boarding_pass_issuance(chr(value)?),
+            Field::DateOfIssueOfBoardingPass => self.set_date_of_issue_of_boarding_pass(value),
+            Field::DocumentType => self.set_document_type(chr(value)?),
+            Field::AirlineDesignatorOfBoardingPassIssuer => self.set_airline_designator_of_boarding_pass_issuer(value),
+            Field::BaggageTagLicensePlateNumbers => self.set_baggage_tag_license_plate_numbers(value),
+            Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers => self.set_first_non_consecutive_baggage_tag_license_plate_numbers(value),
+            Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers => self.set_second_non_consecutive_baggage_tag_license_plate_numbers(value),
+            _ => Err(Error::UnknownField),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Leg;
+
+impl Leg {
+    /// Returns `true` if a seat is assigned, being neither blank nor infant seating.
+    pub fn has_seat_assigned(&self) -> bool {
+        !self.seat_number().trim().is_empty() && !self.is_infant_seating()
+    }
+
+    /// Returns `true` if the passenger has checked in, with Passenger Status '1' or '3',
+    /// or has since passed the security check ('4') or the gate ('5').
+    pub fn is_checked_in(&self) -> bool {
+        matches!(self.passenger_status(), '1' | '3' | '4' | '5')
+    }
+
+    /// Returns `true` if the passenger is on standby, with Passenger Status '7'.
+    pub fn is_standby(&self) -> bool {
+        self.passenger_status() == '7'
+    }
+
+    /// Returns `true` if the passenger has passed the gate exit and the coupon is used,
+    /// with Passenger Status '5'.
+    pub fn is_boarded(&self) -> bool {
+        self.passenger_status() == '5'
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Leg;
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+#[cfg(feature = "reference_data")]
+use crate::reference_data::AirlineInfo;
+
+/// Checks that `value` consists of digits, or when not `strict`, that it is entirely spaces.
+fn check_numeric(field: Field, value: Option<&str>, strict: bool) -> Result<()> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    let is_numeric = value.len() == field.len() && value.bytes().all(|b| b.is_ascii_digit());
+    let is_unset = !strict && value.bytes().all(|b| b == b' ');
+    if is_numeric || is_unset {
+        Ok(())
+    } else {
+        Err(Error::InvalidFieldValue(field))
+    }
+}
+
+impl Leg {
+    /// Checks the plausibility of the eTicket number of the leg, comprising the three-digit
+    /// Airline Numeric Code and the ten-digit Document Form/Serial Number.
+    ///
+    /// Each field present must consist entirely of digits. Unless `strict`, a field consisting
+    /// entirely of spaces is also accepted as not set.
+    pub fn validate_ticket_number(&self, strict: bool) -> Result<()> {
+        check_numeric(Field::AirlineNumericCode, self.airline_numeric_code(), strict)?;
+        check_numeric(Field::DocumentFormSerialNumber, self.document_form_serial_number(), strict)
+    }
+
+    /// Checks that a listed Airline Numeric Code belongs to the operating or marketing carrier of the leg.
+    ///
+    /// Interline and codeshare tickets are routinely issued by a third airline, so a mismatch does not
+    /// make a boarding pass invalid and this check is not part of `validate_ticket_number`. Codes which
+    /// are not listed are accepted.
+    #[cfg(feature = "reference_data")]
+    pub fn validate_ticket_carrier(&self) -> Result<()> {
+        let airline = match self.airline_numeric_code().and_then(AirlineInfo::lookup_numeric_code) {
+            Some(airline) => airline,
+            None => return Ok(()),
+        };
+
+        let carriers = [Some(self.operating_carrier_designator()), self.marketing_carrier_designator()];
+        let is_carrier = carriers.iter()
+            .flatten()
+            .any(|designator| designator.trim().eq_ignore_ascii_case(airline.iata_designator()));
+        if is_carrier {
+            Ok(())
+        } else {
+            Err(Error::InvalidFieldValue(Field::AirlineNumericCode))
+        }
+    }
+}
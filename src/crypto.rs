@@ -0,0 +1,58 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature, VerifyingKey};
+
+use crate::security::SecuritySignatureVerifier;
+
+/// Verifies security data consisting of an ECDSA signature over the NIST P-256 curve with SHA-256,
+/// the scheme most commonly deployed by airlines.
+///
+/// The signature covers the signed payload of the boarding pass, and is encoded in the security data
+/// in Base64 as either an ASN.1 DER signature or the 64-byte concatenation of its `r` and `s` values.
+#[derive(Clone, Debug)]
+pub struct EcdsaP256Verifier {
+    verifying_key: VerifyingKey,
+    type_of_security_data: Option<char>,
+}
+
+impl EcdsaP256Verifier {
+    /// Returns a verifier for the public key of the issuing airline, encoded as a SEC1 point
+    /// in compressed or uncompressed form. Returns None if the key is not a valid P-256 point.
+    pub fn from_sec1_bytes(public_key: &[u8]) -> Option<Self> {
+        VerifyingKey::from_sec1_bytes(public_key).ok().map(|verifying_key| EcdsaP256Verifier {
+            verifying_key,
+            type_of_security_data: None,
+        })
+    }
+
+    /// Accepts only security data of the vendor-specific type `type_of_security_data`,
+    /// for airlines identifying their key by the type.
+    pub fn with_type_of_security_data(mut self, type_of_security_data: char) -> Self {
+        self.type_of_security_data = Some(type_of_security_data);
+        self
+    }
+}
+
+impl SecuritySignatureVerifier for EcdsaP256Verifier {
+    fn verify(&self, type_of_security_data: char, signed_payload: &[u8], signature: &str) -> bool {
+        if self.type_of_security_data.is_some_and(|expected| expected != type_of_security_data) {
+            return false;
+        }
+
+        let signature = match STANDARD.decode(signature.trim_end()) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        let signature = match Signature::from_der(&signature).or_else(|_| Signature::from_slice(&signature)) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+        self.verifying_key.verify(signed_payload, &signature).is_ok()
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::ops::Range;
+
+use crate::bcbp::setters;
+use crate::de::field::Field;
+
+/// A field located within boarding pass data by `explain`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Annotation {
+    range: Range<usize>,
+    field: Field,
+    value: String,
+    is_valid: bool,
+}
+
+impl Annotation {
+    /// The byte range of the field within the input.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The field located at the range.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The value of the field.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns `true` if the value is complete and conforms to the data format of the field.
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+}
+
+/// Walks the layout of the input, annotating each field encountered.
+struct Explainer<'a> {
+    input: &'a [u8],
+    offset: usize,
+    annotations: Vec<Annotation>,
+}
+
+impl<'a> Explainer<'a> {
+    /// Annotates `field` spanning up to `len` bytes but not past `end`, advancing past it.
+    ///
+    /// Returns the value of the field if it is complete, even if it does not conform to its data format.
+    fn field(&mut self, field: Field, len: usize, end: usize) -> Option<&'a str> {
+        let field_end = end.min(self.offset + len);
+        let bytes = &self.input[self.offset .. field_end];
+        let value = std::str::from_utf8(bytes).ok().filter(|_| bytes.len() == len);
+        let is_valid = value.is_some_and(|value| setters::validate(field, value).is_ok());
+
+        self.annotations.push(Annotation {
+            range: self.offset .. field_end,
+            field,
+            value: String::from_utf8_lossy(bytes).into_owned(),
+            is_valid,
+        });
+        self.offset = field_end;
+
+        value
+    }
+
+    /// Annotates a field which must have exactly the value `expected`.
+    fn literal(&mut self, field: Field, expected: &str, end: usize) -> Option<()> {
+        let is_expected = self.field(field, expected.len(), end) == Some(expected);
+        if !is_expected {
+            self.invalidate_last();
+            return None;
+        }
+        Some(())
+    }
+
+    /// Annotates a hexadecimal size field, returning the end of the data it describes if within `end`.
+    fn size(&mut self, field: Field, digits: usize, end: usize) -> Option<usize> {
+        let value = self.field(field, digits, end)?;
+        let size = usize::from_str_radix(value, 16).ok()
+            .filter(|_| value.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_uppercase()));
+        match size {
+            Some(size) if self.offset + size <= end => Some(self.offset + size),
+            _ => {
+                self.invalidate_last();
+                None
+            }
+        }
+    }
+
+    /// Annotates the fields of an optional section in order, each present only if data remains before `end`.
+    fn optional_fields(&mut self, fields: &[Field], end: usize) -> Option<()> {
+        for &field in fields {
+            if self.offset >= end {
+                break;
+            }
+            self.field(field, field.len(), end)?;
+        }
+        Some(())
+    }
+
+    /// Marks the most recent annotation as invalid.
+    fn invalidate_last(&mut self) {
+        if let Some(annotation) = self.annotations.last_mut() {
+            annotation.is_valid = false;
+        }
+    }
+
+    fn leg(&mut self, is_first_leg: bool) -> Option<()> {
+        let end = self.input.len();
+        for &field in &[
+            Field::OperatingCarrierPnrCode,
+            Field::FromCityAirportCode,
+            Field::ToCityAirportCode,
+            Field::OperatingCarrierDesignator,
+            Field::FlightNumber,
+            Field::DateOfFlight,
+            Field::CompartmentCode,
+            Field::SeatNumber,
+            Field::CheckInSequenceNumber,
+            Field::PassengerStatus,
+        ] {
+            self.field(field, field.len(), end)?;
+        }
+
+        let section_end = self.size(Field::FieldSizeOfVariableSizeField, 2, end)?;
+
+        // Conditional items unique to the boarding pass are embedded in the first leg.
+        if is_first_leg && self.offset < section_end {
+            self.literal(Field::BeginningOfVersionNumber, ">", section_end)?;
+            self.optional_fields(&[Field::VersionNumber], section_end)?;
+            if self.offset < section_end {
+                let unique_end = self.size(Field::FieldSizeOfStructuredMessageUnique, 2, section_end)?;
+                self.optional_fields(&[
+                    Field::PassengerDescription,
+                    Field::SourceOfCheckIn,
+                    Field::SourceOfBoardingPassIssuance,
+                    Field::DateOfIssueOfBoardingPass,
+                    Field::DocumentType,
+                    Field::AirlineDesignatorOfBoardingPassIssuer,
+                    Field::BaggageTagLicensePlateNumbers,
+                    Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers,
+                    Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers,
+                ], unique_end)?;
+                self.offset = unique_end;
+            }
+        }
+
+        if self.offset < section_end {
+            let repeated_end = self.size(Field::FieldSizeOfStructuredMessageRepeated, 2, section_end)?;
+            self.optional_fields(&[
+                Field::AirlineNumericCode,
+                Field::DocumentFormSerialNumber,
+                Field::SelecteeIndicator,
+                Field::InternationalDocumentVerification,
+                Field::MarketingCarrierDesignator,
+                Field::FrequentFlyerAirlineDesignator,
+                Field::FrequentFlyerNumber,
+                Field::IdAdIndicator,
+                Field::FreeBaggageAllowance,
+                Field::FastTrack,
+            ], repeated_end)?;
+            self.offset = repeated_end;
+        }
+
+        if self.offset < section_end {
+            self.field(Field::AirlineIndividualUse, section_end - self.offset, section_end)?;
+        }
+
+        Some(())
+    }
+
+    fn bcbp(&mut self) -> Option<()> {
+        let end = self.input.len();
+        self.literal(Field::FormatCode, "M", end)?;
+        let number_of_legs = self.field(Field::NumberOfLegsEncoded, 1, end)
+            .and_then(|value| u8::from_str_radix(value, 16).ok())
+            .filter(|&number_of_legs| number_of_legs > 0);
+        if number_of_legs.is_none() {
+            self.invalidate_last();
+        }
+        self.field(Field::PassengerName, Field::PassengerName.len(), end)?;
+        self.field(Field::ElectronicTicketIndicator, 1, end)?;
+
+        for leg_index in 0 .. number_of_legs? {
+            self.leg(leg_index == 0)?;
+        }
+
+        if self.offset < end {
+            self.literal(Field::BeginningOfSecurityData, "^", end)?;
+            self.field(Field::TypeOfSecurityData, 1, end)?;
+            let security_end = self.size(Field::LengthOfSecurityData, 2, end)?;
+            self.field(Field::SecurityData, security_end - self.offset, security_end)?;
+        }
+
+        Some(())
+    }
+}
+
+/// Annotates the byte range, value and validity of each field in `input_data`.
+///
+/// Fields are annotated in order until the layout of the remaining data can no longer be
+/// determined, so inputs which fail to parse are explained up to the point of failure.
+/// Data following the last field of the layout is not annotated.
+pub fn explain<I>(input_data: I) -> Vec<Annotation>
+where
+    I: AsRef<str>,
+{
+    let mut explainer = Explainer {
+        input: input_data.as_ref().as_bytes(),
+        offset: 0,
+        annotations: Vec::new(),
+    };
+    let _ = explainer.bcbp();
+    explainer.annotations
+}
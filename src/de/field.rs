@@ -4,13 +4,20 @@
 // of the MIT license.  See the LICENSE file for details.
 
 use std::fmt;
+use std::str::FromStr;
 
-#[allow(dead_code)]
+use crate::error::{Error, Result};
+
+/// Identifies an individual item defined in the IATA BCBP Implementation Guide.
+///
+/// Variants are listed in ascending order of item number.
+#[allow(clippy::enum_variant_names)]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
-pub(crate) enum Field {
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Field {
     /// Item 1: Format Code. 1 byte. Data Type 'f'.
     FormatCode,
-    /// Item 4: Airline Individual Use. n bytes. Data Type unspecified.
+    /// Item 4: Airline Individual Use. n bytes. Data Type 'f'.
     AirlineIndividualUse,
     /// Item 5: Number of Legs Encoded. 1 byte. Data Type 'N'.
     NumberOfLegsEncoded,
@@ -72,7 +79,7 @@ pub(crate) enum Field {
     DateOfFlight,
     /// Item 71: Compartment Code. 1 byte. Data Type 'a'.
     CompartmentCode,
-    /// Item 89: Electronic Ticket Indicator. 1 byte. Data Type 'f'.
+    /// Item 89: ID/AD Indicator. 1 byte. Data Type 'f'.
     IdAdIndicator,
     /// Item 104: Seat Number. 4 bytes. Data Type is usually 'NNNa', but can be 'INF ' or similar.
     SeatNumber,
@@ -96,9 +103,171 @@ pub(crate) enum Field {
     FastTrack,
 }
 
+/// All fields in ascending order of item number.
+const ALL_FIELDS: &[Field] = &[
+    Field::FormatCode,
+    Field::AirlineIndividualUse,
+    Field::NumberOfLegsEncoded,
+    Field::FieldSizeOfVariableSizeField,
+    Field::OperatingCarrierPnrCode,
+    Field::BeginningOfVersionNumber,
+    Field::VersionNumber,
+    Field::FieldSizeOfStructuredMessageUnique,
+    Field::PassengerName,
+    Field::SourceOfCheckIn,
+    Field::SourceOfBoardingPassIssuance,
+    Field::PassengerDescription,
+    Field::DocumentType,
+    Field::FieldSizeOfStructuredMessageRepeated,
+    Field::SelecteeIndicator,
+    Field::MarketingCarrierDesignator,
+    Field::FrequentFlyerAirlineDesignator,
+    Field::AirlineDesignatorOfBoardingPassIssuer,
+    Field::DateOfIssueOfBoardingPass,
+    Field::BaggageTagLicensePlateNumbers,
+    Field::BeginningOfSecurityData,
+    Field::FromCityAirportCode,
+    Field::TypeOfSecurityData,
+    Field::LengthOfSecurityData,
+    Field::SecurityData,
+    Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers,
+    Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers,
+    Field::ToCityAirportCode,
+    Field::OperatingCarrierDesignator,
+    Field::FlightNumber,
+    Field::DateOfFlight,
+    Field::CompartmentCode,
+    Field::IdAdIndicator,
+    Field::SeatNumber,
+    Field::CheckInSequenceNumber,
+    Field::InternationalDocumentVerification,
+    Field::PassengerStatus,
+    Field::FreeBaggageAllowance,
+    Field::AirlineNumericCode,
+    Field::DocumentFormSerialNumber,
+    Field::FrequentFlyerNumber,
+    Field::ElectronicTicketIndicator,
+    Field::FastTrack,
+];
+
 impl Field {
+    /// Every field defined in the Implementation Guide, in ascending order of item number.
+    pub fn all() -> &'static [Field] {
+        ALL_FIELDS
+    }
+
+    /// Looks up the field assigned the specified item number in the Implementation Guide.
+    /// Returns None if no field is defined for the item number.
+    pub fn from_item_number(item_number: usize) -> Option<Field> {
+        ALL_FIELDS
+            .iter()
+            .find(|field| field.item_number() == item_number)
+            .cloned()
+    }
+
+    /// The item number assigned to the field in the Implementation Guide.
+    pub const fn item_number(self) -> usize {
+        match self {
+            Field::FormatCode => 1,
+            Field::AirlineIndividualUse => 4,
+            Field::NumberOfLegsEncoded => 5,
+            Field::FieldSizeOfVariableSizeField => 6,
+            Field::OperatingCarrierPnrCode => 7,
+            Field::BeginningOfVersionNumber => 8,
+            Field::VersionNumber => 9,
+            Field::FieldSizeOfStructuredMessageUnique => 10,
+            Field::PassengerName => 11,
+            Field::SourceOfCheckIn => 12,
+            Field::SourceOfBoardingPassIssuance => 14,
+            Field::PassengerDescription => 15,
+            Field::DocumentType => 16,
+            Field::FieldSizeOfStructuredMessageRepeated => 17,
+            Field::SelecteeIndicator => 18,
+            Field::MarketingCarrierDesignator => 19,
+            Field::FrequentFlyerAirlineDesignator => 20,
+            Field::AirlineDesignatorOfBoardingPassIssuer => 21,
+            Field::DateOfIssueOfBoardingPass => 22,
+            Field::BaggageTagLicensePlateNumbers => 23,
+            Field::BeginningOfSecurityData => 25,
+            Field::FromCityAirportCode => 26,
+            Field::TypeOfSecurityData => 28,
+            Field::LengthOfSecurityData => 29,
+            Field::SecurityData => 30,
+            Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers => 31,
+            Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers => 32,
+            Field::ToCityAirportCode => 38,
+            Field::OperatingCarrierDesignator => 42,
+            Field::FlightNumber => 43,
+            Field::DateOfFlight => 46,
+            Field::CompartmentCode => 71,
+            Field::IdAdIndicator => 89,
+            Field::SeatNumber => 104,
+            Field::CheckInSequenceNumber => 107,
+            Field::InternationalDocumentVerification => 108,
+            Field::PassengerStatus => 117,
+            Field::FreeBaggageAllowance => 118,
+            Field::AirlineNumericCode => 142,
+            Field::DocumentFormSerialNumber => 143,
+            Field::FrequentFlyerNumber => 236,
+            Field::ElectronicTicketIndicator => 253,
+            Field::FastTrack => 254,
+        }
+    }
+
+    /// The data format of the field as specified in the Implementation Guide.
+    /// 'f' indicates a free-form field, 'N' a numeric field and 'a' an alphabetic field.
+    /// Brackets indicate an optional trailing character.
+    pub const fn data_format(self) -> &'static str {
+        match self {
+            Field::FormatCode => "f",
+            Field::AirlineIndividualUse => "f",
+            Field::NumberOfLegsEncoded => "N",
+            Field::FieldSizeOfVariableSizeField => "f",
+            Field::OperatingCarrierPnrCode => "f",
+            Field::BeginningOfVersionNumber => "f",
+            Field::VersionNumber => "f",
+            Field::FieldSizeOfStructuredMessageUnique => "f",
+            Field::PassengerName => "f",
+            Field::SourceOfCheckIn => "f",
+            Field::SourceOfBoardingPassIssuance => "f",
+            Field::PassengerDescription => "f",
+            Field::DocumentType => "f",
+            Field::FieldSizeOfStructuredMessageRepeated => "f",
+            Field::SelecteeIndicator => "f",
+            Field::MarketingCarrierDesignator => "f",
+            Field::FrequentFlyerAirlineDesignator => "f",
+            Field::AirlineDesignatorOfBoardingPassIssuer => "f",
+            Field::DateOfIssueOfBoardingPass => "N",
+            Field::BaggageTagLicensePlateNumbers => "f",
+            Field::BeginningOfSecurityData => "f",
+            Field::FromCityAirportCode => "a",
+            Field::TypeOfSecurityData => "f",
+            Field::LengthOfSecurityData => "f",
+            Field::SecurityData => "f",
+            Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers => "f",
+            Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers => "f",
+            Field::ToCityAirportCode => "a",
+            Field::OperatingCarrierDesignator => "f",
+            Field::FlightNumber => "NNNN[a]",
+            Field::DateOfFlight => "N",
+            Field::CompartmentCode => "a",
+            Field::IdAdIndicator => "f",
+            Field::SeatNumber => "NNNa",
+            Field::CheckInSequenceNumber => "NNNN[f]",
+            Field::InternationalDocumentVerification => "f",
+            Field::PassengerStatus => "f",
+            Field::FreeBaggageAllowance => "f",
+            Field::AirlineNumericCode => "N",
+            Field::DocumentFormSerialNumber => "f",
+            Field::FrequentFlyerNumber => "f",
+            Field::ElectronicTicketIndicator => "f",
+            Field::FastTrack => "f",
+        }
+    }
+
     /// The required length of the field. If zero, the field may be arbitrarily long.
-    pub fn len(self) -> usize {
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> usize {
         match self {
             Field::FormatCode => 1,
             Field::AirlineIndividualUse => 0,
@@ -164,7 +333,7 @@ impl Field {
             Field::VersionNumber =>
                 "Version Number",
             Field::FieldSizeOfStructuredMessageUnique =>
-                "Field Size of Strutured Message (Unique)",
+                "Field Size of Structured Message (Unique)",
             Field::PassengerName =>
                 "Passenger Name",
             Field::SourceOfCheckIn =>
@@ -176,7 +345,7 @@ impl Field {
             Field::DocumentType =>
                 "Document Type",
             Field::FieldSizeOfStructuredMessageRepeated =>
-                "Field Size of Strutured Message (Repeated)",
+                "Field Size of Structured Message (Repeated)",
             Field::SelecteeIndicator =>
                 "Selectee Indicator",
             Field::MarketingCarrierDesignator =>
@@ -244,3 +413,16 @@ impl fmt::Display for Field {
         f.write_str(self.name())
     }
 }
+
+impl FromStr for Field {
+    type Err = Error;
+
+    /// Looks up a field by the name defined in the Implementation Guide, ignoring ASCII case.
+    fn from_str(name: &str) -> Result<Self> {
+        ALL_FIELDS
+            .iter()
+            .find(|field| field.name().eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or(Error::UnknownField)
+    }
+}
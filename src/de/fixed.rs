@@ -0,0 +1,387 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::{Bcbp, ConditionalMetadata, Leg, SecurityData};
+use crate::de::field::Field;
+use crate::error::{Error, ParseFailure, Result};
+use crate::fixed_string::FixedString;
+
+/// Items unique to the boarding pass, in the order they are encoded.
+const UNIQUE_FIELDS: [Field; 14] = [
+    Field::PassengerName,
+    Field::ElectronicTicketIndicator,
+    Field::VersionNumber,
+    Field::PassengerDescription,
+    Field::SourceOfCheckIn,
+    Field::SourceOfBoardingPassIssuance,
+    Field::DateOfIssueOfBoardingPass,
+    Field::DocumentType,
+    Field::AirlineDesignatorOfBoardingPassIssuer,
+    Field::BaggageTagLicensePlateNumbers,
+    Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers,
+    Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers,
+    Field::TypeOfSecurityData,
+    Field::SecurityData,
+];
+
+/// Mandatory items of each leg, in the order they are encoded.
+const MANDATORY_LEG_FIELDS: [Field; 10] = [
+    Field::OperatingCarrierPnrCode,
+    Field::FromCityAirportCode,
+    Field::ToCityAirportCode,
+    Field::OperatingCarrierDesignator,
+    Field::FlightNumber,
+    Field::DateOfFlight,
+    Field::CompartmentCode,
+    Field::SeatNumber,
+    Field::CheckInSequenceNumber,
+    Field::PassengerStatus,
+];
+
+/// Items of the repeated structured message of each leg, in the order they are encoded.
+const REPEATED_LEG_FIELDS: [Field; 10] = [
+    Field::AirlineNumericCode,
+    Field::DocumentFormSerialNumber,
+    Field::SelecteeIndicator,
+    Field::InternationalDocumentVerification,
+    Field::MarketingCarrierDesignator,
+    Field::FrequentFlyerAirlineDesignator,
+    Field::FrequentFlyerNumber,
+    Field::IdAdIndicator,
+    Field::FreeBaggageAllowance,
+    Field::FastTrack,
+];
+
+/// The number of items stored for each leg, including the airline individual use data.
+const LEG_SLOTS: usize = MANDATORY_LEG_FIELDS.len() + REPEATED_LEG_FIELDS.len() + 1;
+
+/// Returns the slot storing the value of `field` for a leg.
+fn leg_slot(field: Field) -> Option<usize> {
+    if field == Field::AirlineIndividualUse {
+        return Some(LEG_SLOTS - 1);
+    }
+    MANDATORY_LEG_FIELDS.iter()
+        .chain(REPEATED_LEG_FIELDS.iter())
+        .position(|&leg_field| leg_field == field)
+}
+
+/// Returns the slot storing the value of `field` unique to the boarding pass.
+fn unique_slot(field: Field) -> Option<usize> {
+    UNIQUE_FIELDS.iter().position(|&unique_field| unique_field == field)
+}
+
+/// Copies an optional fixed-length field value into owned storage.
+fn str_value<const N: usize>(value: Option<&str>) -> Option<FixedString<N>> {
+    value.map(|value| FixedString::from(value).unwrap())
+}
+
+/// Returns the character of an optional single-character field value.
+fn chr_value(value: Option<&str>) -> Option<char> {
+    value.and_then(|value| value.chars().next())
+}
+
+/// A boarding pass parsed without allocating, whose field values are borrowed from the input.
+///
+/// Up to `MAX_LEGS` legs are stored inline, so the boarding pass can be parsed on the stack
+/// on devices without an allocator. Field values are accessed dynamically and are neither
+/// copied nor trimmed. Only a failure to parse allocates, to describe the failure.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BcbpFixed<'a, const MAX_LEGS: usize> {
+    input: &'a str,
+    unique: [Option<&'a str>; UNIQUE_FIELDS.len()],
+    legs: [[Option<&'a str>; LEG_SLOTS]; MAX_LEGS],
+    leg_count: usize,
+}
+
+impl<'a, const MAX_LEGS: usize> BcbpFixed<'a, MAX_LEGS> {
+    /// Parses a boarding pass from `input`, which must encode at most `MAX_LEGS` legs.
+    ///
+    /// Parsing is as strict as `from_str`. Boarding passes encoding more than `MAX_LEGS` legs
+    /// fail with `Error::LengthOverflow(Field::NumberOfLegsEncoded)`.
+    pub fn parse(input: &'a str) -> Result<Self> {
+        if !input.is_ascii() {
+            return Err(Error::InvalidCharacters);
+        }
+        if !input.starts_with('M') {
+            return Err(Error::UnsupportedFormat);
+        }
+
+        let mut boarding_pass = BcbpFixed {
+            input,
+            unique: [None; UNIQUE_FIELDS.len()],
+            legs: [[None; LEG_SLOTS]; MAX_LEGS],
+            leg_count: 0,
+        };
+        let mut scanner = Scanner { input, offset: 0 };
+        scanner.bcbp(&mut boarding_pass)?;
+
+        if scanner.offset < input.len() {
+            Err(Error::TrailingCharacters)
+        } else {
+            Ok(boarding_pass)
+        }
+    }
+
+    /// The number of legs encoded into the boarding pass, always at least one.
+    pub fn leg_count(&self) -> usize {
+        self.leg_count
+    }
+
+    /// The value of `field` unique to the boarding pass, or `None` if it is not present
+    /// or is not unique to the boarding pass.
+    pub fn field(&self, field: Field) -> Option<&'a str> {
+        unique_slot(field).and_then(|slot| self.unique[slot])
+    }
+
+    /// The value of `field` in the leg at `leg_index`, or `None` if the leg or field is not present
+    /// or the field is not encoded in legs.
+    pub fn leg_field(&self, leg_index: usize, field: Field) -> Option<&'a str> {
+        if leg_index >= self.leg_count {
+            return None;
+        }
+        leg_slot(field).and_then(|slot| self.legs[leg_index][slot])
+    }
+
+    /// The Type of Security Data, if a security data section is present.
+    pub fn type_of_security_data(&self) -> Option<char> {
+        self.field(Field::TypeOfSecurityData).and_then(|value| value.chars().next())
+    }
+
+    /// The Security Data, borrowed from the input, if a security data section is present and not empty.
+    pub fn security_data(&self) -> Option<&'a [u8]> {
+        self.field(Field::SecurityData).map(str::as_bytes)
+    }
+
+    /// Copies the boarding pass into owned storage, equal to the result of `from_str`.
+    ///
+    /// Together with `parse`, this parses a `Bcbp` with a hand-written scanner rather than nom.
+    pub fn to_bcbp(&self) -> Bcbp {
+        let legs = (0 .. self.leg_count).map(|leg_index| {
+            let value = |field| self.leg_field(leg_index, field);
+            Leg {
+                operating_carrier_pnr_code: str_value(value(Field::OperatingCarrierPnrCode)).unwrap_or_default(),
+                from_city_airport_code: str_value(value(Field::FromCityAirportCode)).unwrap_or_default(),
+                to_city_airport_code: str_value(value(Field::ToCityAirportCode)).unwrap_or_default(),
+                operating_carrier_designator: str_value(value(Field::OperatingCarrierDesignator)).unwrap_or_default(),
+                flight_number: str_value(value(Field::FlightNumber)).unwrap_or_default(),
+                date_of_flight: str_value(value(Field::DateOfFlight)).unwrap_or_default(),
+                compartment_code: chr_value(value(Field::CompartmentCode)).unwrap_or_default(),
+                seat_number: str_value(value(Field::SeatNumber)).unwrap_or_default(),
+                check_in_sequence_number: str_value(value(Field::CheckInSequenceNumber)).unwrap_or_default(),
+                passenger_status: chr_value(value(Field::PassengerStatus)).unwrap_or_default(),
+                airline_numeric_code: str_value(value(Field::AirlineNumericCode)),
+                document_form_serial_number: str_value(value(Field::DocumentFormSerialNumber)),
+                selectee_indicator: chr_value(value(Field::SelecteeIndicator)),
+                international_document_verification: chr_value(value(Field::InternationalDocumentVerification)),
+                marketing_carrier_designator: str_value(value(Field::MarketingCarrierDesignator)),
+                frequent_flyer_airline_designator: str_value(value(Field::FrequentFlyerAirlineDesignator)),
+                frequent_flyer_number: str_value(value(Field::FrequentFlyerNumber)),
+                id_ad_indicator: chr_value(value(Field::IdAdIndicator)),
+                free_baggage_allowance: str_value(value(Field::FreeBaggageAllowance)),
+                fast_track: chr_value(value(Field::FastTrack)),
+                airline_individual_use: value(Field::AirlineIndividualUse).map(String::from),
+            }
+        }).collect();
+
+        let value = |field| self.field(field);
+        let metadata = ConditionalMetadata {
+            version_number: chr_value(value(Field::VersionNumber)),
+            passenger_description: chr_value(value(Field::PassengerDescription)),
+            source_of_check_in: chr_value(value(Field::SourceOfCheckIn)),
+            source_of_boarding_pass_issuance: chr_value(value(Field::SourceOfBoardingPassIssuance)),
+            date_of_issue_of_boarding_pass: str_value(value(Field::DateOfIssueOfBoardingPass)),
+            document_type: chr_value(value(Field::DocumentType)),
+            airline_designator_of_boarding_pass_issuer: str_value(value(Field::AirlineDesignatorOfBoardingPassIssuer)),
+            baggage_tag_license_plate_numbers: str_value(value(Field::BaggageTagLicensePlateNumbers)),
+            first_non_consecutive_baggage_tag_license_plate_numbers:
+                str_value(value(Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers)),
+            second_non_consecutive_baggage_tag_license_plate_numbers:
+                str_value(value(Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers)),
+        };
+
+        // The signature covers all pass data preceding the beginning of the security data.
+        let security_data = match self.type_of_security_data() {
+            Some(type_of_security_data) => {
+                let security_data = value(Field::SecurityData);
+                let length_of_security_data = security_data.map_or(0, str::len);
+                let signed_len = self.input.len() - length_of_security_data - 4;
+                SecurityData {
+                    type_of_security_data: Some(type_of_security_data),
+                    length_of_security_data: Some(length_of_security_data),
+                    security_data: security_data.map(String::from),
+                    signed_range: Some(0 .. signed_len),
+                    signed_payload: Some(String::from(&self.input[.. signed_len])),
+                }
+            }
+            None => Default::default(),
+        };
+
+        Bcbp {
+            passenger_name: str_value(value(Field::PassengerName)).unwrap_or_default(),
+            electronic_ticket_indicator: chr_value(value(Field::ElectronicTicketIndicator)).unwrap_or_default(),
+            metadata,
+            legs,
+            declared_leg_count: None,
+            security_data,
+        }
+    }
+}
+
+/// Walks the layout of the input, borrowing the value of each field encountered.
+struct Scanner<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Returns the error for a failure to parse `field` at `offset` from data ending at `end`.
+    fn error(&self, field: Field, offset: usize, end: usize) -> Error {
+        let failure = ParseFailure::new(field, offset, &self.input[offset .. end], Vec::new());
+        if failure.is_truncated() {
+            Error::UnexpectedEndOfInput(failure)
+        } else {
+            Error::ParseFailed(failure)
+        }
+    }
+
+    /// Consumes `len` bytes of `field` from the data ending at `end`.
+    fn take(&mut self, field: Field, len: usize, end: usize) -> Result<&'a str> {
+        if self.offset + len > end {
+            return Err(self.error(field, self.offset, end));
+        }
+        let value = &self.input[self.offset .. self.offset + len];
+        self.offset += len;
+        Ok(value)
+    }
+
+    /// Consumes a field which must have exactly the value `expected`.
+    fn literal(&mut self, field: Field, expected: &str, end: usize) -> Result<()> {
+        let offset = self.offset;
+        if self.take(field, expected.len(), end)? != expected {
+            return Err(self.error(field, offset, end));
+        }
+        Ok(())
+    }
+
+    /// Consumes a hexadecimal size field, returning the end of the data it describes.
+    fn size(&mut self, field: Field, end: usize) -> Result<usize> {
+        let offset = self.offset;
+        let value = self.take(field, field.len(), end)?;
+        let size = usize::from_str_radix(value, 16).ok()
+            .filter(|_| value.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_uppercase()));
+        match size {
+            Some(size) if self.offset + size <= end => Ok(self.offset + size),
+            // Data extending past the end of the input is truncated.
+            Some(size) if end == self.input.len() => {
+                let failure = ParseFailure::new(field, offset, &self.input[offset ..], Vec::new())
+                    .with_missing_len(self.offset + size - end);
+                Err(Error::UnexpectedEndOfInput(failure))
+            }
+            _ => Err(self.error(field, offset, end)),
+        }
+    }
+
+    /// Consumes the fields of an optional section in order, each present only if data remains
+    /// before `end`, storing their values in `slots`.
+    fn optional_fields<F>(
+        &mut self,
+        fields: &[Field],
+        slots: &mut [Option<&'a str>],
+        slot: F,
+        end: usize
+    ) -> Result<()>
+    where
+        F: Fn(Field) -> Option<usize>,
+    {
+        for &field in fields {
+            if self.offset >= end {
+                break;
+            }
+            let value = self.take(field, field.len(), end)?;
+            if let Some(slot) = slot(field) {
+                slots[slot] = Some(value);
+            }
+        }
+        Ok(())
+    }
+
+    fn leg(
+        &mut self,
+        leg: &mut [Option<&'a str>; LEG_SLOTS],
+        unique: Option<&mut [Option<&'a str>]>
+    ) -> Result<()> {
+        let end = self.input.len();
+        for &field in MANDATORY_LEG_FIELDS.iter() {
+            leg[leg_slot(field).unwrap()] = Some(self.take(field, field.len(), end)?);
+        }
+
+        let section_end = self.size(Field::FieldSizeOfVariableSizeField, end)?;
+
+        // Conditional items unique to the boarding pass are embedded in the first leg.
+        if let Some(unique) = unique {
+            if self.offset < section_end {
+                self.literal(Field::BeginningOfVersionNumber, ">", section_end)?;
+                self.optional_fields(&[Field::VersionNumber], unique, unique_slot, section_end)?;
+            }
+            if self.offset < section_end {
+                let unique_end = self.size(Field::FieldSizeOfStructuredMessageUnique, section_end)?;
+                self.optional_fields(&UNIQUE_FIELDS[3 .. 12], unique, unique_slot, unique_end)?;
+                self.offset = unique_end;
+            }
+        }
+
+        if self.offset < section_end {
+            let repeated_end = self.size(Field::FieldSizeOfStructuredMessageRepeated, section_end)?;
+            self.optional_fields(&REPEATED_LEG_FIELDS, leg, leg_slot, repeated_end)?;
+            self.offset = repeated_end;
+        }
+
+        // Anything remaining in the section is ascribed to airline individual use.
+        if self.offset < section_end {
+            leg[LEG_SLOTS - 1] = Some(&self.input[self.offset .. section_end]);
+            self.offset = section_end;
+        }
+
+        Ok(())
+    }
+
+    fn bcbp<const MAX_LEGS: usize>(&mut self, boarding_pass: &mut BcbpFixed<'a, MAX_LEGS>) -> Result<()> {
+        let end = self.input.len();
+        self.literal(Field::FormatCode, "M", end)?;
+
+        let offset = self.offset;
+        let value = self.take(Field::NumberOfLegsEncoded, 1, end)?;
+        let number_of_legs = usize::from_str_radix(value, 16).ok()
+            .filter(|&number_of_legs| number_of_legs > 0 && !value.bytes().any(|b| b.is_ascii_lowercase()))
+            .ok_or_else(|| self.error(Field::NumberOfLegsEncoded, offset, end))?;
+        if number_of_legs > MAX_LEGS {
+            return Err(Error::LengthOverflow(Field::NumberOfLegsEncoded));
+        }
+
+        for &field in &UNIQUE_FIELDS[.. 2] {
+            boarding_pass.unique[unique_slot(field).unwrap()] = Some(self.take(field, field.len(), end)?);
+        }
+
+        for leg_index in 0 .. number_of_legs {
+            let unique = if leg_index == 0 { Some(&mut boarding_pass.unique[..]) } else { None };
+            self.leg(&mut boarding_pass.legs[leg_index], unique)?;
+        }
+        boarding_pass.leg_count = number_of_legs;
+
+        // Security data follows the last leg, if any.
+        if self.offset < end {
+            self.literal(Field::BeginningOfSecurityData, "^", end)?;
+            let type_of_security_data = self.take(Field::TypeOfSecurityData, 1, end)?;
+            let security_end = self.size(Field::LengthOfSecurityData, end)?;
+            let security_data = self.take(Field::SecurityData, security_end - self.offset, end)?;
+            boarding_pass.unique[unique_slot(Field::TypeOfSecurityData).unwrap()] = Some(type_of_security_data);
+            if !security_data.is_empty() {
+                boarding_pass.unique[unique_slot(Field::SecurityData).unwrap()] = Some(security_data);
+            }
+        }
+
+        Ok(())
+    }
+}
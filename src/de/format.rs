@@ -0,0 +1,180 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, Deserialize, IntoDeserializer, Visitor};
+
+use crate::de::field::Field;
+use crate::de::fixed::BcbpFixed;
+use crate::error::{Error, Result};
+
+/// The largest number of legs representable by the Number of Legs Encoded field.
+const MAX_LEGS: usize = 0xF;
+
+/// The key of the sequence of legs within the boarding pass.
+pub(crate) const LEGS_KEY: &str = "Legs";
+
+impl de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// The key of `field` within a map, the name of its variant as in the serialized form of `Field`.
+pub(crate) fn key(field: Field) -> String {
+    format!("{:?}", field)
+}
+
+/// Deserializes data structures from the fields of an IATA BCBP Type M boarding pass.
+///
+/// The boarding pass is presented as a map from field names such as `PassengerName` to their values,
+/// with the legs as a sequence of maps under `Legs`. Only fields present in the boarding pass appear.
+/// Values are borrowed from the input untrimmed, may be deserialized as numbers or characters, and
+/// consist entirely of spaces when deserialized as an absent `Option`.
+pub struct Deserializer<'de> {
+    boarding_pass: BcbpFixed<'de, MAX_LEGS>,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Parses a boarding pass from `input` as strictly as `from_str`.
+    pub fn parse(input: &'de str) -> Result<Self> {
+        Ok(Deserializer { boarding_pass: BcbpFixed::parse(input)? })
+    }
+}
+
+/// Deserializes an instance of `T` from the boarding pass `input`, as described by `Deserializer`.
+pub fn from_str_as<'de, T: Deserialize<'de>>(input: &'de str) -> Result<T> {
+    T::deserialize(Deserializer::parse(input)?)
+}
+
+/// A value within the boarding pass.
+#[derive(Copy, Clone)]
+enum Value<'a, 'de> {
+    Field(Field, &'de str),
+    Legs(&'a BcbpFixed<'de, MAX_LEGS>),
+    Leg(&'a BcbpFixed<'de, MAX_LEGS>, usize),
+}
+
+impl<'a, 'de> Value<'a, 'de> {
+    /// Parses the trimmed value of a field as a number or another type implementing `FromStr`.
+    fn parse<T: std::str::FromStr>(self) -> Result<T> {
+        match self {
+            Value::Field(field, value) => value.trim().parse().map_err(|_| Error::InvalidFieldValue(field)),
+            _ => Err(de::Error::custom("expected a field value")),
+        }
+    }
+}
+
+/// Visits `entries` as a map, failing if the visitor does not consume all of them.
+fn visit_map<'a, 'de, I, V>(entries: I, visitor: V) -> Result<V::Value>
+where
+    I: Iterator<Item = (String, Value<'a, 'de>)>,
+    V: Visitor<'de>,
+    'de: 'a,
+{
+    let mut map = MapDeserializer::new(entries);
+    let value = visitor.visit_map(&mut map)?;
+    map.end()?;
+    Ok(value)
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                match self {
+                    Value::Field(..) => visitor.$visit(self.parse()?),
+                    _ => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'a, 'de> de::Deserializer<'de> for Value<'a, 'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::Field(_, value) =>
+                visitor.visit_borrowed_str(value),
+            Value::Legs(boarding_pass) => {
+                let legs = (0 .. boarding_pass.leg_count()).map(|leg_index| Value::Leg(boarding_pass, leg_index));
+                let mut seq = SeqDeserializer::new(legs);
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Leg(boarding_pass, leg_index) => {
+                let entries = Field::all().iter().filter_map(move |&field| {
+                    boarding_pass.leg_field(leg_index, field).map(|value| (key(field), Value::Field(field, value)))
+                });
+                visit_map(entries, visitor)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::Field(_, value) if value.bytes().all(|b| b == b' ') => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self {
+            Value::Field(_, value) if value.len() == 1 =>
+                visitor.visit_char(value.chars().next().unwrap()),
+            Value::Field(field, _) =>
+                Err(Error::InvalidFieldValue(field)),
+            _ =>
+                self.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i128 u128 str string bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'a, 'de> IntoDeserializer<'de, Error> for Value<'a, 'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let boarding_pass = &self.boarding_pass;
+        let entries = Field::all()
+            .iter()
+            .filter_map(|&field| boarding_pass.field(field).map(|value| (key(field), Value::Field(field, value))))
+            .chain(std::iter::once((String::from(LEGS_KEY), Value::Legs(boarding_pass))));
+        visit_map(entries, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
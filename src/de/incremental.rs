@@ -0,0 +1,85 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Bcbp;
+use crate::de::explain::{explain, Annotation};
+use crate::de::parser;
+use crate::error::{Error, Result};
+
+/// The state of an `IncrementalParser` after input is pushed.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParseProgress {
+    /// The input received so far is a complete boarding pass.
+    Complete(Bcbp),
+    /// The input received so far is a valid prefix of a boarding pass.
+    Incomplete {
+        /// The fields received in their entirety.
+        parsed: Vec<Annotation>,
+        /// The offset at which the data still required begins.
+        required_from: usize,
+    },
+}
+
+/// A push-based parser accepting boarding pass data in chunks, such as from a scanner
+/// progressively refining its read.
+///
+/// The input received so far is parsed again when a push completes the field which was
+/// missing data, so that data arriving a byte at a time is parsed at most once per field.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct IncrementalParser {
+    buffer: String,
+    /// The progress of the last parse, with the length of input required to complete the field it stopped at.
+    pending: Option<(usize, ParseProgress)>,
+}
+
+impl IncrementalParser {
+    /// Returns a parser which has not yet received any input.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The input received so far.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Appends `chunk` to the input received so far and attempts to parse it.
+    ///
+    /// Fails if the input cannot be the prefix of a valid boarding pass. A pass without
+    /// security data is reported as complete even though a security data section may follow.
+    pub fn push(&mut self, chunk: &str) -> Result<ParseProgress> {
+        self.buffer.push_str(chunk);
+
+        // The outcome cannot change until the field the last parse stopped at is complete.
+        if let Some((required_len, ref progress)) = self.pending {
+            if self.buffer.len() < required_len {
+                return Ok(progress.clone());
+            }
+        }
+        self.pending = None;
+
+        // Data which ends before the format code is known is always incomplete.
+        if self.buffer.is_empty() {
+            return Ok(ParseProgress::Incomplete { parsed: Vec::new(), required_from: 0 });
+        }
+
+        let (required_from, missing_len) = match parser::from_str(&self.buffer) {
+            Ok(boarding_pass) =>
+                return Ok(ParseProgress::Complete(boarding_pass)),
+            Err(Error::UnexpectedEndOfInput(failure)) =>
+                (failure.offset(), failure.missing_len().unwrap_or(1)),
+            Err(error) =>
+                return Err(error),
+        };
+
+        let parsed = explain(&self.buffer)
+            .into_iter()
+            .filter(|annotation| annotation.range().end <= required_from && annotation.is_valid())
+            .collect();
+        let progress = ParseProgress::Incomplete { parsed, required_from };
+        self.pending = Some((self.buffer.len() + missing_len, progress.clone()));
+        Ok(progress)
+    }
+}
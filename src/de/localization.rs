@@ -0,0 +1,123 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::de::field::Field;
+
+/// A language in which field names are available.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+/// Names of each field in French, German and Spanish, in item number order.
+const LOCALIZED_NAMES: &[(Field, &str, &str, &str)] = &[
+    (Field::FormatCode,
+        "Code de format", "Formatcode", "Código de formato"),
+    (Field::AirlineIndividualUse,
+        "Usage individuel de la compagnie", "Individuelle Verwendung der Fluggesellschaft", "Uso individual de la aerolínea"),
+    (Field::NumberOfLegsEncoded,
+        "Nombre de segments encodés", "Anzahl codierter Flugsegmente", "Número de tramos codificados"),
+    (Field::FieldSizeOfVariableSizeField,
+        "Taille du champ de taille variable", "Größe des Felds variabler Größe", "Tamaño del campo de tamaño variable"),
+    (Field::OperatingCarrierPnrCode,
+        "Code PNR du transporteur exploitant", "PNR-Code des ausführenden Luftfrachtführers", "Código PNR del transportista operador"),
+    (Field::BeginningOfVersionNumber,
+        "Début du numéro de version", "Beginn der Versionsnummer", "Inicio del número de versión"),
+    (Field::VersionNumber,
+        "Numéro de version", "Versionsnummer", "Número de versión"),
+    (Field::FieldSizeOfStructuredMessageUnique,
+        "Taille du message structuré (unique)", "Größe der strukturierten Nachricht (einmalig)", "Tamaño del mensaje estructurado (único)"),
+    (Field::PassengerName,
+        "Nom du passager", "Name des Passagiers", "Nombre del pasajero"),
+    (Field::SourceOfCheckIn,
+        "Source de l'enregistrement", "Quelle des Check-ins", "Origen de la facturación"),
+    (Field::SourceOfBoardingPassIssuance,
+        "Source d'émission de la carte d'embarquement", "Quelle der Bordkartenausstellung", "Origen de la emisión de la tarjeta de embarque"),
+    (Field::PassengerDescription,
+        "Description du passager", "Passagierbeschreibung", "Descripción del pasajero"),
+    (Field::DocumentType,
+        "Type de document", "Dokumenttyp", "Tipo de documento"),
+    (Field::FieldSizeOfStructuredMessageRepeated,
+        "Taille du message structuré (répété)", "Größe der strukturierten Nachricht (wiederholt)", "Tamaño del mensaje estructurado (repetido)"),
+    (Field::SelecteeIndicator,
+        "Indicateur de sélection", "Selectee-Kennzeichen", "Indicador de seleccionado"),
+    (Field::MarketingCarrierDesignator,
+        "Code du transporteur commercialisant", "Code des vermarktenden Luftfrachtführers", "Código del transportista comercializador"),
+    (Field::FrequentFlyerAirlineDesignator,
+        "Code de la compagnie du programme de fidélité", "Code der Fluggesellschaft des Vielfliegerprogramms", "Código de la aerolínea del programa de viajero frecuente"),
+    (Field::AirlineDesignatorOfBoardingPassIssuer,
+        "Code de la compagnie émettrice de la carte d'embarquement", "Code der ausstellenden Fluggesellschaft der Bordkarte", "Código de la aerolínea emisora de la tarjeta de embarque"),
+    (Field::DateOfIssueOfBoardingPass,
+        "Date d'émission de la carte d'embarquement", "Ausstellungsdatum der Bordkarte", "Fecha de emisión de la tarjeta de embarque"),
+    (Field::BaggageTagLicensePlateNumbers,
+        "Numéro(s) d'étiquette bagage", "Gepäckanhängernummer(n)", "Número(s) de etiqueta de equipaje"),
+    (Field::BeginningOfSecurityData,
+        "Début des données de sécurité", "Beginn der Sicherheitsdaten", "Inicio de los datos de seguridad"),
+    (Field::FromCityAirportCode,
+        "Code de l'aéroport de départ", "Code des Abflughafens", "Código del aeropuerto de origen"),
+    (Field::TypeOfSecurityData,
+        "Type des données de sécurité", "Art der Sicherheitsdaten", "Tipo de los datos de seguridad"),
+    (Field::LengthOfSecurityData,
+        "Longueur des données de sécurité", "Länge der Sicherheitsdaten", "Longitud de los datos de seguridad"),
+    (Field::SecurityData,
+        "Données de sécurité", "Sicherheitsdaten", "Datos de seguridad"),
+    (Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers,
+        "Premier numéro d'étiquette bagage non consécutif", "Erste nicht fortlaufende Gepäckanhängernummer", "Primer número de etiqueta de equipaje no consecutivo"),
+    (Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers,
+        "Second numéro d'étiquette bagage non consécutif", "Zweite nicht fortlaufende Gepäckanhängernummer", "Segundo número de etiqueta de equipaje no consecutivo"),
+    (Field::ToCityAirportCode,
+        "Code de l'aéroport d'arrivée", "Code des Zielflughafens", "Código del aeropuerto de destino"),
+    (Field::OperatingCarrierDesignator,
+        "Code du transporteur exploitant", "Code des ausführenden Luftfrachtführers", "Código del transportista operador"),
+    (Field::FlightNumber,
+        "Numéro de vol", "Flugnummer", "Número de vuelo"),
+    (Field::DateOfFlight,
+        "Date du vol", "Flugdatum", "Fecha del vuelo"),
+    (Field::CompartmentCode,
+        "Code de cabine", "Beförderungsklasse", "Código de cabina"),
+    (Field::IdAdIndicator,
+        "Indicateur ID/AD", "ID/AD-Kennzeichen", "Indicador ID/AD"),
+    (Field::SeatNumber,
+        "Numéro de siège", "Sitzplatznummer", "Número de asiento"),
+    (Field::CheckInSequenceNumber,
+        "Numéro de séquence d'enregistrement", "Check-in-Sequenznummer", "Número de secuencia de facturación"),
+    (Field::InternationalDocumentVerification,
+        "Vérification des documents internationaux", "Prüfung internationaler Reisedokumente", "Verificación de documentos internacionales"),
+    (Field::PassengerStatus,
+        "Statut du passager", "Passagierstatus", "Estado del pasajero"),
+    (Field::FreeBaggageAllowance,
+        "Franchise de bagages", "Freigepäck", "Franquicia de equipaje"),
+    (Field::AirlineNumericCode,
+        "Code numérique de la compagnie", "Numerischer Code der Fluggesellschaft", "Código numérico de la aerolínea"),
+    (Field::DocumentFormSerialNumber,
+        "Numéro de formulaire / de série du document", "Formular- / Seriennummer des Dokuments", "Número de formulario / de serie del documento"),
+    (Field::FrequentFlyerNumber,
+        "Numéro de voyageur fréquent", "Vielfliegernummer", "Número de viajero frecuente"),
+    (Field::ElectronicTicketIndicator,
+        "Indicateur de billet électronique", "Kennzeichen für elektronisches Ticket", "Indicador de billete electrónico"),
+    (Field::FastTrack,
+        "Accès prioritaire", "Fast Track", "Acceso prioritario"),
+];
+
+impl Field {
+    /// Name of the field in the specified language.
+    ///
+    /// English names are those defined in the Implementation Guide.
+    pub fn localized_name(self, language: Language) -> &'static str {
+        let localized_names = LOCALIZED_NAMES.iter()
+            .find(|(field, _, _, _)| *field == self)
+            .map(|&(_, french, german, spanish)| (french, german, spanish));
+
+        match (language, localized_names) {
+            (Language::French, Some((french, _, _))) => french,
+            (Language::German, Some((_, german, _))) => german,
+            (Language::Spanish, Some((_, _, spanish))) => spanish,
+            _ => self.name(),
+        }
+    }
+}
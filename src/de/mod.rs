@@ -5,13 +5,35 @@
 
 use std::str::FromStr;
 
-mod field;
+mod explain;
+mod fixed;
+pub(crate) mod field;
+#[cfg(feature = "serde")]
+pub(crate) mod format;
+mod incremental;
+mod localization;
+mod observer;
 mod parser;
+mod quirks;
+mod reader;
+mod recovery;
+mod spans;
 
 use crate::bcbp;
 use crate::error::{Error, Result};
 
-pub use self::parser::from_str;
+pub use self::explain::{explain, Annotation};
+pub use self::field::Field;
+pub use self::fixed::BcbpFixed;
+#[cfg(feature = "serde")]
+pub use self::format::{from_str_as, Deserializer};
+pub use self::incremental::{IncrementalParser, ParseProgress};
+pub use self::localization::Language;
+pub use self::observer::{from_str_observed, ParseObserver};
+pub use self::parser::{from_str, from_str_recovering, from_str_with_quirks, split_passes};
+pub use self::quirks::{Quirk, Quirks};
+pub use self::recovery::{ParsedLeg, PartialBcbp};
+pub use self::spans::{spans, TokenKind};
 
 impl FromStr for bcbp::Bcbp {
     type Err = Error;
@@ -19,3 +41,10 @@ impl FromStr for bcbp::Bcbp {
         from_str(input)
     }
 }
+
+impl FromStr for bcbp::Leg {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self> {
+        parser::leg_from_str(input)
+    }
+}
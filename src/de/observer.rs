@@ -0,0 +1,39 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Bcbp;
+use crate::de::parser;
+use crate::error::{Error, Result};
+
+/// Receives the outcome of each parse performed with `from_str_observed`.
+///
+/// Intended for aggregating metrics such as success rates, versions and leg counts
+/// across a fleet of scanners. All methods default to doing nothing.
+pub trait ParseObserver {
+    /// Called when a boarding pass was parsed successfully.
+    /// The version number, legs and security data are available from `boarding_pass`.
+    fn parsed(&self, boarding_pass: &Bcbp) {
+        let _ = boarding_pass;
+    }
+
+    /// Called when parsing failed with `error`.
+    fn failed(&self, error: &Error) {
+        let _ = error;
+    }
+}
+
+/// Parses a boarding pass from `input_data` as in `from_str`, reporting the outcome to `observer`.
+pub fn from_str_observed<I, O>(input_data: I, observer: &O) -> Result<Bcbp>
+where
+    I: AsRef<str>,
+    O: ParseObserver + ?Sized,
+{
+    let result = parser::from_str(input_data);
+    match result {
+        Ok(ref boarding_pass) => observer.parsed(boarding_pass),
+        Err(ref error) => observer.failed(error),
+    }
+    result
+}
@@ -5,18 +5,41 @@
 
 use crate::bcbp::{Bcbp, ConditionalMetadata, Leg, SecurityData};
 use crate::de::field;
-use crate::error::{Error, Result};
+use crate::de::quirks::{self, Quirk, Quirks};
+use crate::de::recovery::{ParsedLeg, PartialBcbp};
+use crate::error::{Error, LegError, ParseFailure, Result, TraceFrame};
+use crate::fixed_string::FixedString;
 
-use arrayvec::{Array, ArrayString};
 use nom::{
     bytes::complete::{take, take_while_m_n},
     character::complete::{anychar, char},
-    combinator::{map, map_res},
-    error::{context, convert_error, ParseError, VerboseError},
+    combinator::{map, map_res, verify},
+    error::{context, ParseError, VerboseError, VerboseErrorKind},
     sequence::tuple,
-    IResult,
+    IResult, Offset,
 };
 
+/// Deviations from Resolution 792 tolerated while parsing, and those encountered.
+#[derive(Default)]
+struct ParseContext<'q> {
+    quirks: Option<&'q Quirks>,
+    applied_quirks: Vec<Quirk>,
+}
+
+impl<'q> ParseContext<'q> {
+    /// Returns `true` if `quirk` is tolerated for the carrier with airline designator `carrier`.
+    fn tolerates(&self, carrier: &str, quirk: Quirk) -> bool {
+        self.quirks.is_some_and(|quirks| quirks.applies_to(carrier, quirk))
+    }
+
+    /// Records that `quirk` was encountered, if it has not been already.
+    fn apply(&mut self, quirk: Quirk) {
+        if !self.applied_quirks.contains(&quirk) {
+            self.applied_quirks.push(quirk);
+        }
+    }
+}
+
 /// Tests if char c is ASCII uppercase alphabetic (A-F) or numeric (0-9).
 fn is_ascii_uppercase_hexdigit(c: char) -> bool {
     c.is_ascii_hexdigit() && !c.is_ascii_lowercase()
@@ -37,26 +60,23 @@ fn hex_byte_literal<'a, Error: ParseError<&'a str>>(
 }
 
 /// Parses a variable-length field whose size data is in the specified first field.
-fn variable_size_field_data<'a>(
-    input: &'a str, 
+/// Data extending past the end of the input is attributed to the size field.
+fn variable_size_field_data(
+    input: &str, 
     field_id: field::Field
-) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
-    let (remainder, length) = context(field_id.name(), 
-        hex_byte_literal(2)
-    )(input)?;
-
-    match length {
-        0 => Ok((remainder, &input[0 .. 0])),
-        _ => take(length as usize)(remainder),
-    }
+) -> IResult<&str, &str, VerboseError<&str>> {
+    context(field_id.name(), |input| {
+        let (remainder, length) = hex_byte_literal(2)(input)?;
+        take(length as usize)(remainder)
+    })(input)
 }
 
 /// Parses an optional variable-length field whose size data is specified in the first field.
-fn optional_variable_size_field_data<'a>(
-    input: &'a str, 
+fn optional_variable_size_field_data(
+    input: &str, 
     field_id: field::Field
-) -> IResult<&'a str, &'a str, VerboseError<&'a str>> {
-    if input.len() == 0 {
+) -> IResult<&str, &str, VerboseError<&str>> {
+    if input.is_empty() {
         Ok((input, input))
     } else {
         variable_size_field_data(input, field_id)
@@ -64,15 +84,16 @@ fn optional_variable_size_field_data<'a>(
 }
 
 /// Parses the field encoding the number of legs embedded in the BCBP data.
-fn number_of_legs<'a>(input: &'a str) -> IResult<&'a str, u8, VerboseError<&'a str>> {
+/// A boarding pass must contain at least one leg.
+fn number_of_legs(input: &str) -> IResult<&str, u8, VerboseError<&str>> {
     context(field::Field::NumberOfLegsEncoded.name(),
-        hex_byte_literal(1)
+        verify(hex_byte_literal(1), |&number_of_legs| number_of_legs > 0)
     )(input)
 }
 
 /// Parses and returns an (optional) version number field, beginning with the '>' indicator.
-fn optional_version_number<'a>(input: &'a str) -> IResult<&'a str, Option<char>, VerboseError<&'a str>> {
-    if input.len() == 0 {
+fn optional_version_number(input: &str) -> IResult<&str, Option<char>, VerboseError<&str>> {
+    if input.is_empty() {
         return Ok((input, None));
     }
 
@@ -85,19 +106,30 @@ fn optional_version_number<'a>(input: &'a str) -> IResult<&'a str, Option<char>,
     optional_chr_field(field::Field::VersionNumber)(input)
 }
 
-/// Returns a parser for a specified field returning an `ArrayString` over its length.
-fn str_field<'a, T, Error: ParseError<&'a str>>(
+/// Returns a parser for a specified field returning a `FixedString` over its length.
+fn str_field<'a, const N: usize, Error: ParseError<&'a str>>(
     field_id: field::Field
-) -> impl Fn(&'a str) -> IResult<&'a str, ArrayString<T>, Error>
-where
-    T: Array<Item = u8> + Copy,
-{
+) -> impl Fn(&'a str) -> IResult<&'a str, FixedString<N>, Error> {
     // Verify that the size of the storage array matches the field exactly.
-    assert_eq!(std::mem::size_of::<T>(), field_id.len());
+    assert_eq!(N, field_id.len());
     context(field_id.name(),
         map_res(
             take(field_id.len()), 
-            |s: &str| ArrayString::from(s)
+            |s: &str| FixedString::from(s)
+        )
+    )
+}
+
+/// Returns a parser for an airport code field of `len` characters,
+/// the length of the field unless four-character codes are tolerated.
+fn airport_code_field<'a, Error: ParseError<&'a str>>(
+    field_id: field::Field,
+    len: usize
+) -> impl Fn(&'a str) -> IResult<&'a str, FixedString<4>, Error> {
+    context(field_id.name(),
+        map_res(
+            take(len),
+            |s: &str| FixedString::from(s)
         )
     )
 }
@@ -107,19 +139,16 @@ where
 /// # Notes
 /// - The parser will succeed and return None if the remaining length of the string is zero.
 /// - The parser will fail if the remaining length of the string is less than that of the requested field.
-fn optional_str_field<'a, T, Error: ParseError<&'a str>>(
+fn optional_str_field<'a, const N: usize, Error: ParseError<&'a str>>(
     field_id: field::Field
-) -> impl Fn(&'a str) -> IResult<&'a str, Option<ArrayString<T>>, Error>
-where
-    T: Array<Item = u8> + Copy,
-{
+) -> impl Fn(&'a str) -> IResult<&'a str, Option<FixedString<N>>, Error> {
     move |input: &'a str| {
-        if input.len() == 0 {
+        if input.is_empty() {
             Ok((input, None))
         } else {
             map(
                 str_field(field_id),
-                |field_value| Some(field_value),
+                Some,
             )(input)
         }
     }
@@ -141,7 +170,7 @@ fn optional_chr_field<'a, Error: ParseError<&'a str>>(
     field_id: field::Field
 ) -> impl Fn(&'a str) -> IResult<&'a str, Option<char>, Error> {
     move |input: &'a str| {
-        if input.len() == 0 {
+        if input.is_empty() {
             Ok((input, None))
         } else {
             map(
@@ -153,8 +182,17 @@ fn optional_chr_field<'a, Error: ParseError<&'a str>>(
 }
 
 /// Parses conditional metadata potentially embedded in the first leg.
-fn conditional_metadata<'a>(input: &'a str) -> IResult<&'a str, ConditionalMetadata, VerboseError<&'a str>> {
-    let (input, version_number) = optional_version_number(input)?;
+///
+/// If `has_version_marker` is `false`, the section begins with the unique structured message size.
+fn conditional_metadata(
+    input: &str,
+    has_version_marker: bool
+) -> IResult<&str, ConditionalMetadata, VerboseError<&str>> {
+    let (input, version_number) = if has_version_marker {
+        optional_version_number(input)?
+    } else {
+        (input, None)
+    };
 
     // Conditional metadata is encoded in an optional variable-size field.
     let (remainder, conditional_item_data) =
@@ -202,6 +240,25 @@ fn conditional_metadata<'a>(input: &'a str) -> IResult<&'a str, ConditionalMetad
     ))
 }
 
+/// Returns `true` if the leg beginning at `input` encodes four-character airport codes,
+/// which is only considered if tolerated for the operating carrier at the shifted position.
+///
+/// The layout is chosen only if the flight number and date of flight are well-formed when the
+/// fields are shifted by the two additional characters but not in the standard layout.
+fn has_four_character_airport_codes(input: &str, parse_context: &ParseContext) -> bool {
+    let is_plausible = |carrier_offset: usize| {
+        let flight_number = input.get(carrier_offset + 3 .. carrier_offset + 7);
+        let date_of_flight = input.get(carrier_offset + 8 .. carrier_offset + 11);
+        flight_number.into_iter().chain(date_of_flight)
+            .all(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+            && date_of_flight.is_some()
+    };
+
+    input.get(15 .. 18).is_some_and(|carrier| {
+        parse_context.tolerates(carrier, Quirk::FourCharacterAirportCodes)
+    }) && is_plausible(15) && !is_plausible(13)
+}
+
 /// Parses a leg.
 /// 
 /// When parsing the first leg, additional Pass-level data may be present.
@@ -209,8 +266,17 @@ fn conditional_metadata<'a>(input: &'a str) -> IResult<&'a str, ConditionalMetad
 /// is returned if available when `is_first` is `true` so parsing may resume at the top-level.
 fn leg<'a>(
     input: &'a str,
-    is_first_leg: bool
+    is_first_leg: bool,
+    parse_context: &mut ParseContext
 ) -> IResult<&'a str, (Leg, Option<ConditionalMetadata>), VerboseError<&'a str>> {
+    // Some carriers encode four-character airport codes, shifting the fields which follow.
+    let airport_code_len = if has_four_character_airport_codes(input, parse_context) {
+        parse_context.apply(Quirk::FourCharacterAirportCodes);
+        4
+    } else {
+        field::Field::FromCityAirportCode.len()
+    };
+
     // Parse mandatory fields common to all legs.
     let (input, (
         operating_carrier_pnr_code,
@@ -225,8 +291,8 @@ fn leg<'a>(
         passenger_status,
     )) = tuple((
         str_field(field::Field::OperatingCarrierPnrCode),
-        str_field(field::Field::FromCityAirportCode),
-        str_field(field::Field::ToCityAirportCode),
+        airport_code_field(field::Field::FromCityAirportCode, airport_code_len),
+        airport_code_field(field::Field::ToCityAirportCode, airport_code_len),
         str_field(field::Field::OperatingCarrierDesignator),
         str_field(field::Field::FlightNumber),
         str_field(field::Field::DateOfFlight),
@@ -241,15 +307,37 @@ fn leg<'a>(
         variable_size_field_data(input, field::Field::FieldSizeOfVariableSizeField)?;
 
     // Top-level conditional metadata may be embedded in the first leg.
+    let mut is_unstructured = false;
     let (conditional_item_data, optional_conditional_metadata) = if is_first_leg {
-        map(conditional_metadata, |data| Some(data))(conditional_item_data)?
+        // Some carriers omit the Beginning of Version Number and the Version Number.
+        let is_missing_version_marker = !conditional_item_data.is_empty()
+            && !conditional_item_data.starts_with('>')
+            && parse_context.tolerates(&operating_carrier_designator, Quirk::MissingVersionMarker);
+        if is_missing_version_marker {
+            parse_context.apply(Quirk::MissingVersionMarker);
+        }
+        match conditional_metadata(conditional_item_data, !is_missing_version_marker) {
+            Ok((conditional_item_data, metadata)) =>
+                (conditional_item_data, Some(metadata)),
+            // Without the marker the section may not be structured at all, in which case
+            // it is ascribed to airline individual use rather than failing the leg.
+            Err(_) if is_missing_version_marker => {
+                is_unstructured = true;
+                (conditional_item_data, Some(ConditionalMetadata::default()))
+            }
+            Err(error) =>
+                return Err(error),
+        }
     } else {
         (conditional_item_data, None)
     };
 
     // Repeated conditional items are stored in a variable-length section.
-    let (individual_use_data, conditional_item_data) =
-        optional_variable_size_field_data(conditional_item_data, field::Field::FieldSizeOfStructuredMessageRepeated)?;
+    let (individual_use_data, conditional_item_data) = if is_unstructured {
+        (conditional_item_data, "")
+    } else {
+        optional_variable_size_field_data(conditional_item_data, field::Field::FieldSizeOfStructuredMessageRepeated)?
+    };
 
     // Conditional leg data is encoded in an optional variable-size field.
     let (_, (
@@ -277,7 +365,7 @@ fn leg<'a>(
     ))(conditional_item_data)?;
 
     // Anything remaining in the section is ascribed to airline individual use.
-    let airline_individual_use = if individual_use_data.len() > 0 {
+    let airline_individual_use = if !individual_use_data.is_empty() {
         Some(String::from(individual_use_data))
     } else {
         None
@@ -311,8 +399,11 @@ fn leg<'a>(
 }
 
 /// Parses a Security Data section.
-fn security_data<'a>(input: &'a str) -> IResult<&'a str, SecurityData, VerboseError<&'a str>> {
-    if input.len() == 0 {
+///
+/// If `tolerate_truncation` is `true`, security data shorter than its declared length
+/// is captured as-is rather than failing.
+fn security_data(input: &str, tolerate_truncation: bool) -> IResult<&str, SecurityData, VerboseError<&str>> {
+    if input.is_empty() {
         return Ok((input, Default::default()));
     }
 
@@ -324,11 +415,20 @@ fn security_data<'a>(input: &'a str) -> IResult<&'a str, SecurityData, VerboseEr
     // The type field is mandatory, as is at least the length of the security data.
     let (input, type_of_security_data) =
         chr_field(field::Field::TypeOfSecurityData)(input)?;
-    let (remainder, security_data_field_data) =
-        variable_size_field_data(input, field::Field::LengthOfSecurityData)?;
+    let (input, length_of_security_data) = context(field::Field::LengthOfSecurityData.name(),
+        hex_byte_literal(2)
+    )(input)?;
+    let security_data_len = if tolerate_truncation {
+        input.len().min(length_of_security_data as usize)
+    } else {
+        length_of_security_data as usize
+    };
+    let (remainder, security_data_field_data) = context(field::Field::SecurityData.name(),
+        take(security_data_len)
+    )(input)?;
 
     // Variable-length security data is stored as a String.
-    let security_data = if security_data_field_data.len() > 0 {
+    let security_data = if !security_data_field_data.is_empty() {
         Some(String::from(security_data_field_data))
     } else {
         None
@@ -338,7 +438,10 @@ fn security_data<'a>(input: &'a str) -> IResult<&'a str, SecurityData, VerboseEr
         remainder,
         SecurityData {
             type_of_security_data: Some(type_of_security_data),
-            security_data: security_data
+            length_of_security_data: Some(length_of_security_data as usize),
+            security_data,
+            signed_range: None,
+            signed_payload: None,
         }
     ))
 }
@@ -346,23 +449,57 @@ fn security_data<'a>(input: &'a str) -> IResult<&'a str, SecurityData, VerboseEr
 /// Parses a boarding pass from `input`.
 ///
 /// The input must contain only valid ASCII characters.
-fn bcbp<'a>(input: &'a str) -> IResult<&'a str, Bcbp, VerboseError<&'a str>> {
-    // Scan mandatory unique fields including the format code and the number of legs encoded.
-    let (input, (
-        _,
-        number_of_legs_encoded,
-        passenger_name,
-        electronic_ticket_indicator,
-    )) = tuple((
-        char('M'),
+fn bcbp<'a>(
+    input: &'a str,
+    parse_context: &mut ParseContext
+) -> IResult<&'a str, Bcbp, VerboseError<&'a str>> {
+    let pass_input = input;
+    let (input, mut boarding_pass) = bcbp_without_security_data(input, parse_context)?;
+
+    // Consume security data that follows the last leg, if any.
+    // The signature covers all pass data preceding the beginning of the security data.
+    let signed_len = pass_input.offset(input);
+    let issuer = boarding_pass
+        .airline_designator_of_boarding_pass_issuer()
+        .unwrap_or_else(|| boarding_pass.first_leg().operating_carrier_designator());
+    let tolerate_truncation = parse_context.tolerates(issuer, Quirk::TruncatedSecurityData);
+    let (remainder, mut security_data) = security_data(input, tolerate_truncation)?;
+    if security_data.is_truncated() {
+        parse_context.apply(Quirk::TruncatedSecurityData);
+    }
+    if security_data.type_of_security_data.is_some() {
+        security_data.signed_range = Some(0 .. signed_len);
+        security_data.signed_payload = Some(String::from(&pass_input[.. signed_len]));
+    }
+
+    boarding_pass.security_data = security_data;
+    Ok((remainder, boarding_pass))
+}
+
+/// Parses the mandatory unique fields preceding the first leg, returning the number of legs encoded,
+/// the passenger name and the electronic ticket indicator.
+fn header(input: &str) -> IResult<&str, (u8, FixedString<20>, char), VerboseError<&str>> {
+    let (input, (_, number_of_legs_encoded, passenger_name, electronic_ticket_indicator)) = tuple((
+        context(field::Field::FormatCode.name(), char('M')),
         number_of_legs,
         str_field(field::Field::PassengerName),
         chr_field(field::Field::ElectronicTicketIndicator),
     ))(input)?;
+    Ok((input, (number_of_legs_encoded, passenger_name, electronic_ticket_indicator)))
+}
+
+/// Parses the data of a boarding pass preceding the security data section from `input`.
+fn bcbp_without_security_data<'a>(
+    input: &'a str,
+    parse_context: &mut ParseContext
+) -> IResult<&'a str, Bcbp, VerboseError<&'a str>> {
+    // Scan mandatory unique fields including the format code and the number of legs encoded.
+    let (input, (number_of_legs_encoded, passenger_name, electronic_ticket_indicator)) = header(input)?;
 
     // Collect the legs and metadata fields.
-    let mut legs = Vec::new();
+    let mut legs: Vec<Leg> = Vec::new();
     let mut metadata = Default::default();
+    let mut declared_leg_count = None;
 
     // Track the input as each leg is consumed.
     let mut input = input;
@@ -371,8 +508,18 @@ fn bcbp<'a>(input: &'a str) -> IResult<&'a str, Bcbp, VerboseError<&'a str>> {
     for leg_index in 0 .. number_of_legs_encoded {
         let is_first_leg = leg_index == 0;
 
+        // Some carriers declare more legs than they encode, the data ending after the last leg present.
+        let is_missing_legs = !is_first_leg
+            && (input.is_empty() || input.starts_with('^'))
+            && parse_context.tolerates(legs[0].operating_carrier_designator(), Quirk::MissingLegs);
+        if is_missing_legs {
+            parse_context.apply(Quirk::MissingLegs);
+            declared_leg_count = Some(number_of_legs_encoded);
+            break;
+        }
+
         // Consume the leg and, if available, the metadata embedded in the first leg.
-        let (next_input, (current_leg, first_leg_metadata)) = leg(input, is_first_leg)?;
+        let (next_input, (current_leg, first_leg_metadata)) = leg(input, is_first_leg, parse_context)?;
         if let Some(value) = first_leg_metadata {
             metadata = value;
         }
@@ -382,27 +529,84 @@ fn bcbp<'a>(input: &'a str) -> IResult<&'a str, Bcbp, VerboseError<&'a str>> {
         input = next_input;
     }
 
-    // Consume security data that follows the last leg, if any.
-    let (remainder, security_data) = security_data(input)?;
-
     Ok((
-        remainder,
+        input,
         Bcbp {
             passenger_name,
             electronic_ticket_indicator,
             metadata,
             legs,
-            security_data
+            declared_leg_count,
+            security_data: Default::default(),
         },
     ))
 }
 
-/// Parses a boarding pass from `input_data` representable as a string reference.
-pub fn from_str<I>(input_data: I) -> Result<Bcbp>
-where
-    I: AsRef<str>,
-{
-    let input = input_data.as_ref();
+/// Returns the number of bytes missing from a variable-size section which failed to parse at
+/// `offset`, from the size declared for it and the `available` length of its data.
+fn missing_section_len(input: &str, failed_field: field::Field, offset: usize, available: usize) -> Option<usize> {
+    // The size of the security data precedes the field, the size of other sections is the field.
+    let size_offset = match failed_field {
+        field::Field::FieldSizeOfVariableSizeField
+        | field::Field::FieldSizeOfStructuredMessageUnique
+        | field::Field::FieldSizeOfStructuredMessageRepeated => offset,
+        field::Field::SecurityData => offset.checked_sub(field::Field::LengthOfSecurityData.len())?,
+        _ => return None,
+    };
+    let size = input.get(size_offset .. size_offset + 2)?;
+    let declared_len = usize::from_str_radix(size, 16).ok()?;
+    Some(declared_len.saturating_sub(available))
+}
+
+/// Maps an error produced by the nom combinators over `input` to a library error.
+fn parse_error(input: &str, error: nom::Err<VerboseError<&str>>) -> Error {
+    match error {
+        nom::Err::Incomplete(_) => {
+            // Complete combinators do not report incomplete input, attribute it to the end of the input.
+            let failure = ParseFailure::new(field::Field::FormatCode, input.len(), "", Vec::new());
+            Error::UnexpectedEndOfInput(failure)
+        }
+        nom::Err::Error(verbose_error) | nom::Err::Failure(verbose_error) => {
+            // Each context names an enclosing field, attributed the innermost parser error kind.
+            let mut kind = String::new();
+            let mut trace = Vec::new();
+            for (remainder, error_kind) in verbose_error.errors.iter() {
+                match error_kind {
+                    VerboseErrorKind::Context(name) => {
+                        if let Ok(failed_field) = name.parse::<field::Field>() {
+                            trace.push(TraceFrame::new(failed_field, input.offset(remainder), kind.clone()));
+                        }
+                    }
+                    VerboseErrorKind::Char(_) =>
+                        kind = String::from("Char"),
+                    VerboseErrorKind::Nom(nom_error_kind) =>
+                        kind = format!("{:?}", nom_error_kind),
+                }
+            }
+
+            // Every field parser provides context, so the format code is only a fallback.
+            let (failed_field, offset) = trace.first()
+                .map_or((field::Field::FormatCode, 0), |frame| (frame.field(), frame.offset()));
+            let is_eof = trace.first().is_some_and(|frame| frame.kind() == "Eof");
+            let mut failure = ParseFailure::new(failed_field, offset, &input[offset ..], trace);
+
+            // The innermost error holds the data remaining for a section which ended early.
+            if let Some((available, _)) = verbose_error.errors.first().filter(|_| is_eof) {
+                if let Some(missing_len) = missing_section_len(input, failed_field, offset, available.len()) {
+                    failure = failure.with_missing_len(missing_len);
+                }
+            }
+            if failure.is_truncated() {
+                Error::UnexpectedEndOfInput(failure)
+            } else {
+                Error::ParseFailed(failure)
+            }
+        }
+    }
+}
+
+/// Parses a boarding pass from `input`, tolerating the deviations permitted by `parse_context`.
+fn parse(input: &str, parse_context: &mut ParseContext) -> Result<Bcbp> {
     if !input.is_ascii() {
         return Err(Error::InvalidCharacters);
     }
@@ -413,16 +617,177 @@ where
     }
 
     // Pass the provided input data with the nom combinator and map the error.
-    let (remainder, boarding_pass) = bcbp(input).map_err(|e| match e {
-        nom::Err::Incomplete(_) =>
-            Error::UnexpectedEndOfInput,
-        nom::Err::Error(verbose_error) | nom::Err::Failure(verbose_error) =>
-            Error::ParseFailed(convert_error(input, verbose_error)),
-    })?;
-
-    if remainder.len() > 0 {
+    let (remainder, boarding_pass) = bcbp(input, parse_context)
+        .map_err(|e| parse_error(input, e))?;
+
+    if !remainder.is_empty() {
         Err(Error::TrailingCharacters)
     } else {
         Ok(boarding_pass)
     }
 }
+
+/// Parses a boarding pass from `input_data` representable as a string reference.
+pub fn from_str<I>(input_data: I) -> Result<Bcbp>
+where
+    I: AsRef<str>,
+{
+    parse(input_data.as_ref(), &mut Default::default())
+}
+
+/// Parses a boarding pass from `input_data`, tolerating the deviations described by `quirks`.
+///
+/// Returns the boarding pass along with the quirks it was found to exhibit, in the order encountered.
+pub fn from_str_with_quirks<I>(input_data: I, quirks: &Quirks) -> Result<(Bcbp, Vec<Quirk>)>
+where
+    I: AsRef<str>,
+{
+    let mut parse_context = ParseContext {
+        quirks: Some(quirks),
+        applied_quirks: Vec::new(),
+    };
+    let boarding_pass = parse(input_data.as_ref(), &mut parse_context)?;
+    quirks::detect(quirks, &boarding_pass, &mut parse_context.applied_quirks);
+    Ok((boarding_pass, parse_context.applied_quirks))
+}
+
+/// Returns the input following the leg beginning at `input`, delimited by the size of its conditional
+/// section regardless of the validity of its items, or None if the size cannot be read.
+fn skip_leg(input: &str) -> Option<&str> {
+    let mandatory_len: usize = [
+        field::Field::OperatingCarrierPnrCode,
+        field::Field::FromCityAirportCode,
+        field::Field::ToCityAirportCode,
+        field::Field::OperatingCarrierDesignator,
+        field::Field::FlightNumber,
+        field::Field::DateOfFlight,
+        field::Field::CompartmentCode,
+        field::Field::SeatNumber,
+        field::Field::CheckInSequenceNumber,
+        field::Field::PassengerStatus,
+    ].iter().map(|field_id| field_id.len()).sum();
+
+    let conditional_item_data = input.get(mandatory_len ..)?;
+    variable_size_field_data(conditional_item_data, field::Field::FieldSizeOfVariableSizeField)
+        .ok()
+        .map(|(remainder, _)| remainder)
+}
+
+/// Parses a boarding pass from `input_data`, isolating the legs which fail to parse.
+///
+/// A leg which fails to parse is recorded along with its error, and parsing resumes with the next leg
+/// using the size of the conditional section of the failed leg, then with the security data.
+/// Fails only if the data preceding the first leg cannot be parsed.
+pub fn from_str_recovering<I>(input_data: I) -> Result<PartialBcbp>
+where
+    I: AsRef<str>,
+{
+    let pass_input = input_data.as_ref();
+    if !pass_input.is_ascii() {
+        return Err(Error::InvalidCharacters);
+    }
+    if !pass_input.starts_with("M") {
+        return Err(Error::UnsupportedFormat);
+    }
+
+    let (mut input, (number_of_legs_encoded, passenger_name, electronic_ticket_indicator)) = header(pass_input)
+        .map_err(|e| parse_error(pass_input, e))?;
+
+    let mut legs = Vec::new();
+    let mut metadata = Default::default();
+    for leg_index in 0 .. number_of_legs_encoded as usize {
+        match leg(input, leg_index == 0, &mut Default::default()) {
+            Ok((next_input, (current_leg, first_leg_metadata))) => {
+                if let Some(value) = first_leg_metadata {
+                    metadata = value;
+                }
+                legs.push(ParsedLeg::Ok(current_leg));
+                input = next_input;
+            }
+            Err(error) => {
+                let error = LegError::new(leg_index, parse_error(pass_input, error));
+                let next_input = skip_leg(input);
+                let raw = &input[.. next_input.map_or(input.len(), |next_input| input.offset(next_input))];
+                legs.push(ParsedLeg::Failed { error, raw: String::from(raw) });
+                match next_input {
+                    Some(next_input) => input = next_input,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    // The security data can only be located if every leg was delimited.
+    let mut security_data = SecurityData::default();
+    let trailing_error = if legs.len() < number_of_legs_encoded as usize {
+        None
+    } else {
+        match self::security_data(input, false) {
+            Ok((remainder, mut parsed)) => {
+                if parsed.type_of_security_data.is_some() {
+                    let signed_len = pass_input.offset(input);
+                    parsed.signed_range = Some(0 .. signed_len);
+                    parsed.signed_payload = Some(String::from(&pass_input[.. signed_len]));
+                }
+                security_data = parsed;
+                Some(Error::TrailingCharacters).filter(|_| !remainder.is_empty())
+            }
+            Err(error) =>
+                Some(parse_error(pass_input, error)),
+        }
+    };
+
+    Ok(PartialBcbp {
+        passenger_name,
+        electronic_ticket_indicator,
+        metadata,
+        security_data,
+        number_of_legs_encoded: number_of_legs_encoded as usize,
+        legs,
+        trailing_error,
+    })
+}
+
+/// Splits `input_data` containing concatenated boarding passes into the data of each pass.
+///
+/// Passes are delimited using the lengths encoded within them, and may be separated by whitespace.
+/// Splitting ends at the first data which does not begin a complete boarding pass.
+pub fn split_passes(input_data: &str) -> Vec<&str> {
+    let mut passes = Vec::new();
+    let mut input = input_data.trim_start();
+
+    while input.starts_with('M') {
+        let remainder = match bcbp_without_security_data(input, &mut Default::default()) {
+            Ok((remainder, _)) if remainder.starts_with('^') => match security_data(remainder, false) {
+                Ok((remainder, _)) => remainder,
+                Err(_) => break,
+            },
+            Ok((remainder, _)) => remainder,
+            Err(_) => break,
+        };
+
+        let (pass, next_input) = input.split_at(input.offset(remainder));
+        passes.push(pass);
+        input = next_input.trim_start();
+    }
+
+    passes
+}
+
+/// Parses a single leg from `input`, as encoded in the second or subsequent leg of a boarding pass.
+///
+/// The input consists of the mandatory items of the leg followed by its conditional section.
+pub(crate) fn leg_from_str(input: &str) -> Result<Leg> {
+    if !input.is_ascii() {
+        return Err(Error::InvalidCharacters);
+    }
+
+    let (remainder, (parsed_leg, _)) = leg(input, false, &mut Default::default())
+        .map_err(|e| parse_error(input, e))?;
+
+    if !remainder.is_empty() {
+        Err(Error::TrailingCharacters)
+    } else {
+        Ok(parsed_leg)
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Bcbp;
+
+/// A known deviation from Resolution 792 exhibited by the boarding passes of some carriers.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Quirk {
+    /// The Document Type is 'I', used for itinerary receipts, rather than 'B'.
+    ItineraryReceiptDocumentType,
+    /// The first leg omits the Beginning of Version Number and the Version Number,
+    /// its conditional section beginning directly with the unique structured message size.
+    /// If the section is not structured, it is retained as airline individual use.
+    MissingVersionMarker,
+    /// The From City and To City Airport Codes are four characters long rather than three,
+    /// shifting the fields which follow within the leg.
+    /// Passes containing such codes cannot be encoded.
+    FourCharacterAirportCodes,
+    /// Fewer legs are present than the Number of Legs Encoded declares, the data ending
+    /// after the last leg present. The legs present are retained.
+    MissingLegs,
+    /// The Check-In Sequence Number does not take the form 'NNNN[f]'.
+    NonStandardCheckInSequenceNumber,
+    /// The Security Data is shorter than the Length of Security Data declares.
+    /// The data present is captured and the security data is reported as truncated.
+    TruncatedSecurityData,
+}
+
+/// Quirks documented for specific carriers, keyed by airline designator.
+const KNOWN_QUIRKS: &[(&str, Quirk)] = &[
+    ("AC", Quirk::ItineraryReceiptDocumentType),
+];
+
+/// A registry of quirks tolerated when parsing with `from_str_with_quirks`.
+///
+/// Each quirk applies either to a single carrier, identified by its airline designator,
+/// or to every carrier.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Quirks {
+    rules: Vec<(Option<String>, Quirk)>,
+}
+
+impl Quirks {
+    /// Returns an empty registry under which parsing is strict.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a registry containing the quirks known to this library.
+    pub fn known() -> Self {
+        KNOWN_QUIRKS.iter().fold(Quirks::new(), |quirks, &(carrier, quirk)| {
+            quirks.with_quirk(Some(carrier), quirk)
+        })
+    }
+
+    /// Adds `quirk` for the carrier with airline designator `carrier`, or for all carriers if `None`.
+    pub fn with_quirk(mut self, carrier: Option<&str>, quirk: Quirk) -> Self {
+        self.rules.push((carrier.map(|c| String::from(c.trim_end())), quirk));
+        self
+    }
+
+    /// Returns `true` if `quirk` is tolerated for the carrier with airline designator `carrier`.
+    pub fn applies_to(&self, carrier: &str, quirk: Quirk) -> bool {
+        let carrier = carrier.trim_end();
+        self.rules.iter().any(|(rule_carrier, rule_quirk)| {
+            *rule_quirk == quirk && rule_carrier.as_deref().map_or(true, |c| c == carrier)
+        })
+    }
+}
+
+/// Records in `applied` the quirks in `quirks` exhibited by the fields of `boarding_pass`.
+///
+/// These quirks do not affect parsing and are only reported.
+pub(crate) fn detect(quirks: &Quirks, boarding_pass: &Bcbp, applied: &mut Vec<Quirk>) {
+    let mut apply = |quirk: Quirk| {
+        if !applied.contains(&quirk) {
+            applied.push(quirk);
+        }
+    };
+
+    // The document type is attributed to the issuer, falling back to the first operating carrier.
+    let issuer = boarding_pass
+        .airline_designator_of_boarding_pass_issuer()
+        .unwrap_or_else(|| boarding_pass.first_leg().operating_carrier_designator());
+    if boarding_pass.document_type() == Some('I')
+        && quirks.applies_to(issuer, Quirk::ItineraryReceiptDocumentType)
+    {
+        apply(Quirk::ItineraryReceiptDocumentType);
+    }
+
+    for leg in boarding_pass.legs() {
+        let sequence_number = leg.check_in_sequence_number();
+        let is_standard = sequence_number.trim().is_empty()
+            || sequence_number[.. 4].bytes().all(|b| b.is_ascii_digit());
+        if !is_standard
+            && quirks.applies_to(leg.operating_carrier_designator(), Quirk::NonStandardCheckInSequenceNumber)
+        {
+            apply(Quirk::NonStandardCheckInSequenceNumber);
+        }
+    }
+}
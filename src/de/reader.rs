@@ -0,0 +1,42 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::io::{self, Read};
+
+use crate::bcbp::Bcbp;
+use crate::de::parser;
+use crate::error::Error;
+
+/// The length of the longest boarding pass: the unique mandatory items, fifteen legs with
+/// conditional sections of the largest encodable size, and security data of the largest size.
+const MAX_LEN: usize = 23 + 15 * (35 + 2 + 0xFF) + (2 + 2 + 0xFF);
+
+/// Returns `data` without a single trailing line terminator, as appended by scanners and text files.
+fn trim_line_terminator(data: &[u8]) -> &[u8] {
+    let data = data.strip_suffix(b"\n").unwrap_or(data);
+    data.strip_suffix(b"\r").unwrap_or(data)
+}
+
+impl Bcbp {
+    /// Parses a boarding pass read from `reader` until the end of its input.
+    ///
+    /// At most the length of the longest encodable boarding pass is read, followed by an optional
+    /// line terminator. Longer input, and input which does not parse, fail with
+    /// `io::ErrorKind::InvalidData`.
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Bcbp> {
+        let mut data = Vec::new();
+        reader.take(MAX_LEN as u64 + 3).read_to_end(&mut data)?;
+
+        let data = trim_line_terminator(&data);
+        if data.len() > MAX_LEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "input exceeds the length of a boarding pass"));
+        }
+
+        std::str::from_utf8(data)
+            .map_err(|_| Error::InvalidCharacters)
+            .and_then(parser::from_str)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::dynamic::unique_field;
+use crate::bcbp::{Bcbp, ConditionalMetadata, Leg, SecurityData};
+use crate::de::field::Field;
+use crate::error::{Error, LegError};
+use crate::fixed_string::FixedString;
+
+/// The outcome of parsing a leg with `from_str_recovering`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParsedLeg {
+    /// The leg was parsed successfully.
+    Ok(Leg),
+    /// The leg could not be parsed.
+    Failed {
+        /// The reason the leg could not be parsed.
+        error: LegError,
+        /// The data of the leg, or the remainder of the input if the leg could not be delimited.
+        raw: String,
+    },
+}
+
+impl ParsedLeg {
+    /// The leg, if it was parsed successfully.
+    pub fn leg(&self) -> Option<&Leg> {
+        match self {
+            ParsedLeg::Ok(leg) => Some(leg),
+            ParsedLeg::Failed { .. } => None,
+        }
+    }
+
+    /// The reason the leg could not be parsed, if it failed.
+    pub fn error(&self) -> Option<&LegError> {
+        match self {
+            ParsedLeg::Ok(_) => None,
+            ParsedLeg::Failed { error, .. } => Some(error),
+        }
+    }
+
+    /// Returns `true` if the leg was parsed successfully.
+    pub fn is_ok(&self) -> bool {
+        self.leg().is_some()
+    }
+}
+
+/// A boarding pass parsed by `from_str_recovering`, with the outcome of parsing each leg.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PartialBcbp {
+    pub(crate) passenger_name: FixedString<20>,
+    pub(crate) electronic_ticket_indicator: char,
+    pub(crate) metadata: ConditionalMetadata,
+    pub(crate) security_data: SecurityData,
+    pub(crate) number_of_legs_encoded: usize,
+    pub(crate) legs: Vec<ParsedLeg>,
+    pub(crate) trailing_error: Option<Error>,
+}
+
+impl PartialBcbp {
+    /// The number of legs declared by the boarding pass.
+    pub fn number_of_legs_encoded(&self) -> usize {
+        self.number_of_legs_encoded
+    }
+
+    /// The outcome of parsing each leg, in order.
+    ///
+    /// Fewer outcomes than legs encoded are present if a leg could not be delimited,
+    /// as the data following it cannot be attributed to any leg.
+    pub fn legs(&self) -> &[ParsedLeg] {
+        &self.legs
+    }
+
+    /// The errors of the legs which could not be parsed.
+    pub fn leg_errors(&self) -> impl Iterator<Item = &LegError> {
+        self.legs.iter().filter_map(ParsedLeg::error)
+    }
+
+    /// The value of a field unique to the boarding pass, as in `Bcbp::field`.
+    ///
+    /// The conditional items unique to the boarding pass are encoded within the first leg,
+    /// and are absent if it could not be parsed.
+    pub fn field(&self, field_id: Field) -> Option<&str> {
+        unique_field(
+            &self.passenger_name,
+            self.electronic_ticket_indicator,
+            &self.metadata,
+            &self.security_data,
+            field_id,
+        )
+    }
+
+    /// The security data, unset if it could not be parsed.
+    pub fn security_data(&self) -> &SecurityData {
+        &self.security_data
+    }
+
+    /// The failure to parse the security data or the data following it, if any.
+    pub fn trailing_error(&self) -> Option<&Error> {
+        self.trailing_error.as_ref()
+    }
+
+    /// Returns `true` if the boarding pass was parsed in its entirety without errors.
+    pub fn is_complete(&self) -> bool {
+        self.legs.len() == self.number_of_legs_encoded
+            && self.legs.iter().all(ParsedLeg::is_ok)
+            && self.trailing_error.is_none()
+    }
+
+    /// Returns the boarding pass containing only the legs which were parsed successfully,
+    /// or None if no leg was.
+    ///
+    /// The signed payload is discarded if any leg was dropped, as it no longer represents the pass.
+    pub fn to_bcbp(&self) -> Option<Bcbp> {
+        let legs: Vec<Leg> = self.legs.iter().filter_map(ParsedLeg::leg).cloned().collect();
+        if legs.is_empty() {
+            return None;
+        }
+
+        let mut security_data = self.security_data.clone();
+        // The signed payload covers the legs which were dropped.
+        if legs.len() != self.number_of_legs_encoded {
+            security_data.clear_signed_payload();
+        }
+
+        Some(Bcbp {
+            passenger_name: self.passenger_name,
+            electronic_ticket_indicator: self.electronic_ticket_indicator,
+            metadata: self.metadata.clone(),
+            legs,
+            declared_leg_count: None,
+            security_data,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::ops::Range;
+
+use crate::de::explain::explain;
+use crate::de::field::Field;
+
+/// The kind of a span of boarding pass data yielded by `spans`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TokenKind {
+    /// A mandatory item of the boarding pass or of a leg.
+    MandatoryField,
+    /// A field encoding the size of the section which follows it.
+    LengthField,
+    /// A conditional item, including the beginning of version number indicator.
+    ConditionalField,
+    /// Airline individual use data.
+    AirlineIndividualUse,
+    /// The security data section, excluding its length.
+    SecurityData,
+    /// A field which is incomplete or does not conform to its data format.
+    Invalid,
+    /// Data not recognized as part of any field, such as trailing characters
+    /// or conditional items defined in versions newer than those supported.
+    Unknown,
+}
+
+/// Returns the kind of a valid `field`.
+fn token_kind(field: Field) -> TokenKind {
+    match field {
+        Field::FieldSizeOfVariableSizeField |
+        Field::FieldSizeOfStructuredMessageUnique |
+        Field::FieldSizeOfStructuredMessageRepeated |
+        Field::LengthOfSecurityData =>
+            TokenKind::LengthField,
+        Field::FormatCode |
+        Field::NumberOfLegsEncoded |
+        Field::PassengerName |
+        Field::ElectronicTicketIndicator |
+        Field::OperatingCarrierPnrCode |
+        Field::FromCityAirportCode |
+        Field::ToCityAirportCode |
+        Field::OperatingCarrierDesignator |
+        Field::FlightNumber |
+        Field::DateOfFlight |
+        Field::CompartmentCode |
+        Field::SeatNumber |
+        Field::CheckInSequenceNumber |
+        Field::PassengerStatus =>
+            TokenKind::MandatoryField,
+        Field::AirlineIndividualUse =>
+            TokenKind::AirlineIndividualUse,
+        Field::BeginningOfSecurityData |
+        Field::TypeOfSecurityData |
+        Field::SecurityData =>
+            TokenKind::SecurityData,
+        _ =>
+            TokenKind::ConditionalField,
+    }
+}
+
+/// Returns contiguous, non-overlapping spans covering `input_data`, each classified by kind.
+///
+/// Intended for syntax highlighting, the spans cover the entire input even when it does not parse.
+/// Data which follows an invalid field is yielded as a single `TokenKind::Unknown` span.
+pub fn spans<I>(input_data: I) -> impl Iterator<Item = (Range<usize>, TokenKind)>
+where
+    I: AsRef<str>,
+{
+    let input = input_data.as_ref();
+
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for annotation in explain(input) {
+        let range = annotation.range();
+        if range.is_empty() {
+            continue;
+        }
+        if range.start > offset {
+            spans.push((offset .. range.start, TokenKind::Unknown));
+        }
+        offset = range.end;
+
+        let kind = if annotation.is_valid() {
+            token_kind(annotation.field())
+        } else {
+            TokenKind::Invalid
+        };
+        spans.push((range, kind));
+    }
+
+    if offset < input.len() {
+        spans.push((offset .. input.len(), TokenKind::Unknown));
+    }
+
+    spans.into_iter()
+}
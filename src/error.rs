@@ -7,18 +7,178 @@ use std::error;
 use std::fmt;
 use std::result;
 
+use crate::de::Field;
+
+/// A field being parsed when a failure occurred.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TraceFrame {
+    field: Field,
+    offset: usize,
+    kind: String,
+}
+
+impl TraceFrame {
+    /// Returns a frame for `field` beginning at `offset` which failed with the parser error `kind`.
+    pub(crate) fn new(field: Field, offset: usize, kind: String) -> Self {
+        TraceFrame {
+            field,
+            offset,
+            kind,
+        }
+    }
+
+    /// The field being parsed.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The offset within the input at which parsing of the field began, in bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The kind of the underlying parser error, e.g. `Char`, `Eof` or `TakeWhileMN`.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+}
+
+/// The field and position at which parsing failed.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParseFailure {
+    field: Field,
+    offset: usize,
+    found: String,
+    missing_len: Option<usize>,
+    trace: Vec<TraceFrame>,
+}
+
+impl ParseFailure {
+    /// Returns a failure to parse `field` beginning at `offset`, where `remainder` is the input at that offset.
+    ///
+    /// Data past the end of the input is represented by '?' characters.
+    pub(crate) fn new(field: Field, offset: usize, remainder: &str, trace: Vec<TraceFrame>) -> Self {
+        let found = if field.len() > 0 {
+            let mut found: String = remainder.chars().take(field.len()).collect();
+            while found.len() < field.len() {
+                found.push('?');
+            }
+            found
+        } else {
+            String::from(remainder)
+        };
+
+        let missing_len = Some(field.len().saturating_sub(remainder.len())).filter(|&len| len > 0);
+
+        ParseFailure {
+            field,
+            offset,
+            found,
+            missing_len,
+            trace,
+        }
+    }
+
+    /// Returns the failure with `missing_len` bytes of a variable-size section missing,
+    /// as computed from the size declared for the section.
+    pub(crate) fn with_missing_len(mut self, missing_len: usize) -> Self {
+        self.missing_len = Some(missing_len).filter(|&len| len > 0);
+        self
+    }
+
+    /// Returns `true` if the failure is due to the input ending before the field,
+    /// either because the field extends past the end of the input or because
+    /// its parser reached the end of the input.
+    pub(crate) fn is_truncated(&self) -> bool {
+        self.missing_len.is_some() || self.trace.first().is_some_and(|frame| frame.kind() == "Eof")
+    }
+
+    /// The field which could not be parsed.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The offset of the field within the input, in bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The data found in place of the field.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// The number of bytes of a fixed-length field, or of a variable-size section as declared
+    /// by its size, missing from the end of the data, or None if the field is complete.
+    pub fn missing_len(&self) -> Option<usize> {
+        self.missing_len
+    }
+
+    /// The fields being parsed when the failure occurred, from the innermost outwards.
+    pub fn trace(&self) -> &[TraceFrame] {
+        &self.trace
+    }
+}
+
+impl fmt::Display for ParseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Item {} ({}) at offset {}: ", self.field.item_number(), self.field, self.offset)?;
+
+        // Literals and hexadecimal sizes name the expected token rather than the data format.
+        match self.field {
+            Field::NumberOfLegsEncoded =>
+                write!(f, "invalid leg count '{}'", self.found),
+            Field::FormatCode =>
+                write!(f, "expected 'M', found '{}'", self.found),
+            Field::BeginningOfVersionNumber =>
+                write!(f, "expected '>', found '{}'", self.found),
+            Field::BeginningOfSecurityData =>
+                write!(f, "expected '^', found '{}'", self.found),
+            Field::FieldSizeOfVariableSizeField
+            | Field::FieldSizeOfStructuredMessageUnique
+            | Field::FieldSizeOfStructuredMessageRepeated
+            | Field::LengthOfSecurityData =>
+                write!(f, "expected hexadecimal length, found '{}'", self.found),
+            _ =>
+                write!(f, "expected '{}', found '{}'", self.field.data_format(), self.found),
+        }
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "kind", content = "detail"))]
 pub enum Error {
     /// The BCBP string does not contain exclusively ASCII characters.
     InvalidCharacters,
     /// The BCBP format is not supported.
     UnsupportedFormat,
-    /// The end of otherwise-valid IATA BCBP data was reached prematurely.
-    UnexpectedEndOfInput,
+    /// The end of otherwise-valid IATA BCBP data was reached prematurely while parsing a field.
+    UnexpectedEndOfInput(ParseFailure),
     /// Parsing the encoded data failed.
-    ParseFailed(String),
+    ParseFailed(ParseFailure),
     /// After successfully parsing a BCBP object, additional characters remain.
     TrailingCharacters,
+    /// The name or item number does not identify a field defined in the Implementation Guide.
+    UnknownField,
+    /// The boarding pass does not contain a security data section.
+    MissingSecurityData,
+    /// The security data does not verify against the signed boarding pass data.
+    InvalidSecurityData,
+    /// The data to encode does not fit within the length or count encoded in the field.
+    LengthOverflow(Field),
+    /// The value does not conform to the data format of the field.
+    InvalidFieldValue(Field),
+    /// A mandatory field is not set.
+    MissingField(Field),
+    /// The encoded boarding pass exceeds the capacity of the barcode symbology.
+    CapacityExceeded,
+    /// The destination of the encoded boarding pass failed to accept the data.
+    WriteFailed,
+    /// A failure reported by a data structure being serialized or deserialized, or malformed JSON.
+    /// Only produced when the `serde` or `serde_json` features are enabled.
+    Custom(String),
 }
 
 impl error::Error for Error {}
@@ -26,18 +186,128 @@ impl error::Error for Error {}
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            &Error::InvalidCharacters =>
+            Error::InvalidCharacters =>
                 write!(f, "non-ASCII characters"),
-            &Error::UnsupportedFormat =>
+            Error::UnsupportedFormat =>
                 write!(f, "not an IATA BCBP Type M boarding pass"),
-            &Error::UnexpectedEndOfInput =>
-                write!(f, "unexpected end-of-input"),
-            &Error::ParseFailed(ref reason) =>
-                write!(f, "parse failed: {}", reason),
-            &Error::TrailingCharacters =>
+            Error::UnexpectedEndOfInput(failure) => {
+                write!(f, "unexpected end-of-input reading Item {} ({}) at offset {}",
+                    failure.field().item_number(),
+                    failure.field(),
+                    failure.offset()
+                )?;
+                match failure.missing_len() {
+                    Some(1) => write!(f, ", 1 byte missing"),
+                    Some(missing_len) => write!(f, ", {} bytes missing", missing_len),
+                    None => Ok(()),
+                }
+            }
+            Error::ParseFailed(failure) =>
+                write!(f, "{}", failure),
+            Error::TrailingCharacters =>
                 write!(f, "input includes data after a valid boarding pass"),
+            Error::UnknownField =>
+                write!(f, "unknown field"),
+            Error::MissingSecurityData =>
+                write!(f, "no security data present"),
+            Error::InvalidSecurityData =>
+                write!(f, "security data verification failed"),
+            Error::LengthOverflow(field) =>
+                write!(f, "data exceeds the maximum encodable in {}", field),
+            Error::InvalidFieldValue(field) =>
+                write!(f, "invalid value for {}", field),
+            Error::MissingField(field) =>
+                write!(f, "{} is not set", field),
+            Error::CapacityExceeded =>
+                write!(f, "data exceeds the capacity of the barcode"),
+            Error::WriteFailed =>
+                write!(f, "writing the encoded data failed"),
+            Error::Custom(message) =>
+                write!(f, "{}", message),
+        }
+    }
+}
+
+/// A field which prevents a boarding pass from being encoded.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BuildError {
+    field: Field,
+    leg_index: Option<usize>,
+    error: Error,
+}
+
+impl BuildError {
+    /// Returns an error encoding `field`, within the leg at `leg_index` if not unique to the boarding pass.
+    pub(crate) fn new(field: Field, leg_index: Option<usize>, error: Error) -> Self {
+        BuildError {
+            field,
+            leg_index,
+            error,
+        }
+    }
+
+    /// The field which cannot be encoded.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The index of the leg containing the field, or None if the field is unique to the boarding pass.
+    pub fn leg_index(&self) -> Option<usize> {
+        self.leg_index
+    }
+
+    /// The reason the field cannot be encoded.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.leg_index {
+            Some(leg_index) => write!(f, "leg {}: {}", leg_index + 1, self.error),
+            None => write!(f, "{}", self.error),
         }
     }
 }
 
+impl error::Error for BuildError {}
+
+/// A leg which could not be parsed, isolated from the rest of the boarding pass.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LegError {
+    leg_index: usize,
+    error: Error,
+}
+
+impl LegError {
+    /// Returns an error parsing the leg at `leg_index`.
+    pub(crate) fn new(leg_index: usize, error: Error) -> Self {
+        LegError {
+            leg_index,
+            error,
+        }
+    }
+
+    /// The index of the leg which could not be parsed.
+    pub fn leg_index(&self) -> usize {
+        self.leg_index
+    }
+
+    /// The reason the leg could not be parsed, with offsets relative to the start of the boarding pass.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for LegError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "leg {}: {}", self.leg_index + 1, self.error)
+    }
+}
+
+impl error::Error for LegError {}
+
 pub type Result<T> = result::Result<T, Error>;
@@ -0,0 +1,136 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// The error returned when a string does not fit within the capacity of a `FixedString`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(crate) struct CapacityError;
+
+/// A string of at most `N` bytes stored inline, used for fixed-width fields.
+#[derive(Copy, Clone)]
+pub(crate) struct FixedString<const N: usize> {
+    bytes: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> FixedString<N> {
+    /// The capacity of the string in bytes, which must be representable by the length.
+    const CAPACITY: usize = {
+        assert!(N <= u8::MAX as usize);
+        N
+    };
+
+    /// Returns an empty string.
+    pub(crate) const fn new() -> Self {
+        FixedString { bytes: [0; N], len: 0 }
+    }
+
+    /// Returns a copy of `s`, failing if it is longer than the capacity.
+    pub(crate) fn from(s: &str) -> Result<Self, CapacityError> {
+        let mut value = Self::new();
+        value.try_push_str(s)?;
+        Ok(value)
+    }
+
+    /// The capacity of the string in bytes.
+    pub(crate) const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// Appends `s`, failing without modifying the string if it does not fit.
+    pub(crate) fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let len = usize::from(self.len);
+        if s.len() > self.capacity() - len {
+            return Err(CapacityError);
+        }
+        self.bytes[len .. len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len() as u8;
+        Ok(())
+    }
+
+    /// Appends `s`.
+    ///
+    /// # Panics
+    /// Panics if `s` does not fit within the remaining capacity.
+    pub(crate) fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).expect("string exceeds the capacity of the field")
+    }
+
+    /// Appends `c`.
+    ///
+    /// # Panics
+    /// Panics if `c` does not fit within the remaining capacity.
+    pub(crate) fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// The contents of the string.
+    pub(crate) fn as_str(&self) -> &str {
+        // Only whole strings are ever copied into the buffer.
+        std::str::from_utf8(&self.bytes[.. usize::from(self.len)]).unwrap()
+    }
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for FixedString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> PartialOrd for FixedString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FixedString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for FixedString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
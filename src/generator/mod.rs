@@ -0,0 +1,271 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Generation of random, structurally valid boarding passes.
+//!
+//! Generated passes conform to the field rules of the chosen version, so they parse, encode and
+//! validate without errors. Generation is deterministic for a given seed and configuration.
+//!
+//! By default values are random strings of the right format. Passes resembling live traffic, for
+//! demonstrations and screenshots, draw names, airports and airlines from bundled lists instead.
+
+mod pools;
+
+use std::str::FromStr;
+
+use crate::bcbp::{AirportCode, Bcbp, CarrierDesignator, Leg, PnrCode, MAX_NUMBER_OF_LEGS};
+use crate::validation::ValidationProfile;
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &[u8] = b"0123456789";
+const ALPHANUMERICS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// A SplitMix64 pseudo-random number generator, sufficient for test data.
+#[derive(Clone, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `low ..= high`.
+    fn range(&mut self, low: usize, high: usize) -> usize {
+        low + (self.next_u64() % (high - low + 1) as u64) as usize
+    }
+
+    /// Returns `true` with the probability `p`.
+    fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    fn choose(&mut self, characters: &[u8]) -> char {
+        char::from(characters[self.range(0, characters.len() - 1)])
+    }
+
+    fn string(&mut self, characters: &[u8], len: usize) -> String {
+        (0 .. len).map(|_| self.choose(characters)).collect()
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0, items.len() - 1)]
+    }
+
+    /// Returns an airline designator and its airline numeric code, real if `realistic`.
+    fn airline(&mut self, realistic: bool) -> (String, String) {
+        if realistic {
+            let (designator, numeric_code) = self.pick(pools::AIRLINES);
+            (String::from(designator), String::from(numeric_code))
+        } else {
+            (self.string(LETTERS, 2), self.string(DIGITS, 3))
+        }
+    }
+}
+
+/// Generates random boarding passes which are structurally valid for a version of the standard.
+///
+/// # Example
+/// ```rust
+/// use iata_bcbp::generator::Generator;
+///
+/// let mut generator = Generator::new(42).with_leg_count(2);
+/// let pass_data = generator.generate();
+/// assert_eq!(pass_data.legs().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: Rng,
+    leg_count: Option<usize>,
+    profile: ValidationProfile,
+    optional_field_density: f64,
+    realistic_data: bool,
+}
+
+impl Generator {
+    /// Returns a generator of passes conforming to version 6 with between one and four legs,
+    /// setting half of the conditional items, seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            rng: Rng(seed),
+            leg_count: None,
+            profile: ValidationProfile::V6,
+            optional_field_density: 0.5,
+            realistic_data: false,
+        }
+    }
+
+    /// Generates passes with exactly `leg_count` legs.
+    ///
+    /// # Panics
+    /// Panics if `leg_count` is zero or exceeds the 4 legs a boarding pass may contain.
+    pub fn with_leg_count(mut self, leg_count: usize) -> Self {
+        assert!((1 ..= MAX_NUMBER_OF_LEGS).contains(&leg_count), "a boarding pass contains between 1 and 4 legs");
+        self.leg_count = Some(leg_count);
+        self
+    }
+
+    /// Generates passes declaring and conforming to the version `profile`.
+    /// Items not yet defined in that version are never set.
+    pub fn with_version(mut self, profile: ValidationProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Sets the probability of each conditional item being set, from 0.0 for passes containing
+    /// only the version number and mandatory items, to 1.0 for passes containing every item.
+    pub fn with_optional_field_density(mut self, density: f64) -> Self {
+        self.optional_field_density = density.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draws passenger names, airports and airlines from lists of real-world values
+    /// rather than generating random strings.
+    pub fn with_realistic_data(mut self, realistic_data: bool) -> Self {
+        self.realistic_data = realistic_data;
+        self
+    }
+
+    /// Returns `value` with the probability of the optional field density.
+    fn optional<T>(&mut self, value: impl FnOnce(&mut Rng) -> T) -> Option<T> {
+        if self.rng.chance(self.optional_field_density) {
+            Some(value(&mut self.rng))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a random three-letter airport code.
+    fn airport_code(&mut self) -> AirportCode {
+        let airport_code = if self.realistic_data {
+            String::from(self.rng.pick(pools::AIRPORTS))
+        } else {
+            self.rng.string(LETTERS, 3)
+        };
+        AirportCode::from_str(&airport_code).unwrap()
+    }
+
+    /// Returns a random passenger name.
+    fn passenger_name(&mut self) -> String {
+        if self.realistic_data {
+            format!("{}/{}", self.rng.pick(pools::SURNAMES), self.rng.pick(pools::GIVEN_NAMES))
+        } else {
+            let surname_len = self.rng.range(2, 10);
+            let surname = self.rng.string(LETTERS, surname_len);
+            let given_name_len = self.rng.range(2, 8);
+            format!("{}/{}", surname, self.rng.string(LETTERS, given_name_len))
+        }
+    }
+
+    /// Generates a leg departing from `from`.
+    fn leg(&mut self, from: AirportCode) -> Leg {
+        let mut to = self.airport_code();
+        while to == from {
+            to = self.airport_code();
+        }
+
+        let realistic = self.realistic_data;
+        let (designator, numeric_code) = self.rng.airline(realistic);
+
+        let mut leg = Leg::default();
+        let pnr_code = PnrCode::from_str(&self.rng.string(ALPHANUMERICS, 6)).unwrap();
+        leg.set_operating_carrier_pnr_code(pnr_code).unwrap();
+        leg.set_from_city_airport_code(from).unwrap();
+        leg.set_to_city_airport_code(to).unwrap();
+        leg.set_operating_carrier_designator(CarrierDesignator::from_str(&designator).unwrap()).unwrap();
+        leg.set_flight_number(&format!("{:04}", self.rng.range(1, 9999))).unwrap();
+        leg.set_date_of_flight(format!("{:03}", self.rng.range(1, 365))).unwrap();
+        leg.set_compartment_code(self.rng.choose(b"FJCYMW")).unwrap();
+        leg.set_seat_number(&format!("{:03}{}", self.rng.range(1, 60), self.rng.choose(b"ABCDEFGHJK"))).unwrap();
+        leg.set_check_in_sequence_number(&format!("{:04}", self.rng.range(1, 400))).unwrap();
+        leg.set_passenger_status(self.rng.choose(b"0123")).unwrap();
+
+        let airline_numeric_code = self.optional(|_| numeric_code);
+        let document_form_serial_number = self.optional(|rng| rng.string(DIGITS, 10));
+        // The Selectee Indicator is required beginning with version 6.
+        let selectee_indicator = if self.profile >= ValidationProfile::V6 {
+            Some(self.rng.choose(b"013"))
+        } else {
+            self.optional(|rng| rng.choose(b"013"))
+        };
+        let international_document_verification = self.optional(|rng| rng.choose(b"012"));
+        let marketing_carrier_designator = self.optional(|rng| rng.airline(realistic).0);
+        let frequent_flyer_airline_designator = self.optional(|rng| rng.airline(realistic).0);
+        let frequent_flyer_number = self.optional(|rng| rng.string(DIGITS, 13));
+        let id_ad_indicator = self.optional(|rng| rng.choose(b"0123456789"));
+        let free_baggage_allowance = self.optional(|rng| format!("{}PC", rng.range(0, 3)));
+        let fast_track = if self.profile >= ValidationProfile::V5 {
+            self.optional(|rng| rng.choose(b"YN"))
+        } else {
+            None
+        };
+
+        leg.set_airline_numeric_code(airline_numeric_code.as_deref()).unwrap();
+        leg.set_document_form_serial_number(document_form_serial_number.as_deref()).unwrap();
+        leg.set_selectee_indicator(selectee_indicator).unwrap();
+        leg.set_international_document_verification(international_document_verification).unwrap();
+        leg.set_marketing_carrier_designator(marketing_carrier_designator.as_deref()).unwrap();
+        leg.set_frequent_flyer_airline_designator(frequent_flyer_airline_designator.as_deref()).unwrap();
+        leg.set_frequent_flyer_number(frequent_flyer_number.as_deref()).unwrap();
+        leg.set_id_ad_indicator(id_ad_indicator).unwrap();
+        leg.set_free_baggage_allowance(free_baggage_allowance.as_deref()).unwrap();
+        leg.set_fast_track(fast_track).unwrap();
+        leg
+    }
+
+    /// Generates a boarding pass.
+    pub fn generate(&mut self) -> Bcbp {
+        let leg_count = match self.leg_count {
+            Some(leg_count) => leg_count,
+            None => self.rng.range(1, 4),
+        };
+
+        let mut boarding_pass = Bcbp::default();
+        let passenger_name = self.passenger_name();
+        boarding_pass.set_passenger_name(&passenger_name).unwrap();
+        boarding_pass.set_electronic_ticket_indicator('E').unwrap();
+        boarding_pass.set_version_number(Some(self.profile.version_number())).unwrap();
+
+        let passenger_description = self.optional(|rng| rng.choose(b"0123"));
+        let source_of_check_in = self.optional(|rng| rng.choose(b"WKRMOTV"));
+        let source_of_boarding_pass_issuance = self.optional(|rng| rng.choose(b"WKRMOTV"));
+        let date_of_issue = self.optional(|rng| format!("{}{:03}", rng.choose(DIGITS), rng.range(1, 365)));
+        let document_type = self.optional(|rng| rng.choose(b"BI"));
+        let realistic = self.realistic_data;
+        let issuer = self.optional(|rng| rng.airline(realistic).0);
+        let baggage_tag = self.optional(|rng| format!("0{}{:03}", rng.string(DIGITS, 9), rng.range(0, 2)));
+        boarding_pass.set_passenger_description(passenger_description).unwrap();
+        boarding_pass.set_source_of_check_in(source_of_check_in).unwrap();
+        boarding_pass.set_source_of_boarding_pass_issuance(source_of_boarding_pass_issuance).unwrap();
+        boarding_pass.set_date_of_issue_of_boarding_pass(date_of_issue.as_deref()).unwrap();
+        boarding_pass.set_document_type(document_type).unwrap();
+        boarding_pass.set_airline_designator_of_boarding_pass_issuer(issuer.as_deref()).unwrap();
+        boarding_pass.set_baggage_tag_license_plate_numbers(baggage_tag.as_deref()).unwrap();
+
+        // Each leg departs from the destination of the previous leg.
+        let mut from = self.airport_code();
+        boarding_pass.legs = (0 .. leg_count)
+            .map(|_| {
+                let leg = self.leg(from);
+                from = AirportCode::from_str(leg.to_city_airport_code()).unwrap();
+                leg
+            })
+            .collect();
+
+        boarding_pass
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Bcbp;
+
+    /// Generates boarding passes without end.
+    fn next(&mut self) -> Option<Bcbp> {
+        Some(self.generate())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Bundled lists of real-world values for generating passes resembling live traffic.
+
+/// Airline designators and the corresponding three-digit airline numeric codes.
+pub(crate) const AIRLINES: &[(&str, &str)] = &[
+    ("AA", "001"),
+    ("AC", "014"),
+    ("AF", "057"),
+    ("AM", "139"),
+    ("AS", "027"),
+    ("AY", "105"),
+    ("AZ", "055"),
+    ("B6", "279"),
+    ("BA", "125"),
+    ("CX", "160"),
+    ("DL", "006"),
+    ("EI", "053"),
+    ("EK", "176"),
+    ("ET", "071"),
+    ("EY", "607"),
+    ("IB", "075"),
+    ("JL", "131"),
+    ("KE", "180"),
+    ("KL", "074"),
+    ("LA", "045"),
+    ("LH", "220"),
+    ("LO", "080"),
+    ("LX", "724"),
+    ("NH", "205"),
+    ("NZ", "086"),
+    ("OS", "257"),
+    ("QF", "081"),
+    ("QR", "157"),
+    ("SK", "117"),
+    ("SQ", "618"),
+    ("TK", "235"),
+    ("TP", "047"),
+    ("UA", "016"),
+    ("VS", "932"),
+    ("WS", "838"),
+];
+
+/// Airport codes of major airports.
+pub(crate) const AIRPORTS: &[&str] = &[
+    "AMS", "ATL", "AKL", "BCN", "BKK", "BOS", "CDG", "DEN", "DFW", "DOH", "DUB", "DXB", "EWR", "FCO",
+    "FRA", "GRU", "HKG", "HND", "IAD", "ICN", "IST", "JFK", "LAX", "LHR", "LIS", "MAD", "MEX", "MIA",
+    "MUC", "NRT", "ORD", "PEK", "SEA", "SFO", "SIN", "SYD", "VIE", "WAW", "YUL", "YVR", "YYZ", "ZRH",
+];
+
+/// Common surnames.
+pub(crate) const SURNAMES: &[&str] = &[
+    "ANDERSEN", "BROWN", "CHEN", "DESMARAIS", "DUBOIS", "GARCIA", "HANSEN", "IVANOVA", "JOHNSON",
+    "KIM", "KOWALSKI", "LEE", "MARTIN", "MUELLER", "NGUYEN", "OKAFOR", "PATEL", "ROSSI", "SANTOS",
+    "SATO", "SILVA", "SMITH", "TANAKA", "WANG", "WILLIAMS",
+];
+
+/// Common given names.
+pub(crate) const GIVEN_NAMES: &[&str] = &[
+    "ADAM", "AIKO", "ALEX", "AMARA", "ANA", "CARLOS", "CHLOE", "DAVID", "ELENA", "EMMA", "HANNA",
+    "JAMES", "LUC", "LUCIA", "MARIA", "MEI", "MOHAMMED", "NOAH", "OLIVIA", "PRIYA", "SAM", "SOFIA",
+    "TOMAS", "WEI", "YUKI",
+];
@@ -0,0 +1,64 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::dynamic::non_blank;
+use crate::bcbp::Bcbp;
+
+/// Decodes structured data embedded in the Airline Individual Use section of a leg.
+///
+/// The format of the section is at the discretion of the carrier and is not published,
+/// so no decoders are built in. Decoders are registered per carrier in `IndividualUseDecoders`.
+pub trait IndividualUseDecoder {
+    /// Returns the named values encoded in `airline_individual_use`,
+    /// or `None` if the data is not in the format understood by the decoder.
+    fn decode(&self, airline_individual_use: &str) -> Option<Vec<(&'static str, String)>>;
+}
+
+/// A registry of individual use decoders keyed by the airline designator of the issuing carrier.
+#[derive(Default)]
+pub struct IndividualUseDecoders {
+    decoders: Vec<(String, Box<dyn IndividualUseDecoder>)>,
+}
+
+impl IndividualUseDecoders {
+    /// Returns an empty registry.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers `decoder` for the carrier with airline designator `carrier`,
+    /// taking precedence over any decoders registered previously for that carrier.
+    pub fn with_decoder<D>(mut self, carrier: &str, decoder: D) -> Self
+    where
+        D: IndividualUseDecoder + 'static,
+    {
+        self.decoders.insert(0, (String::from(carrier.trim_end()), Box::new(decoder)));
+        self
+    }
+
+    /// Decodes `airline_individual_use` issued by the carrier with airline designator `carrier`
+    /// using the first registered decoder which recognizes the data.
+    pub fn decode(&self, carrier: &str, airline_individual_use: &str) -> Option<Vec<(&'static str, String)>> {
+        let carrier = carrier.trim_end();
+        self.decoders
+            .iter()
+            .filter(|(decoder_carrier, _)| decoder_carrier == carrier)
+            .find_map(|(_, decoder)| decoder.decode(airline_individual_use))
+    }
+}
+
+impl Bcbp {
+    /// Decodes the Airline Individual Use section of the leg at `leg_index` using `decoders`.
+    ///
+    /// The data is attributed to the issuer of the boarding pass, or to the operating carrier
+    /// of the leg if the issuer is not set.
+    pub fn decode_individual_use(&self, leg_index: usize, decoders: &IndividualUseDecoders) -> Option<Vec<(&'static str, String)>> {
+        let leg = self.leg(leg_index)?;
+        let airline_individual_use = leg.airline_individual_use()?;
+        let carrier = non_blank(self.airline_designator_of_boarding_pass_issuer())
+            .unwrap_or_else(|| leg.operating_carrier_designator());
+        decoders.decode(carrier, airline_individual_use)
+    }
+}
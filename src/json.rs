@@ -0,0 +1,122 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use serde_json::{Map, Value};
+
+use crate::bcbp::{Bcbp, Leg, MAX_NUMBER_OF_LEGS};
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+
+/// Returns the JSON property name of `field`, its Implementation Guide name in lower camel case.
+pub(crate) fn property_name(field: Field) -> String {
+    let name = format!("{:?}", field);
+    let mut chars = name.chars();
+    chars.next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// Returns an object with the trimmed value of each field present in `fields`.
+fn fields_object<'a, F>(fields: F) -> Map<String, Value>
+where
+    F: Iterator<Item = (Field, &'a str)>,
+{
+    fields
+        .map(|(field, value)| (property_name(field), Value::from(value.trim())))
+        .collect()
+}
+
+/// Returns the field whose JSON property name is `name`.
+fn field_named(name: &str) -> Result<Field> {
+    Field::all().iter().cloned().find(|&field| property_name(field) == name).ok_or(Error::UnknownField)
+}
+
+/// Returns the string value of `field` in a JSON object, or None if it is null.
+///
+/// Values are trimmed by `to_json`, so an empty single-character field is restored as a space.
+fn field_value(field: Field, value: &Value) -> Result<Option<&str>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(value) if value.is_empty() && field.len() == 1 => Ok(Some(" ")),
+        Value::String(value) => Ok(Some(value)),
+        _ => Err(Error::InvalidFieldValue(field)),
+    }
+}
+
+/// Returns the properties of a JSON object, failing with `field` if `value` is not an object.
+fn object(value: &Value, field: Field) -> Result<&Map<String, Value>> {
+    value.as_object().ok_or(Error::InvalidFieldValue(field))
+}
+
+impl Leg {
+    fn from_json_object(object: &Map<String, Value>) -> Result<Leg> {
+        let mut leg = Leg::default();
+        for (name, value) in object {
+            let field = field_named(name)?;
+            leg.set_field(field, field_value(field, value)?)?;
+        }
+        Ok(leg)
+    }
+
+    fn to_json_object(&self) -> Map<String, Value> {
+        fields_object(Field::all().iter().filter_map(|&field| {
+            self.field(field).map(|value| (field, value))
+        }))
+    }
+}
+
+impl Bcbp {
+    /// Returns the boarding pass as a JSON object whose property names follow the
+    /// Implementation Guide item names, suitable for modern data-sharing pipelines.
+    ///
+    /// Each item present is a string property named in lower camel case, e.g. `passengerName`,
+    /// with surrounding spaces removed. Legs are an array of objects in the `legs` property.
+    /// Size fields are omitted as they are implied by the data.
+    pub fn to_json(&self) -> Value {
+        let mut object = fields_object(Field::all().iter().filter_map(|&field| {
+            self.field(field).map(|value| (field, value))
+        }));
+        let legs = self.legs().iter().map(|leg| Value::Object(leg.to_json_object())).collect();
+        object.insert(String::from("legs"), Value::Array(legs));
+        Value::Object(object)
+    }
+
+    /// Constructs a boarding pass from a JSON object in the schema produced by `to_json`.
+    ///
+    /// Fields not present are unset, and each value is validated as by its setter. Values are
+    /// padded to the width of their fields, so trailing spaces of airline individual use data
+    /// removed by `to_json` are not restored.
+    pub fn from_json(json: &str) -> Result<Bcbp> {
+        let json: Value = serde_json::from_str(json).map_err(|error| Error::Custom(error.to_string()))?;
+
+        let mut boarding_pass = Bcbp::default();
+        let mut type_of_security_data = None;
+        let mut security_data = None;
+        for (name, value) in json.as_object().ok_or(Error::UnsupportedFormat)? {
+            if name == "legs" {
+                let legs = value.as_array()
+                    .filter(|legs| !legs.is_empty())
+                    .ok_or(Error::InvalidFieldValue(Field::NumberOfLegsEncoded))?;
+                if legs.len() > MAX_NUMBER_OF_LEGS {
+                    return Err(Error::LengthOverflow(Field::NumberOfLegsEncoded));
+                }
+                boarding_pass.legs = legs.iter()
+                    .map(|leg| Leg::from_json_object(object(leg, Field::NumberOfLegsEncoded)?))
+                    .collect::<Result<_>>()?;
+                continue;
+            }
+
+            let field = field_named(name)?;
+            match field {
+                Field::TypeOfSecurityData => type_of_security_data = field_value(field, value)?,
+                Field::SecurityData => security_data = field_value(field, value)?,
+                _ => boarding_pass.set_field(field, field_value(field, value)?)?,
+            }
+        }
+
+        boarding_pass.set_security_data_fields(type_of_security_data, security_data)?;
+        Ok(boarding_pass)
+    }
+}
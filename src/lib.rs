@@ -35,13 +35,67 @@
 //! }
 //! ```
 
-extern crate arrayvec;
+#[cfg(feature = "crypto")]
+extern crate base64;
+#[cfg(feature = "digest")]
+extern crate digest;
 extern crate nom;
+#[cfg(feature = "crypto")]
+extern crate p256;
+#[cfg(feature = "barcode")]
+extern crate qrcode;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+#[cfg(feature = "time")]
+extern crate time;
 
+#[cfg(feature = "barcode")]
+mod barcode;
 mod bcbp;
+#[cfg(feature = "crypto")]
+mod crypto;
 mod de;
 mod error;
+mod fixed_string;
+#[cfg(feature = "generator")]
+pub mod generator;
+mod individual_use;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "model")]
+pub mod model;
+#[cfg(feature = "digest")]
+mod pseudonym;
+#[cfg(feature = "reference_data")]
+pub mod reference_data;
+mod security;
+mod ser;
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
+mod validation;
+#[cfg(feature = "serde_json")]
+mod wallet;
 
-pub use bcbp::{Bcbp, Leg, SecurityData};
-pub use de::from_str;
-pub use error::{Error, Result};
+pub use bcbp::{
+    AirportCode, BaggageTag, BaggageTagNumber, Bcbp, CarrierDesignator, ConditionalMetadata,
+    Connection, DocumentVerification, FlightDate, Itinerary, JulianDate, Leg, Normalization,
+    PnrCode, ScreeningSummary, SecurityData, SelecteeIndicator,
+};
+#[cfg(feature = "crypto")]
+pub use crypto::EcdsaP256Verifier;
+pub use de::{
+    explain, from_str, from_str_observed, from_str_recovering, from_str_with_quirks, spans,
+    split_passes, Annotation, BcbpFixed, Field, IncrementalParser, Language, ParseObserver,
+    ParseProgress, ParsedLeg, PartialBcbp, Quirk, Quirks, TokenKind,
+};
+#[cfg(feature = "serde")]
+pub use de::{from_str_as, Deserializer};
+pub use error::{BuildError, Error, LegError, ParseFailure, Result, TraceFrame};
+pub use individual_use::{IndividualUseDecoder, IndividualUseDecoders};
+pub use security::{SecuritySignatureVerifier, SecuritySigner};
+pub use ser::{to_string, to_string_checked, to_string_mandatory, to_string_signed};
+#[cfg(feature = "serde")]
+pub use ser::to_string_from;
+pub use validation::{Finding, FindingKind, Severity, ValidationProfile, ValidationReport};
@@ -0,0 +1,384 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! A typed domain model of a boarding pass.
+//!
+//! Unlike `Bcbp`, which retains the data exactly as encoded, values in the model are trimmed,
+//! parsed into integers and enumerations, and fields which are blank are `None`. Conversion
+//! from `Bcbp` is therefore lossy for data which does not conform to Resolution 792.
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::bcbp::{Bcbp, FlightDate, JulianDate, Leg};
+use crate::de::field::Field;
+use crate::error::{Error, Result};
+
+/// Passenger Description, Item 15.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum PassengerDescription {
+    Adult,
+    Male,
+    Female,
+    Child,
+    Infant,
+    NoPassenger,
+    AdultTravellingWithInfant,
+    UnaccompaniedMinor,
+    Other(char),
+}
+
+impl From<char> for PassengerDescription {
+    fn from(code: char) -> Self {
+        match code {
+            '0' => PassengerDescription::Adult,
+            '1' => PassengerDescription::Male,
+            '2' => PassengerDescription::Female,
+            '3' => PassengerDescription::Child,
+            '4' => PassengerDescription::Infant,
+            '5' => PassengerDescription::NoPassenger,
+            '6' => PassengerDescription::AdultTravellingWithInfant,
+            '7' => PassengerDescription::UnaccompaniedMinor,
+            other => PassengerDescription::Other(other),
+        }
+    }
+}
+
+impl From<PassengerDescription> for char {
+    fn from(description: PassengerDescription) -> Self {
+        match description {
+            PassengerDescription::Adult => '0',
+            PassengerDescription::Male => '1',
+            PassengerDescription::Female => '2',
+            PassengerDescription::Child => '3',
+            PassengerDescription::Infant => '4',
+            PassengerDescription::NoPassenger => '5',
+            PassengerDescription::AdultTravellingWithInfant => '6',
+            PassengerDescription::UnaccompaniedMinor => '7',
+            PassengerDescription::Other(other) => other,
+        }
+    }
+}
+
+/// Source of Check-In, Item 12, and Source of Boarding Pass Issuance, Item 14.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Source {
+    Web,
+    AirportKiosk,
+    TransferKiosk,
+    RemoteOrOffSiteKiosk,
+    MobileDevice,
+    AirportAgent,
+    TownAgent,
+    ThirdPartyVendor,
+    Other(char),
+}
+
+impl From<char> for Source {
+    fn from(code: char) -> Self {
+        match code {
+            'W' => Source::Web,
+            'K' => Source::AirportKiosk,
+            'X' => Source::TransferKiosk,
+            'R' => Source::RemoteOrOffSiteKiosk,
+            'M' => Source::MobileDevice,
+            'O' => Source::AirportAgent,
+            'T' => Source::TownAgent,
+            'V' => Source::ThirdPartyVendor,
+            other => Source::Other(other),
+        }
+    }
+}
+
+impl From<Source> for char {
+    fn from(source: Source) -> Self {
+        match source {
+            Source::Web => 'W',
+            Source::AirportKiosk => 'K',
+            Source::TransferKiosk => 'X',
+            Source::RemoteOrOffSiteKiosk => 'R',
+            Source::MobileDevice => 'M',
+            Source::AirportAgent => 'O',
+            Source::TownAgent => 'T',
+            Source::ThirdPartyVendor => 'V',
+            Source::Other(other) => other,
+        }
+    }
+}
+
+/// Document Type, Item 16.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum DocumentType {
+    BoardingPass,
+    ItineraryReceipt,
+    Other(char),
+}
+
+impl From<char> for DocumentType {
+    fn from(code: char) -> Self {
+        match code {
+            'B' => DocumentType::BoardingPass,
+            'I' => DocumentType::ItineraryReceipt,
+            other => DocumentType::Other(other),
+        }
+    }
+}
+
+impl From<DocumentType> for char {
+    fn from(document_type: DocumentType) -> Self {
+        match document_type {
+            DocumentType::BoardingPass => 'B',
+            DocumentType::ItineraryReceipt => 'I',
+            DocumentType::Other(other) => other,
+        }
+    }
+}
+
+/// A single flight of a boarding pass.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Flight {
+    pub pnr_code: Option<String>,
+    pub origin: Option<String>,
+    pub destination: Option<String>,
+    pub operating_carrier: Option<String>,
+    pub flight_number: Option<u16>,
+    pub flight_number_suffix: Option<char>,
+    pub date_of_flight: Option<JulianDate>,
+    pub compartment_code: Option<char>,
+    pub seat: Option<String>,
+    pub check_in_sequence_number: Option<u16>,
+    pub check_in_sequence_suffix: Option<char>,
+    pub passenger_status: Option<char>,
+    pub airline_numeric_code: Option<u16>,
+    pub document_form_serial_number: Option<String>,
+    pub selectee_indicator: Option<char>,
+    pub international_document_verification: Option<char>,
+    pub marketing_carrier: Option<String>,
+    pub frequent_flyer_airline: Option<String>,
+    pub frequent_flyer_number: Option<String>,
+    pub id_ad_indicator: Option<char>,
+    pub free_baggage_allowance: Option<String>,
+    pub fast_track: Option<bool>,
+    pub airline_individual_use: Option<String>,
+}
+
+/// A boarding pass with typed values.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct BoardingPass {
+    pub passenger_name: Option<String>,
+    pub electronic_ticket: bool,
+    pub version_number: Option<u8>,
+    pub passenger_description: Option<PassengerDescription>,
+    pub source_of_check_in: Option<Source>,
+    pub source_of_boarding_pass_issuance: Option<Source>,
+    pub date_of_issue: Option<FlightDate>,
+    pub document_type: Option<DocumentType>,
+    pub issuer: Option<String>,
+    pub baggage_tag_license_plate_numbers: Option<String>,
+    pub first_non_consecutive_baggage_tag_license_plate_numbers: Option<String>,
+    pub second_non_consecutive_baggage_tag_license_plate_numbers: Option<String>,
+    pub flights: Vec<Flight>,
+    pub type_of_security_data: Option<char>,
+    pub security_data: Option<String>,
+}
+
+/// Returns `value` trimmed of spaces, or `None` if blank.
+fn text(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(String::from(value))
+    }
+}
+
+/// Returns `value` as `None` if a space.
+fn code(value: char) -> Option<char> {
+    if value == ' ' {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Returns the leading digits of `value` as an integer and the character following them, if any.
+fn number_with_suffix(value: &str) -> (Option<u16>, Option<char>) {
+    let value = value.trim();
+    let digits_len = value.bytes().take_while(|b| b.is_ascii_digit()).count();
+    let number = value[.. digits_len].parse().ok();
+    let suffix = value[digits_len ..].chars().next();
+    (number, suffix)
+}
+
+/// Formats `number` with leading zeroes to `width` followed by `suffix`, or spaces if `None`.
+fn format_number(number: Option<u16>, width: usize, suffix: Option<char>) -> String {
+    match number {
+        Some(number) => format!("{:0width$}{}", number, suffix.unwrap_or(' '), width = width),
+        None => String::new(),
+    }
+}
+
+impl<'a> From<&'a Leg> for Flight {
+    fn from(leg: &'a Leg) -> Self {
+        let (flight_number, flight_number_suffix) = number_with_suffix(leg.flight_number());
+        let (check_in_sequence_number, check_in_sequence_suffix) = number_with_suffix(leg.check_in_sequence_number());
+
+        // Seat numbers are stored without leading zeroes, e.g. "1A" rather than "001A".
+        let seat = text(leg.seat_number()).map(|seat| {
+            let trimmed = seat.trim_start_matches('0');
+            if trimmed.is_empty() || !trimmed.starts_with(|c: char| c.is_ascii_digit()) {
+                seat
+            } else {
+                String::from(trimmed)
+            }
+        });
+
+        Flight {
+            pnr_code: text(leg.operating_carrier_pnr_code()),
+            origin: text(leg.from_city_airport_code()),
+            destination: text(leg.to_city_airport_code()),
+            operating_carrier: text(leg.operating_carrier_designator()),
+            flight_number,
+            flight_number_suffix,
+            date_of_flight: JulianDate::from_str(leg.date_of_flight()).ok(),
+            compartment_code: code(leg.compartment_code()),
+            seat,
+            check_in_sequence_number,
+            check_in_sequence_suffix,
+            passenger_status: code(leg.passenger_status()),
+            airline_numeric_code: leg.airline_numeric_code().and_then(|value| value.trim().parse().ok()),
+            document_form_serial_number: leg.document_form_serial_number().and_then(text),
+            selectee_indicator: leg.selectee_indicator().and_then(code),
+            international_document_verification: leg.international_document_verification().and_then(code),
+            marketing_carrier: leg.marketing_carrier_designator().and_then(text),
+            frequent_flyer_airline: leg.frequent_flyer_airline_designator().and_then(text),
+            frequent_flyer_number: leg.frequent_flyer_number().and_then(text),
+            id_ad_indicator: leg.id_ad_indicator().and_then(code),
+            free_baggage_allowance: leg.free_baggage_allowance().and_then(text),
+            fast_track: leg.fast_track().and_then(|value| match value {
+                'Y' => Some(true),
+                'N' => Some(false),
+                _ => None,
+            }),
+            airline_individual_use: leg.airline_individual_use().map(String::from),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a Flight> for Leg {
+    type Error = Error;
+
+    fn try_from(flight: &'a Flight) -> Result<Self> {
+        // Seat numbers consist of a three-digit row and a letter.
+        let seat = match flight.seat {
+            Some(ref seat) => match number_with_suffix(seat) {
+                (Some(row), Some(letter)) if seat.len() <= 4 => format_number(Some(row), 3, Some(letter)),
+                _ => seat.clone(),
+            },
+            None => String::new(),
+        };
+
+        let mut leg = Leg::default();
+        leg.set_operating_carrier_pnr_code(flight.pnr_code.as_deref().unwrap_or(""))?;
+        leg.set_from_city_airport_code(flight.origin.as_deref().unwrap_or(""))?;
+        leg.set_to_city_airport_code(flight.destination.as_deref().unwrap_or(""))?;
+        leg.set_operating_carrier_designator(flight.operating_carrier.as_deref().unwrap_or(""))?;
+        leg.set_flight_number(&format_number(flight.flight_number, 4, flight.flight_number_suffix))?;
+        leg.set_date_of_flight(flight.date_of_flight.as_ref().map_or("", JulianDate::as_str))?;
+        leg.set_compartment_code(flight.compartment_code.unwrap_or(' '))?;
+        leg.set_seat_number(&seat)?;
+        leg.set_check_in_sequence_number(&format_number(flight.check_in_sequence_number, 4, flight.check_in_sequence_suffix))?;
+        leg.set_passenger_status(flight.passenger_status.unwrap_or(' '))?;
+        leg.set_airline_numeric_code(flight.airline_numeric_code.map(|value| format!("{:03}", value)).as_deref())?;
+        leg.set_document_form_serial_number(flight.document_form_serial_number.as_deref())?;
+        leg.set_selectee_indicator(flight.selectee_indicator)?;
+        leg.set_international_document_verification(flight.international_document_verification)?;
+        leg.set_marketing_carrier_designator(flight.marketing_carrier.as_deref())?;
+        leg.set_frequent_flyer_airline_designator(flight.frequent_flyer_airline.as_deref())?;
+        leg.set_frequent_flyer_number(flight.frequent_flyer_number.as_deref())?;
+        leg.set_id_ad_indicator(flight.id_ad_indicator)?;
+        leg.set_free_baggage_allowance(flight.free_baggage_allowance.as_deref())?;
+        leg.set_fast_track(flight.fast_track.map(|fast_track| if fast_track { 'Y' } else { 'N' }))?;
+        leg.set_airline_individual_use(flight.airline_individual_use.as_deref())?;
+        Ok(leg)
+    }
+}
+
+impl Flight {
+    /// Resolves the year of the date of flight from the date of issue of the boarding pass,
+    /// as in `Leg::resolved_flight_date`. Returns `None` if the date of flight is not set.
+    pub fn resolved_date(&self, date_of_issue: FlightDate) -> Option<FlightDate> {
+        self.date_of_flight.map(|date| date_of_issue.next_on_day(date.day_of_year()))
+    }
+}
+
+impl<'a> From<&'a Bcbp> for BoardingPass {
+    fn from(boarding_pass: &'a Bcbp) -> Self {
+        BoardingPass {
+            passenger_name: text(boarding_pass.passenger_name()),
+            electronic_ticket: boarding_pass.electronic_ticket_indicator() == 'E',
+            version_number: boarding_pass.version_number()
+                .and_then(|version| version.to_digit(10))
+                .map(|version| version as u8),
+            passenger_description: boarding_pass.passenger_description().and_then(code).map(From::from),
+            source_of_check_in: boarding_pass.source_of_check_in().and_then(code).map(From::from),
+            source_of_boarding_pass_issuance: boarding_pass.source_of_boarding_pass_issuance().and_then(code).map(From::from),
+            date_of_issue: boarding_pass.date_of_issue_of_boarding_pass().and_then(FlightDate::parse),
+            document_type: boarding_pass.document_type().and_then(code).map(From::from),
+            issuer: boarding_pass.airline_designator_of_boarding_pass_issuer().and_then(text),
+            baggage_tag_license_plate_numbers: boarding_pass.baggage_tag_license_plate_numbers().and_then(text),
+            first_non_consecutive_baggage_tag_license_plate_numbers: boarding_pass
+                .first_non_consecutive_baggage_tag_license_plate_numbers()
+                .and_then(text),
+            second_non_consecutive_baggage_tag_license_plate_numbers: boarding_pass
+                .second_non_consecutive_baggage_tag_license_plate_numbers()
+                .and_then(text),
+            flights: boarding_pass.legs().iter().map(Flight::from).collect(),
+            type_of_security_data: boarding_pass.security_data().type_of_security_data(),
+            security_data: boarding_pass.security_data().security_data().map(String::from),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a BoardingPass> for Bcbp {
+    type Error = Error;
+
+    /// Fails if a value does not fit within or conform to the data format of its field,
+    /// or if there are no flights or more than the number of legs which may be encoded.
+    fn try_from(boarding_pass: &'a BoardingPass) -> Result<Self> {
+        let mut flights = boarding_pass.flights.iter();
+        let first_flight = flights.next().ok_or(Error::InvalidFieldValue(Field::NumberOfLegsEncoded))?;
+
+        let mut bcbp = Bcbp::default();
+        bcbp.legs_mut()[0] = Leg::try_from(first_flight)?;
+        for flight in flights {
+            bcbp.push_leg(Leg::try_from(flight)?)?;
+        }
+
+        bcbp.set_passenger_name(boarding_pass.passenger_name.as_deref().unwrap_or(""))?;
+        bcbp.set_electronic_ticket_indicator(if boarding_pass.electronic_ticket { 'E' } else { ' ' })?;
+        bcbp.set_version_number(boarding_pass.version_number.and_then(|version| std::char::from_digit(u32::from(version), 10)))?;
+        bcbp.set_passenger_description(boarding_pass.passenger_description.map(From::from))?;
+        bcbp.set_source_of_check_in(boarding_pass.source_of_check_in.map(From::from))?;
+        bcbp.set_source_of_boarding_pass_issuance(boarding_pass.source_of_boarding_pass_issuance.map(From::from))?;
+        bcbp.set_date_of_issue_of_boarding_pass(boarding_pass.date_of_issue
+            .map(|date| format!("{}{:03}", date.year_digit(), date.day_of_year()))
+            .as_deref())?;
+        bcbp.set_document_type(boarding_pass.document_type.map(From::from))?;
+        bcbp.set_airline_designator_of_boarding_pass_issuer(boarding_pass.issuer.as_deref())?;
+        bcbp.set_baggage_tag_license_plate_numbers(boarding_pass.baggage_tag_license_plate_numbers.as_deref())?;
+        bcbp.set_first_non_consecutive_baggage_tag_license_plate_numbers(
+            boarding_pass.first_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
+        )?;
+        bcbp.set_second_non_consecutive_baggage_tag_license_plate_numbers(
+            boarding_pass.second_non_consecutive_baggage_tag_license_plate_numbers.as_deref()
+        )?;
+        if let Some(type_of_security_data) = boarding_pass.type_of_security_data {
+            bcbp.set_security_data(type_of_security_data, boarding_pass.security_data.as_deref().unwrap_or(""))?;
+        }
+
+        Ok(bcbp)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt::Write;
+
+use digest::{KeyInit, Mac};
+
+use crate::bcbp::Bcbp;
+
+impl Bcbp {
+    /// Returns a pseudonymous identifier for the passenger and booking, as a lowercase hexadecimal
+    /// message authentication code computed by `M` under `key` over the passenger name, PNR code
+    /// and electronic ticket number of the first leg, e.g. with `Hmac<Sha256>`.
+    ///
+    /// The identifier is stable across boarding passes issued for the same booking, allowing
+    /// analytics to join records without storing personally identifiable information. The key
+    /// must be kept secret to prevent identifiers from being reversed by enumeration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` does not accept a key of the length of `key`. HMAC accepts keys of any length.
+    pub fn pseudonymous_id<M>(&self, key: &[u8]) -> String
+    where
+        M: Mac + KeyInit,
+    {
+        let leg = self.first_leg();
+        let passenger_name = self.passenger_name().trim().to_ascii_uppercase();
+
+        // Each component is terminated so adjacent values cannot be confused.
+        let mut mac = <M as KeyInit>::new_from_slice(key).expect("key length accepted by the MAC");
+        for component in &[
+            passenger_name.as_str(),
+            leg.operating_carrier_pnr_code().trim(),
+            leg.airline_numeric_code().unwrap_or("").trim(),
+            leg.document_form_serial_number().unwrap_or("").trim(),
+        ] {
+            mac.update(component.as_bytes());
+            mac.update(&[0]);
+        }
+
+        mac.finalize().into_bytes().iter().fold(String::new(), |mut id, byte| {
+            let _ = write!(id, "{:02x}", byte);
+            id
+        })
+    }
+}
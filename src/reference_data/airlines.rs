@@ -0,0 +1,59 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use super::AirlineInfo;
+
+const fn airline(
+    iata_designator: &'static str,
+    icao_designator: &'static str,
+    numeric_code: &'static str,
+    name: &'static str
+) -> AirlineInfo {
+    AirlineInfo { iata_designator, icao_designator, numeric_code, name }
+}
+
+/// Major airlines, sorted by IATA designator.
+pub(crate) const AIRLINES: &[AirlineInfo] = &[
+    airline("AA", "AAL", "001", "American Airlines"),
+    airline("AC", "ACA", "014", "Air Canada"),
+    airline("AF", "AFR", "057", "Air France"),
+    airline("AI", "AIC", "098", "Air India"),
+    airline("AM", "AMX", "139", "Aeroméxico"),
+    airline("AS", "ASA", "027", "Alaska Airlines"),
+    airline("AY", "FIN", "105", "Finnair"),
+    airline("AZ", "ITY", "055", "ITA Airways"),
+    airline("B6", "JBU", "279", "JetBlue"),
+    airline("BA", "BAW", "125", "British Airways"),
+    airline("CA", "CCA", "999", "Air China"),
+    airline("CX", "CPA", "160", "Cathay Pacific"),
+    airline("DL", "DAL", "006", "Delta Air Lines"),
+    airline("EI", "EIN", "053", "Aer Lingus"),
+    airline("EK", "UAE", "176", "Emirates"),
+    airline("ET", "ETH", "071", "Ethiopian Airlines"),
+    airline("EY", "ETD", "607", "Etihad Airways"),
+    airline("IB", "IBE", "075", "Iberia"),
+    airline("JL", "JAL", "131", "Japan Airlines"),
+    airline("KE", "KAL", "180", "Korean Air"),
+    airline("KL", "KLM", "074", "KLM Royal Dutch Airlines"),
+    airline("LA", "LAN", "045", "LATAM Airlines"),
+    airline("LH", "DLH", "220", "Lufthansa"),
+    airline("LO", "LOT", "080", "LOT Polish Airlines"),
+    airline("LX", "SWR", "724", "Swiss International Air Lines"),
+    airline("MU", "CES", "781", "China Eastern Airlines"),
+    airline("NH", "ANA", "205", "All Nippon Airways"),
+    airline("NZ", "ANZ", "086", "Air New Zealand"),
+    airline("OS", "AUA", "257", "Austrian Airlines"),
+    airline("QF", "QFA", "081", "Qantas"),
+    airline("QR", "QTR", "157", "Qatar Airways"),
+    airline("SK", "SAS", "117", "Scandinavian Airlines"),
+    airline("SN", "BEL", "082", "Brussels Airlines"),
+    airline("SQ", "SIA", "618", "Singapore Airlines"),
+    airline("TK", "THY", "235", "Turkish Airlines"),
+    airline("TP", "TAP", "047", "TAP Air Portugal"),
+    airline("UA", "UAL", "016", "United Airlines"),
+    airline("VS", "VIR", "932", "Virgin Atlantic"),
+    airline("WN", "SWA", "526", "Southwest Airlines"),
+    airline("WS", "WJA", "838", "WestJet"),
+];
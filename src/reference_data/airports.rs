@@ -0,0 +1,75 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use super::AirportInfo;
+
+const fn airport(code: &'static str, city: &'static str, country: &'static str, time_zone: &'static str) -> AirportInfo {
+    AirportInfo { code, city, country, time_zone }
+}
+
+/// Major airports, sorted by code.
+pub(crate) const AIRPORTS: &[AirportInfo] = &[
+    airport("AKL", "Auckland", "New Zealand", "Pacific/Auckland"),
+    airport("AMS", "Amsterdam", "Netherlands", "Europe/Amsterdam"),
+    airport("ATH", "Athens", "Greece", "Europe/Athens"),
+    airport("ATL", "Atlanta", "United States", "America/New_York"),
+    airport("BCN", "Barcelona", "Spain", "Europe/Madrid"),
+    airport("BKK", "Bangkok", "Thailand", "Asia/Bangkok"),
+    airport("BOG", "Bogotá", "Colombia", "America/Bogota"),
+    airport("BOS", "Boston", "United States", "America/New_York"),
+    airport("BRU", "Brussels", "Belgium", "Europe/Brussels"),
+    airport("CDG", "Paris", "France", "Europe/Paris"),
+    airport("CPH", "Copenhagen", "Denmark", "Europe/Copenhagen"),
+    airport("DEL", "Delhi", "India", "Asia/Kolkata"),
+    airport("DEN", "Denver", "United States", "America/Denver"),
+    airport("DFW", "Dallas", "United States", "America/Chicago"),
+    airport("DOH", "Doha", "Qatar", "Asia/Qatar"),
+    airport("DUB", "Dublin", "Ireland", "Europe/Dublin"),
+    airport("DXB", "Dubai", "United Arab Emirates", "Asia/Dubai"),
+    airport("EWR", "Newark", "United States", "America/New_York"),
+    airport("EZE", "Buenos Aires", "Argentina", "America/Argentina/Buenos_Aires"),
+    airport("FCO", "Rome", "Italy", "Europe/Rome"),
+    airport("FRA", "Frankfurt", "Germany", "Europe/Berlin"),
+    airport("GRU", "São Paulo", "Brazil", "America/Sao_Paulo"),
+    airport("GVA", "Geneva", "Switzerland", "Europe/Zurich"),
+    airport("HEL", "Helsinki", "Finland", "Europe/Helsinki"),
+    airport("HKG", "Hong Kong", "Hong Kong", "Asia/Hong_Kong"),
+    airport("HND", "Tokyo", "Japan", "Asia/Tokyo"),
+    airport("IAD", "Washington", "United States", "America/New_York"),
+    airport("ICN", "Seoul", "South Korea", "Asia/Seoul"),
+    airport("IST", "Istanbul", "Türkiye", "Europe/Istanbul"),
+    airport("JFK", "New York", "United States", "America/New_York"),
+    airport("JNB", "Johannesburg", "South Africa", "Africa/Johannesburg"),
+    airport("LAS", "Las Vegas", "United States", "America/Los_Angeles"),
+    airport("LAX", "Los Angeles", "United States", "America/Los_Angeles"),
+    airport("LGA", "New York", "United States", "America/New_York"),
+    airport("LGW", "London", "United Kingdom", "Europe/London"),
+    airport("LHR", "London", "United Kingdom", "Europe/London"),
+    airport("LIS", "Lisbon", "Portugal", "Europe/Lisbon"),
+    airport("MAD", "Madrid", "Spain", "Europe/Madrid"),
+    airport("MEX", "Mexico City", "Mexico", "America/Mexico_City"),
+    airport("MIA", "Miami", "United States", "America/New_York"),
+    airport("MUC", "Munich", "Germany", "Europe/Berlin"),
+    airport("NRT", "Tokyo", "Japan", "Asia/Tokyo"),
+    airport("ORD", "Chicago", "United States", "America/Chicago"),
+    airport("OSL", "Oslo", "Norway", "Europe/Oslo"),
+    airport("PEK", "Beijing", "China", "Asia/Shanghai"),
+    airport("PHX", "Phoenix", "United States", "America/Phoenix"),
+    airport("PVG", "Shanghai", "China", "Asia/Shanghai"),
+    airport("RLG", "Rostock", "Germany", "Europe/Berlin"),
+    airport("SEA", "Seattle", "United States", "America/Los_Angeles"),
+    airport("SFO", "San Francisco", "United States", "America/Los_Angeles"),
+    airport("SIN", "Singapore", "Singapore", "Asia/Singapore"),
+    airport("SJC", "San Jose", "United States", "America/Los_Angeles"),
+    airport("SYD", "Sydney", "Australia", "Australia/Sydney"),
+    airport("VIE", "Vienna", "Austria", "Europe/Vienna"),
+    airport("WAW", "Warsaw", "Poland", "Europe/Warsaw"),
+    airport("YOW", "Ottawa", "Canada", "America/Toronto"),
+    airport("YUL", "Montréal", "Canada", "America/Toronto"),
+    airport("YVR", "Vancouver", "Canada", "America/Vancouver"),
+    airport("YYC", "Calgary", "Canada", "America/Edmonton"),
+    airport("YYZ", "Toronto", "Canada", "America/Toronto"),
+    airport("ZRH", "Zurich", "Switzerland", "Europe/Zurich"),
+];
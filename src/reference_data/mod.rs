@@ -0,0 +1,208 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Bundled reference data for displaying the codes encoded in boarding passes.
+//!
+//! The data covers major airports and airlines only. Lookups of codes which are not listed return
+//! None, in which case the code itself is the best available description. Designators and numeric
+//! codes are reassigned over time, so names reflect the assignments current as of `DATA_VINTAGE`.
+
+mod airlines;
+mod airports;
+
+use std::fmt;
+#[cfg(feature = "time")]
+use std::str::FromStr;
+
+#[cfg(feature = "time")]
+use time::{Date, OffsetDateTime, UtcOffset};
+#[cfg(feature = "time")]
+use time_tz::{timezones, Offset, TimeZone};
+
+use crate::bcbp::Leg;
+#[cfg(feature = "time")]
+use crate::bcbp::JulianDate;
+
+/// The year in which the airport locations and time zone names, and the airline designator and numeric
+/// code assignments, were compiled.
+pub const DATA_VINTAGE: &str = "2024";
+
+/// The location of an airport.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AirportInfo {
+    code: &'static str,
+    city: &'static str,
+    country: &'static str,
+    time_zone: &'static str,
+}
+
+impl AirportInfo {
+    /// All airports listed, sorted by code.
+    pub fn all() -> &'static [AirportInfo] {
+        airports::AIRPORTS
+    }
+
+    /// Returns the airport identified by the three-letter `code`, ignoring case and padding.
+    pub fn lookup(code: &str) -> Option<&'static AirportInfo> {
+        let code = code.trim().to_ascii_uppercase();
+        airports::AIRPORTS
+            .binary_search_by(|airport| airport.code.cmp(code.as_str()))
+            .ok()
+            .map(|index| &airports::AIRPORTS[index])
+    }
+
+    /// The three-letter airport code.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The name of the city served by the airport, in its common English form.
+    pub fn city(&self) -> &'static str {
+        self.city
+    }
+
+    /// The name of the country or territory in which the airport is located.
+    pub fn country(&self) -> &'static str {
+        self.country
+    }
+
+    /// The name of the time zone of the airport in the IANA time zone database, e.g. `America/Toronto`.
+    pub fn time_zone(&self) -> &'static str {
+        self.time_zone
+    }
+
+    /// The offset from UTC of local time at the airport at `instant`, including daylight saving time,
+    /// as recorded in the IANA time zone database bundled by the `time-tz` crate.
+    #[cfg(feature = "time")]
+    pub fn utc_offset_at(&self, instant: OffsetDateTime) -> UtcOffset {
+        timezones::get_by_name(self.time_zone)
+            .expect("every airport lists a time zone in the database")
+            .get_offset_utc(&instant)
+            .to_utc()
+    }
+}
+
+impl fmt::Display for AirportInfo {
+    /// Formats the airport as its city followed by its code, e.g. `Montréal (YUL)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.city, self.code)
+    }
+}
+
+/// The identity of an airline.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct AirlineInfo {
+    iata_designator: &'static str,
+    icao_designator: &'static str,
+    numeric_code: &'static str,
+    name: &'static str,
+}
+
+impl AirlineInfo {
+    /// All airlines listed, sorted by IATA designator.
+    pub fn all() -> &'static [AirlineInfo] {
+        airlines::AIRLINES
+    }
+
+    /// Returns the airline identified by `designator`, either its two-character IATA designator
+    /// or its three-letter ICAO designator, ignoring case and padding.
+    pub fn lookup(designator: &str) -> Option<&'static AirlineInfo> {
+        let designator = designator.trim().to_ascii_uppercase();
+        match designator.len() {
+            2 => airlines::AIRLINES
+                .binary_search_by(|airline| airline.iata_designator.cmp(designator.as_str()))
+                .ok()
+                .map(|index| &airlines::AIRLINES[index]),
+            3 => airlines::AIRLINES.iter().find(|airline| airline.icao_designator == designator),
+            _ => None,
+        }
+    }
+
+    /// Returns the airline identified by the three-digit `numeric_code` prefixing its ticket numbers,
+    /// ignoring padding.
+    pub fn lookup_numeric_code(numeric_code: &str) -> Option<&'static AirlineInfo> {
+        let numeric_code = numeric_code.trim();
+        airlines::AIRLINES.iter().find(|airline| airline.numeric_code == numeric_code)
+    }
+
+    /// The two-character IATA airline designator.
+    pub fn iata_designator(&self) -> &'static str {
+        self.iata_designator
+    }
+
+    /// The three-letter ICAO airline designator.
+    pub fn icao_designator(&self) -> &'static str {
+        self.icao_designator
+    }
+
+    /// The three-digit IATA airline accounting code prefixing the ticket numbers of the airline,
+    /// e.g. `014` for Air Canada.
+    pub fn numeric_code(&self) -> &'static str {
+        self.numeric_code
+    }
+
+    /// The name of the airline for display.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for AirlineInfo {
+    /// Formats the airline as its name.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Leg {
+    /// The location of the airport of departure, if listed.
+    pub fn from_airport_info(&self) -> Option<&'static AirportInfo> {
+        AirportInfo::lookup(self.from_city_airport_code())
+    }
+
+    /// The location of the airport of arrival, if listed.
+    pub fn to_airport_info(&self) -> Option<&'static AirportInfo> {
+        AirportInfo::lookup(self.to_city_airport_code())
+    }
+
+    /// The current date at the airport of departure at the instant `now`, if the airport is listed.
+    #[cfg(feature = "time")]
+    pub fn departure_local_date(&self, now: OffsetDateTime) -> Option<Date> {
+        let airport = self.from_airport_info()?;
+        Some(now.to_offset(airport.utc_offset_at(now)).date())
+    }
+
+    /// Resolves the Date of Flight to the date nearest the current date at the airport of departure,
+    /// as the day of the year is in the local time of departure. Returns `None` if the airport is not
+    /// listed or the Date of Flight is malformed.
+    #[cfg(feature = "time")]
+    pub fn flight_date_local(&self, now: OffsetDateTime) -> Option<Date> {
+        let today = self.departure_local_date(now)?;
+        let day_of_year = JulianDate::from_str(self.date_of_flight()).ok()?.day_of_year();
+        (today.year() - 1 ..= today.year() + 1)
+            .filter_map(|year| Date::from_ordinal_date(year, day_of_year).ok())
+            .min_by_key(|date| (*date - today).whole_days().abs())
+    }
+
+    /// Returns `true` if the flight departs on the current date at the airport of departure
+    /// at the instant `now`, rather than the current date in UTC.
+    #[cfg(feature = "time")]
+    pub fn is_departure_today(&self, now: OffsetDateTime) -> bool {
+        let today = self.departure_local_date(now);
+        today.is_some() && self.flight_date_local(now) == today
+    }
+
+    /// The display name of the operating carrier, if listed.
+    pub fn operating_carrier_name(&self) -> Option<&'static str> {
+        AirlineInfo::lookup(self.operating_carrier_designator()).map(AirlineInfo::name)
+    }
+
+    /// The display name of the marketing carrier, if present and listed.
+    pub fn marketing_carrier_name(&self) -> Option<&'static str> {
+        self.marketing_carrier_designator()
+            .and_then(AirlineInfo::lookup)
+            .map(AirlineInfo::name)
+    }
+}
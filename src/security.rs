@@ -0,0 +1,126 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::borrow::Cow;
+
+use crate::bcbp::{Bcbp, SecurityData};
+use crate::de::field;
+use crate::error::{Error, Result};
+use crate::ser;
+
+/// Verifies the security data embedded in a boarding pass.
+///
+/// The IATA security scheme signs all pass data preceding the Beginning of Security Data
+/// indicator. Implementations typically check the signature against the public key of the
+/// issuing airline, selected by the vendor-specific type of security data.
+pub trait SecuritySignatureVerifier {
+    /// Returns `true` if `signature` is a valid signature over `signed_payload`.
+    fn verify(&self, type_of_security_data: char, signed_payload: &[u8], signature: &str) -> bool;
+}
+
+/// Computes the security data embedded in a boarding pass when encoding.
+///
+/// This is the counterpart of `SecuritySignatureVerifier` used to issue signed boarding passes.
+pub trait SecuritySigner {
+    /// The vendor-specific type of the security data produced by the signer.
+    fn type_of_security_data(&self) -> char;
+
+    /// Returns the signature over `signed_payload`, encoded as the security data.
+    fn sign(&self, signed_payload: &[u8]) -> String;
+}
+
+impl Bcbp {
+    /// Consumes the boarding pass, returning it without a security data section.
+    ///
+    /// Any modification to a boarding pass invalidates its security data,
+    /// which can be removed with this method before the pass is re-encoded.
+    pub fn without_security_data(self) -> Bcbp {
+        Bcbp {
+            security_data: SecurityData::default(),
+            ..self
+        }
+    }
+
+    /// Replaces the security data section of the boarding pass.
+    ///
+    /// `security_data` must contain only ASCII characters and be at most 255 bytes long.
+    /// Signatures over the current boarding pass data can also be computed on encode
+    /// using `to_string_signed`.
+    pub fn set_security_data(&mut self, type_of_security_data: char, security_data: &str) -> Result<()> {
+        if !type_of_security_data.is_ascii() || !security_data.is_ascii() {
+            return Err(Error::InvalidCharacters);
+        }
+        if security_data.len() > 0xFF {
+            return Err(Error::LengthOverflow(field::Field::LengthOfSecurityData));
+        }
+
+        self.security_data = SecurityData {
+            type_of_security_data: Some(type_of_security_data),
+            length_of_security_data: Some(security_data.len()),
+            security_data: if security_data.is_empty() {
+                None
+            } else {
+                Some(String::from(security_data))
+            },
+            signed_range: None,
+            signed_payload: None,
+        };
+
+        Ok(())
+    }
+
+    /// Replaces the security data section from the values of the Type of Security Data and
+    /// Security Data fields, as identified dynamically. The section is left unchanged if the
+    /// type is not present.
+    #[cfg(any(feature = "serde", feature = "serde_json"))]
+    pub(crate) fn set_security_data_fields(
+        &mut self,
+        type_of_security_data: Option<&str>,
+        security_data: Option<&str>,
+    ) -> Result<()> {
+        match type_of_security_data {
+            Some(type_of_security_data) => {
+                let type_of_security_data = crate::bcbp::setters::single_chr(field::Field::TypeOfSecurityData, type_of_security_data)?;
+                self.set_security_data(type_of_security_data, security_data.unwrap_or(""))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// The data of the parsed boarding pass covered by its digital signature, exactly as it appeared
+    /// in the input: everything preceding the Beginning of Security Data indicator.
+    ///
+    /// Returns None if the boarding pass has no security data section, was not parsed from input,
+    /// or was modified after parsing, in which case the signature covers data no longer represented.
+    pub fn security_signed_payload(&self) -> Option<&str> {
+        self.security_data.signed_payload.as_deref()
+    }
+
+    /// Verifies the security data of the boarding pass using the provided `verifier`.
+    ///
+    /// Fails with `Error::MissingSecurityData` if the pass does not contain a security data
+    /// section, or `Error::InvalidSecurityData` if the verifier rejects the signature.
+    pub fn verify_security_data<V>(&self, verifier: &V) -> Result<()>
+    where
+        V: SecuritySignatureVerifier + ?Sized,
+    {
+        let security_data = self.security_data();
+        let type_of_security_data = security_data.type_of_security_data
+            .ok_or(Error::MissingSecurityData)?;
+
+        // Passes which were not parsed from input verify against their encoded form.
+        let signed_payload = match security_data.signed_payload {
+            Some(ref signed_payload) => Cow::Borrowed(signed_payload),
+            None => Cow::Owned(ser::signed_payload(self)?),
+        };
+
+        let signature = security_data.security_data().unwrap_or("");
+        if verifier.verify(type_of_security_data, signed_payload.as_bytes(), signature) {
+            Ok(())
+        } else {
+            Err(Error::InvalidSecurityData)
+        }
+    }
+}
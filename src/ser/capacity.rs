@@ -0,0 +1,53 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Bcbp;
+use crate::error::Result;
+use crate::ser::encoder;
+
+/// The maximum number of data columns in a PDF417 symbol.
+const PDF417_MAX_COLUMNS: usize = 30;
+
+/// The maximum number of rows in a PDF417 symbol.
+const PDF417_MAX_ROWS: usize = 90;
+
+/// The maximum number of codewords in a PDF417 symbol, including error correction.
+const PDF417_MAX_CODEWORDS: usize = 928;
+
+/// The maximum PDF417 error correction level.
+const PDF417_MAX_ECC_LEVEL: u8 = 8;
+
+/// Returns the number of PDF417 data codewords required to hold `len` bytes in byte compaction mode,
+/// including the symbol length descriptor and the mode latch.
+fn pdf417_data_codewords(len: usize) -> usize {
+    2 + (len / 6) * 5 + len % 6
+}
+
+impl Bcbp {
+    /// Returns the length of the boarding pass when encoded, including any security data.
+    ///
+    /// The length is computed from the data without encoding it, and fails in the same
+    /// circumstances as `to_string`.
+    pub fn encoded_len(&self) -> Result<usize> {
+        encoder::encoded_len(self)
+    }
+
+    /// Returns `true` if the encoded boarding pass fits within a PDF417 symbol with `columns` data columns
+    /// at error correction level `ecc_level`.
+    ///
+    /// The estimate assumes byte compaction, which encoders can always fall back to. Configurations
+    /// which cannot describe a valid symbol, with zero or more than 30 columns or an error correction
+    /// level greater than 8, never fit.
+    pub fn fits_in_pdf417(&self, columns: usize, ecc_level: u8) -> Result<bool> {
+        if columns == 0 || columns > PDF417_MAX_COLUMNS || ecc_level > PDF417_MAX_ECC_LEVEL {
+            return Ok(false);
+        }
+
+        let ecc_codewords = 1 << (ecc_level + 1);
+        let capacity = (columns * PDF417_MAX_ROWS).min(PDF417_MAX_CODEWORDS);
+        let data_codewords = pdf417_data_codewords(self.encoded_len()?);
+        Ok(data_codewords + ecc_codewords <= capacity)
+    }
+}
@@ -0,0 +1,517 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt::{self, Write};
+use std::result;
+
+use crate::bcbp::dynamic::non_blank;
+use crate::bcbp::setters::validate;
+use crate::bcbp::{Bcbp, ConditionalMetadata, Leg, SecurityData, MAX_NUMBER_OF_LEGS};
+use crate::de::field;
+use crate::error::{BuildError, Error, Result};
+use crate::security::SecuritySigner;
+
+/// The largest value representable by a two-digit hexadecimal length field.
+const MAX_VARIABLE_SIZE_FIELD_LEN: usize = 0xFF;
+
+/// Converts a failure of the destination of the encoded data.
+fn write_failed(_: fmt::Error) -> Error {
+    Error::WriteFailed
+}
+
+/// Writes `value` into `output`, left-aligned and space-padded to the length of the field.
+/// Fails if `value` is longer than the field, such as a four-character airport code.
+fn write_str_field<W: Write + ?Sized>(output: &mut W, field_id: field::Field, value: &str) -> Result<()> {
+    str_field_len(field_id, value)?;
+    write!(output, "{:<1$}", value, field_id.len()).map_err(write_failed)
+}
+
+/// Writes a single-character field into `output`.
+fn write_chr_field<W: Write + ?Sized>(output: &mut W, value: char) -> Result<()> {
+    output.write_char(value).map_err(write_failed)
+}
+
+/// Writes the data of a variable-size field preceded by its length as two hexadecimal digits.
+fn write_variable_size_field<W: Write + ?Sized>(output: &mut W, field_id: field::Field, data: &str) -> Result<()> {
+    if data.len() > MAX_VARIABLE_SIZE_FIELD_LEN {
+        return Err(Error::LengthOverflow(field_id));
+    }
+
+    write!(output, "{:02X}{}", data.len(), data).map_err(write_failed)
+}
+
+/// Accumulates a sequence of optional fixed-length fields within a variable-size section.
+///
+/// Fields may only be omitted from the end of a section. A field which is not set but
+/// followed by a field which is set is encoded as spaces instead.
+#[derive(Default)]
+struct OptionalFields {
+    data: String,
+    pending_len: usize,
+}
+
+impl OptionalFields {
+    fn str_field(&mut self, field_id: field::Field, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                for _ in 0 .. self.pending_len {
+                    self.data.push(' ');
+                }
+                self.pending_len = 0;
+                self.data.push_str(value);
+                for _ in value.len() .. field_id.len() {
+                    self.data.push(' ');
+                }
+            }
+            None => {
+                self.pending_len += field_id.len();
+            }
+        }
+    }
+
+    fn chr_field(&mut self, field_id: field::Field, value: Option<char>) {
+        let mut buffer = [0; 4];
+        self.str_field(field_id, value.map(|c| &*c.encode_utf8(&mut buffer)));
+    }
+
+    fn into_string(self) -> String {
+        self.data
+    }
+}
+
+/// Encodes the conditional items unique to the boarding pass.
+fn conditional_metadata(metadata: &ConditionalMetadata) -> String {
+    let mut fields = OptionalFields::default();
+    fields.chr_field(field::Field::PassengerDescription, metadata.passenger_description);
+    fields.chr_field(field::Field::SourceOfCheckIn, metadata.source_of_check_in);
+    fields.chr_field(field::Field::SourceOfBoardingPassIssuance, metadata.source_of_boarding_pass_issuance);
+    fields.str_field(field::Field::DateOfIssueOfBoardingPass, metadata.date_of_issue_of_boarding_pass.as_deref());
+    fields.chr_field(field::Field::DocumentType, metadata.document_type);
+    fields.str_field(field::Field::AirlineDesignatorOfBoardingPassIssuer, metadata.airline_designator_of_boarding_pass_issuer.as_deref());
+    fields.str_field(field::Field::BaggageTagLicensePlateNumbers, metadata.baggage_tag_license_plate_numbers.as_deref());
+    fields.str_field(field::Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers, metadata.first_non_consecutive_baggage_tag_license_plate_numbers.as_deref());
+    fields.str_field(field::Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers, metadata.second_non_consecutive_baggage_tag_license_plate_numbers.as_deref());
+    fields.into_string()
+}
+
+/// Encodes the conditional items repeated for each leg.
+fn conditional_leg_data(leg: &Leg) -> String {
+    let mut fields = OptionalFields::default();
+    fields.str_field(field::Field::AirlineNumericCode, leg.airline_numeric_code.as_deref());
+    fields.str_field(field::Field::DocumentFormSerialNumber, leg.document_form_serial_number.as_deref());
+    fields.chr_field(field::Field::SelecteeIndicator, leg.selectee_indicator);
+    fields.chr_field(field::Field::InternationalDocumentVerification, leg.international_document_verification);
+    fields.str_field(field::Field::MarketingCarrierDesignator, leg.marketing_carrier_designator.as_deref());
+    fields.str_field(field::Field::FrequentFlyerAirlineDesignator, leg.frequent_flyer_airline_designator.as_deref());
+    fields.str_field(field::Field::FrequentFlyerNumber, leg.frequent_flyer_number.as_deref());
+    fields.chr_field(field::Field::IdAdIndicator, leg.id_ad_indicator);
+    fields.str_field(field::Field::FreeBaggageAllowance, leg.free_baggage_allowance.as_deref());
+    fields.chr_field(field::Field::FastTrack, leg.fast_track);
+    fields.into_string()
+}
+
+/// Encodes a leg.
+///
+/// When encoding the first leg, the conditional items unique to the boarding pass are
+/// embedded at the beginning of the conditional section of the leg.
+fn leg<W: Write + ?Sized>(output: &mut W, leg: &Leg, metadata: Option<&ConditionalMetadata>) -> Result<()> {
+    write_str_field(output, field::Field::OperatingCarrierPnrCode, &leg.operating_carrier_pnr_code)?;
+    write_str_field(output, field::Field::FromCityAirportCode, &leg.from_city_airport_code)?;
+    write_str_field(output, field::Field::ToCityAirportCode, &leg.to_city_airport_code)?;
+    write_str_field(output, field::Field::OperatingCarrierDesignator, &leg.operating_carrier_designator)?;
+    write_str_field(output, field::Field::FlightNumber, &leg.flight_number)?;
+    write_str_field(output, field::Field::DateOfFlight, &leg.date_of_flight)?;
+    write_chr_field(output, leg.compartment_code)?;
+    write_str_field(output, field::Field::SeatNumber, &leg.seat_number)?;
+    write_str_field(output, field::Field::CheckInSequenceNumber, &leg.check_in_sequence_number)?;
+    write_chr_field(output, leg.passenger_status)?;
+
+    // The repeated section must be present, if empty, when followed by airline individual use data.
+    let repeated_data = conditional_leg_data(leg);
+    let has_repeated_section = !repeated_data.is_empty() || leg.airline_individual_use.is_some();
+
+    let mut conditional_item_data = String::new();
+    if let Some(metadata) = metadata {
+        let unique_data = conditional_metadata(metadata);
+        let has_unique_section = !unique_data.is_empty() || has_repeated_section;
+
+        // The version number is required to introduce any conditional data in the first leg.
+        if metadata.version_number.is_some() || has_unique_section {
+            let version_number = metadata.version_number.ok_or(Error::MissingField(field::Field::VersionNumber))?;
+            conditional_item_data.push('>');
+            conditional_item_data.push(version_number);
+        }
+        if has_unique_section {
+            write_variable_size_field(
+                &mut conditional_item_data,
+                field::Field::FieldSizeOfStructuredMessageUnique,
+                &unique_data
+            )?;
+        }
+    }
+
+    if has_repeated_section {
+        write_variable_size_field(
+            &mut conditional_item_data,
+            field::Field::FieldSizeOfStructuredMessageRepeated,
+            &repeated_data
+        )?;
+    }
+    if let Some(ref airline_individual_use) = leg.airline_individual_use {
+        conditional_item_data.push_str(airline_individual_use);
+    }
+
+    write_variable_size_field(output, field::Field::FieldSizeOfVariableSizeField, &conditional_item_data)
+}
+
+/// Encodes a Security Data section with the specified type and data.
+fn security_data<W: Write + ?Sized>(output: &mut W, type_of_security_data: char, data: &str) -> Result<()> {
+    write_chr_field(output, '^')?;
+    write_chr_field(output, type_of_security_data)?;
+    write_variable_size_field(output, field::Field::LengthOfSecurityData, data)
+}
+
+/// Writes all boarding pass data preceding the security data section into `output`.
+fn write_signed_payload<W: Write + ?Sized>(output: &mut W, boarding_pass: &Bcbp) -> Result<()> {
+    if boarding_pass.legs.len() > MAX_NUMBER_OF_LEGS {
+        return Err(Error::LengthOverflow(field::Field::NumberOfLegsEncoded));
+    }
+
+    write!(output, "M{:X}", boarding_pass.legs.len()).map_err(write_failed)?;
+    write_str_field(output, field::Field::PassengerName, &boarding_pass.passenger_name)?;
+    write_chr_field(output, boarding_pass.electronic_ticket_indicator)?;
+
+    for (leg_index, current_leg) in boarding_pass.legs.iter().enumerate() {
+        let metadata = if leg_index == 0 {
+            Some(&boarding_pass.metadata)
+        } else {
+            None
+        };
+        leg(output, current_leg, metadata)?;
+    }
+
+    Ok(())
+}
+
+/// Encodes all boarding pass data preceding the security data section.
+/// This is the data covered by the security data.
+pub(crate) fn signed_payload(boarding_pass: &Bcbp) -> Result<String> {
+    let mut output = String::new();
+    write_signed_payload(&mut output, boarding_pass)?;
+    Ok(output)
+}
+
+/// Writes a boarding pass into `output` as in `to_string`.
+///
+/// Data is written as it is encoded, so `output` may hold part of the boarding pass on failure.
+pub(crate) fn write<W: Write + ?Sized>(output: &mut W, boarding_pass: &Bcbp) -> Result<()> {
+    write_signed_payload(output, boarding_pass)?;
+
+    let SecurityData { type_of_security_data, ref security_data, .. } = boarding_pass.security_data;
+    if let Some(type_of_security_data) = type_of_security_data {
+        self::security_data(output, type_of_security_data, security_data.as_deref().unwrap_or(""))?;
+    }
+
+    Ok(())
+}
+
+/// Encodes a boarding pass as an IATA BCBP Type M string.
+///
+/// All length fields are computed from the data being encoded.
+/// The security data of the boarding pass, if any, is encoded as-is.
+/// Conditional data requires a version number to introduce it, and fails to encode without one.
+pub fn to_string(boarding_pass: &Bcbp) -> Result<String> {
+    let mut output = String::with_capacity(encoded_len(boarding_pass)?);
+    write(&mut output, boarding_pass)?;
+    Ok(output)
+}
+
+/// Returns the length of a fixed-length field holding `value` once padded by `write_str_field`,
+/// failing if `value` is longer than the field.
+fn str_field_len(field_id: field::Field, value: &str) -> Result<usize> {
+    if value.len() > field_id.len() {
+        return Err(Error::LengthOverflow(field_id));
+    }
+
+    Ok(field_id.len())
+}
+
+/// Returns the length of a variable-size field holding `data_len` bytes, including its length.
+fn variable_size_field_len(field_id: field::Field, data_len: usize) -> Result<usize> {
+    if data_len > MAX_VARIABLE_SIZE_FIELD_LEN {
+        return Err(Error::LengthOverflow(field_id));
+    }
+
+    Ok(2 + data_len)
+}
+
+/// Measures a sequence of optional fixed-length fields as encoded by `OptionalFields`.
+#[derive(Default)]
+struct OptionalFieldsLen {
+    len: usize,
+    pending_len: usize,
+}
+
+impl OptionalFieldsLen {
+    fn str_field(&mut self, field_id: field::Field, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.len += self.pending_len + value.len().max(field_id.len());
+                self.pending_len = 0;
+            }
+            None => {
+                self.pending_len += field_id.len();
+            }
+        }
+    }
+
+    fn chr_field(&mut self, field_id: field::Field, value: Option<char>) {
+        let mut buffer = [0; 4];
+        self.str_field(field_id, value.map(|c| &*c.encode_utf8(&mut buffer)));
+    }
+}
+
+/// Returns the length of the data encoded by `conditional_metadata`.
+fn conditional_metadata_len(metadata: &ConditionalMetadata) -> usize {
+    let mut fields = OptionalFieldsLen::default();
+    fields.chr_field(field::Field::PassengerDescription, metadata.passenger_description);
+    fields.chr_field(field::Field::SourceOfCheckIn, metadata.source_of_check_in);
+    fields.chr_field(field::Field::SourceOfBoardingPassIssuance, metadata.source_of_boarding_pass_issuance);
+    fields.str_field(field::Field::DateOfIssueOfBoardingPass, metadata.date_of_issue_of_boarding_pass.as_deref());
+    fields.chr_field(field::Field::DocumentType, metadata.document_type);
+    fields.str_field(field::Field::AirlineDesignatorOfBoardingPassIssuer, metadata.airline_designator_of_boarding_pass_issuer.as_deref());
+    fields.str_field(field::Field::BaggageTagLicensePlateNumbers, metadata.baggage_tag_license_plate_numbers.as_deref());
+    fields.str_field(field::Field::FirstNonConsecutiveBaggageTagLicensePlateNumbers, metadata.first_non_consecutive_baggage_tag_license_plate_numbers.as_deref());
+    fields.str_field(field::Field::SecondNonConsecutiveBaggageTagLicensePlateNumbers, metadata.second_non_consecutive_baggage_tag_license_plate_numbers.as_deref());
+    fields.len
+}
+
+/// Returns the length of the data encoded by `conditional_leg_data`.
+fn conditional_leg_data_len(leg: &Leg) -> usize {
+    let mut fields = OptionalFieldsLen::default();
+    fields.str_field(field::Field::AirlineNumericCode, leg.airline_numeric_code.as_deref());
+    fields.str_field(field::Field::DocumentFormSerialNumber, leg.document_form_serial_number.as_deref());
+    fields.chr_field(field::Field::SelecteeIndicator, leg.selectee_indicator);
+    fields.chr_field(field::Field::InternationalDocumentVerification, leg.international_document_verification);
+    fields.str_field(field::Field::MarketingCarrierDesignator, leg.marketing_carrier_designator.as_deref());
+    fields.str_field(field::Field::FrequentFlyerAirlineDesignator, leg.frequent_flyer_airline_designator.as_deref());
+    fields.str_field(field::Field::FrequentFlyerNumber, leg.frequent_flyer_number.as_deref());
+    fields.chr_field(field::Field::IdAdIndicator, leg.id_ad_indicator);
+    fields.str_field(field::Field::FreeBaggageAllowance, leg.free_baggage_allowance.as_deref());
+    fields.chr_field(field::Field::FastTrack, leg.fast_track);
+    fields.len
+}
+
+/// Returns the length of the data encoded by `leg`, mirroring its structure.
+fn leg_len(leg: &Leg, metadata: Option<&ConditionalMetadata>) -> Result<usize> {
+    let mandatory_len = str_field_len(field::Field::OperatingCarrierPnrCode, &leg.operating_carrier_pnr_code)?
+        + str_field_len(field::Field::FromCityAirportCode, &leg.from_city_airport_code)?
+        + str_field_len(field::Field::ToCityAirportCode, &leg.to_city_airport_code)?
+        + str_field_len(field::Field::OperatingCarrierDesignator, &leg.operating_carrier_designator)?
+        + str_field_len(field::Field::FlightNumber, &leg.flight_number)?
+        + str_field_len(field::Field::DateOfFlight, &leg.date_of_flight)?
+        + leg.compartment_code.len_utf8()
+        + str_field_len(field::Field::SeatNumber, &leg.seat_number)?
+        + str_field_len(field::Field::CheckInSequenceNumber, &leg.check_in_sequence_number)?
+        + leg.passenger_status.len_utf8();
+
+    let repeated_len = conditional_leg_data_len(leg);
+    let has_repeated_section = repeated_len > 0 || leg.airline_individual_use.is_some();
+
+    let mut conditional_item_len = 0;
+    if let Some(metadata) = metadata {
+        let unique_len = conditional_metadata_len(metadata);
+        let has_unique_section = unique_len > 0 || has_repeated_section;
+
+        if metadata.version_number.is_some() || has_unique_section {
+            let version_number = metadata.version_number.ok_or(Error::MissingField(field::Field::VersionNumber))?;
+            conditional_item_len += 1 + version_number.len_utf8();
+        }
+        if has_unique_section {
+            conditional_item_len += variable_size_field_len(field::Field::FieldSizeOfStructuredMessageUnique, unique_len)?;
+        }
+    }
+
+    if has_repeated_section {
+        conditional_item_len += variable_size_field_len(field::Field::FieldSizeOfStructuredMessageRepeated, repeated_len)?;
+    }
+    if let Some(ref airline_individual_use) = leg.airline_individual_use {
+        conditional_item_len += airline_individual_use.len();
+    }
+
+    Ok(mandatory_len + variable_size_field_len(field::Field::FieldSizeOfVariableSizeField, conditional_item_len)?)
+}
+
+/// Returns the length of the string `to_string` would produce without encoding it.
+pub(crate) fn encoded_len(boarding_pass: &Bcbp) -> Result<usize> {
+    if boarding_pass.legs.len() > MAX_NUMBER_OF_LEGS {
+        return Err(Error::LengthOverflow(field::Field::NumberOfLegsEncoded));
+    }
+
+    let mut len = 2
+        + str_field_len(field::Field::PassengerName, &boarding_pass.passenger_name)?
+        + boarding_pass.electronic_ticket_indicator.len_utf8();
+
+    for (leg_index, current_leg) in boarding_pass.legs.iter().enumerate() {
+        let metadata = if leg_index == 0 {
+            Some(&boarding_pass.metadata)
+        } else {
+            None
+        };
+        len += leg_len(current_leg, metadata)?;
+    }
+
+    let SecurityData { type_of_security_data, ref security_data, .. } = boarding_pass.security_data;
+    if let Some(type_of_security_data) = type_of_security_data {
+        let data_len = security_data.as_deref().map_or(0, str::len);
+        len += 1 + type_of_security_data.len_utf8()
+            + variable_size_field_len(field::Field::LengthOfSecurityData, data_len)?;
+    }
+
+    Ok(len)
+}
+
+/// Encodes only the mandatory items of a boarding pass as an IATA BCBP Type M string.
+///
+/// Conditional items, airline individual use data and security data are omitted,
+/// producing a minimal pass which discloses as little as possible about the passenger.
+pub fn to_string_mandatory(boarding_pass: &Bcbp) -> Result<String> {
+    let legs = boarding_pass.legs.iter()
+        .map(|leg| Leg {
+            operating_carrier_pnr_code: leg.operating_carrier_pnr_code,
+            from_city_airport_code: leg.from_city_airport_code,
+            to_city_airport_code: leg.to_city_airport_code,
+            operating_carrier_designator: leg.operating_carrier_designator,
+            flight_number: leg.flight_number,
+            date_of_flight: leg.date_of_flight,
+            compartment_code: leg.compartment_code,
+            seat_number: leg.seat_number,
+            check_in_sequence_number: leg.check_in_sequence_number,
+            passenger_status: leg.passenger_status,
+            ..Default::default()
+        })
+        .collect();
+
+    to_string(&Bcbp {
+        passenger_name: boarding_pass.passenger_name,
+        electronic_ticket_indicator: boarding_pass.electronic_ticket_indicator,
+        metadata: Default::default(),
+        legs,
+        declared_leg_count: None,
+        security_data: Default::default(),
+    })
+}
+
+/// Encodes a boarding pass as an IATA BCBP Type M string, signed by `signer`.
+///
+/// Any security data already present in the boarding pass is replaced with a signature
+/// computed by `signer` over the freshly encoded boarding pass data.
+pub fn to_string_signed<S>(boarding_pass: &Bcbp, signer: &S) -> Result<String>
+where
+    S: SecuritySigner + ?Sized,
+{
+    let mut output = signed_payload(boarding_pass)?;
+
+    let signature = signer.sign(output.as_bytes());
+    security_data(&mut output, signer.type_of_security_data(), &signature)?;
+
+    Ok(output)
+}
+
+/// Items unique to the boarding pass which must be set for it to be encoded.
+const MANDATORY_FIELDS: &[field::Field] = &[
+    field::Field::PassengerName,
+    field::Field::ElectronicTicketIndicator,
+];
+
+/// Items of each leg which must be set for the boarding pass to be encoded.
+/// The Seat Number is omitted, as it is blank for passengers without an assigned seat.
+const MANDATORY_LEG_FIELDS: &[field::Field] = &[
+    field::Field::OperatingCarrierPnrCode,
+    field::Field::FromCityAirportCode,
+    field::Field::ToCityAirportCode,
+    field::Field::OperatingCarrierDesignator,
+    field::Field::FlightNumber,
+    field::Field::DateOfFlight,
+    field::Field::CompartmentCode,
+    field::Field::CheckInSequenceNumber,
+    field::Field::PassengerStatus,
+];
+
+/// Appends an error for each field which is not valid for its length and data format,
+/// and for each of `mandatory` which is not set.
+fn field_errors<'a, F>(errors: &mut Vec<BuildError>, leg_index: Option<usize>, mandatory: &[field::Field], value_of: F)
+where
+    F: Fn(field::Field) -> Option<&'a str>,
+{
+    for &field_id in mandatory {
+        if non_blank(value_of(field_id)).is_none() {
+            errors.push(BuildError::new(field_id, leg_index, Error::MissingField(field_id)));
+        }
+    }
+    for &field_id in field::Field::all() {
+        if let Some(Err(error)) = value_of(field_id).map(|value| validate(field_id, value)) {
+            errors.push(BuildError::new(field_id, leg_index, error));
+        }
+    }
+}
+
+/// Returns the field of an error raised while encoding, which is either a length overflow
+/// or a conditional section without the version number introducing it.
+fn failed_field(error: &Error) -> field::Field {
+    match *error {
+        Error::LengthOverflow(field_id) | Error::MissingField(field_id) => field_id,
+        _ => unreachable!("encoding only fails on length overflow or a missing version number"),
+    }
+}
+
+/// Returns every reason the boarding pass cannot be encoded, for the boarding pass followed
+/// by each leg in order.
+fn build_errors(boarding_pass: &Bcbp) -> Vec<BuildError> {
+    let mut errors = Vec::new();
+
+    field_errors(&mut errors, None, MANDATORY_FIELDS, |field_id| boarding_pass.field(field_id));
+    if boarding_pass.legs.len() > MAX_NUMBER_OF_LEGS {
+        let field_id = field::Field::NumberOfLegsEncoded;
+        errors.push(BuildError::new(field_id, None, Error::LengthOverflow(field_id)));
+    }
+
+    for (leg_index, current_leg) in boarding_pass.legs.iter().enumerate() {
+        field_errors(&mut errors, Some(leg_index), MANDATORY_LEG_FIELDS, |field_id| current_leg.field(field_id));
+        let metadata = if leg_index == 0 {
+            Some(&boarding_pass.metadata)
+        } else {
+            None
+        };
+        // Fields longer than their length are already reported by `field_errors`.
+        if let Err(error) = leg_len(current_leg, metadata) {
+            let build_error = BuildError::new(failed_field(&error), Some(leg_index), error);
+            if !errors.contains(&build_error) {
+                errors.push(build_error);
+            }
+        }
+    }
+
+    let data_len = boarding_pass.security_data.security_data().map_or(0, str::len);
+    if let Err(error) = variable_size_field_len(field::Field::LengthOfSecurityData, data_len) {
+        errors.push(BuildError::new(failed_field(&error), None, error));
+    }
+
+    errors
+}
+
+/// Encodes a boarding pass as an IATA BCBP Type M string as in `to_string`, after checking
+/// every field is valid for its length and data format and every mandatory item is set.
+///
+/// Returns all errors found rather than only the first, for the boarding pass followed by each leg.
+pub fn to_string_checked(boarding_pass: &Bcbp) -> result::Result<String, Vec<BuildError>> {
+    let errors = build_errors(boarding_pass);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    to_string(boarding_pass)
+        .map_err(|error| vec![BuildError::new(failed_field(&error), None, error)])
+}
@@ -0,0 +1,351 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use serde::ser::{self, Impossible, Serialize};
+
+use crate::bcbp::{Bcbp, Leg, MAX_NUMBER_OF_LEGS};
+use crate::de::field::Field;
+use crate::de::format::{key, LEGS_KEY};
+use crate::error::{Error, Result};
+use crate::ser::encoder;
+
+impl ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// A serialized value, before it is assigned to the fields of a boarding pass.
+enum Node {
+    None,
+    Str(String),
+    Unsigned(u64),
+    Signed(i64),
+    Seq(Vec<Node>),
+    Map(Vec<(Node, Node)>),
+}
+
+/// Returns the number of digits a numeric value of `field` is zero-padded to.
+fn numeric_width(field: Field) -> usize {
+    match field.data_format() {
+        "N" => field.len(),
+        data_format => data_format.chars().take_while(|&c| c == 'N').count(),
+    }
+}
+
+impl Node {
+    /// The value of `field` represented by the node, or None if it is not set.
+    fn into_value(self, field: Field) -> Result<Option<String>> {
+        match self {
+            Node::None => Ok(None),
+            Node::Str(value) => Ok(Some(value)),
+            Node::Unsigned(value) => Ok(Some(format!("{:01$}", value, numeric_width(field)))),
+            Node::Signed(value) if value >= 0 => Node::Unsigned(value as u64).into_value(field),
+            _ => Err(Error::InvalidFieldValue(field)),
+        }
+    }
+
+    /// The entries of a map or structure keyed by field name.
+    fn into_entries(self) -> Result<Vec<(String, Node)>> {
+        let entries = match self {
+            Node::Map(entries) => entries,
+            _ => return Err(ser::Error::custom("expected a map or structure")),
+        };
+        entries
+            .into_iter()
+            .map(|(key, value)| match key {
+                Node::Str(key) => Ok((key, value)),
+                _ => Err(ser::Error::custom("expected a field name")),
+            })
+            .collect()
+    }
+}
+
+/// Returns the field identified by `name`, as deserialized by `Deserializer`.
+fn field_named(name: &str) -> Result<Field> {
+    Field::all().iter().cloned().find(|&field| key(field) == name).ok_or(Error::UnknownField)
+}
+
+/// Assigns the fields of a leg from a map.
+fn leg(node: Node) -> Result<Leg> {
+    let mut leg = Leg::default();
+    for (name, value) in node.into_entries()? {
+        let field = field_named(&name)?;
+        leg.set_field(field, value.into_value(field)?.as_deref())?;
+    }
+    Ok(leg)
+}
+
+/// Assigns the fields of a boarding pass from a map, with the legs as a sequence of maps under `Legs`.
+fn bcbp(node: Node) -> Result<Bcbp> {
+    let mut boarding_pass = Bcbp::default();
+    let mut type_of_security_data = None;
+    let mut security_data = None;
+
+    for (name, value) in node.into_entries()? {
+        if name == LEGS_KEY {
+            let legs = match value {
+                Node::Seq(legs) if !legs.is_empty() => legs,
+                _ => return Err(Error::InvalidFieldValue(Field::NumberOfLegsEncoded)),
+            };
+            if legs.len() > MAX_NUMBER_OF_LEGS {
+                return Err(Error::LengthOverflow(Field::NumberOfLegsEncoded));
+            }
+            boarding_pass.legs = legs.into_iter().map(leg).collect::<Result<_>>()?;
+            continue;
+        }
+
+        let field = field_named(&name)?;
+        let value = value.into_value(field)?;
+        match field {
+            Field::TypeOfSecurityData => type_of_security_data = value,
+            Field::SecurityData => security_data = value,
+            _ => boarding_pass.set_field(field, value.as_deref())?,
+        }
+    }
+
+    boarding_pass.set_security_data_fields(type_of_security_data.as_deref(), security_data.as_deref())?;
+    Ok(boarding_pass)
+}
+
+/// Serializes `value` as an IATA BCBP Type M string, the inverse of `from_str_as`.
+///
+/// The value must serialize as a map or structure from field names such as `PassengerName` to
+/// their values, with the legs as a sequence of maps under `Legs`. Values are strings, characters
+/// or non-negative integers, which are zero-padded to the digits of numeric fields. Absent options
+/// leave optional fields unset. Each value is validated as by its setter.
+pub fn to_string_from<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    encoder::to_string(&bcbp(value.serialize(NodeSerializer)?)?)
+}
+
+/// Serializes values into a `Node`.
+struct NodeSerializer;
+
+/// Accumulates the elements of a sequence.
+struct SeqSerializer(Vec<Node>);
+
+/// Accumulates the entries of a map or structure.
+struct MapSerializer {
+    entries: Vec<(Node, Node)>,
+    key: Option<Node>,
+}
+
+impl ser::Serializer for NodeSerializer {
+    type Ok = Node;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = Impossible<Node, Error>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = Impossible<Node, Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<Node> {
+        Err(ser::Error::custom("booleans are not supported"))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Node> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Node> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Node> {
+        self.serialize_i64(i64::from(value))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Node> {
+        Ok(Node::Signed(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Node> {
+        self.serialize_u64(u64::from(value))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Node> {
+        self.serialize_u64(u64::from(value))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Node> {
+        self.serialize_u64(u64::from(value))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Node> {
+        Ok(Node::Unsigned(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Node> {
+        Ok(Node::Str(value.to_string()))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Node> {
+        Ok(Node::Str(value.to_string()))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Node> {
+        Ok(Node::Str(value.to_string()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Node> {
+        Ok(Node::Str(String::from(value)))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Node> {
+        std::str::from_utf8(value)
+            .map_err(|_| Error::InvalidCharacters)
+            .and_then(|value| self.serialize_str(value))
+    }
+
+    fn serialize_none(self) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Node> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<Node> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Node> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Node> {
+        Err(ser::Error::custom("enum variants with data are not supported"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(ser::Error::custom("enum variants with data are not supported"))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer> {
+        Ok(MapSerializer { entries: Vec::with_capacity(len.unwrap_or(0)), key: None })
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<MapSerializer> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(ser::Error::custom("enum variants with data are not supported"))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.0.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Seq(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().ok_or_else(|| ser::Error::custom("value serialized before its key"))?;
+        self.entries.push((key, value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Map(self.entries))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Node;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        self.entries.push((Node::Str(String::from(key)), value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node> {
+        Ok(Node::Map(self.entries))
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+mod capacity;
+mod encoder;
+#[cfg(feature = "serde")]
+mod format;
+mod ndef;
+mod writer;
+
+pub use self::encoder::{to_string, to_string_checked, to_string_mandatory, to_string_signed};
+
+#[cfg(feature = "serde")]
+pub use self::format::to_string_from;
+
+pub(crate) use self::encoder::signed_payload;
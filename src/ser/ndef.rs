@@ -0,0 +1,49 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use crate::bcbp::Bcbp;
+use crate::error::Result;
+use crate::ser::encoder;
+
+/// Message Begin, Message End and Short Record flags with the NFC Forum well-known type name format.
+const NDEF_HEADER_SHORT_RECORD: u8 = 0x80 | 0x40 | 0x10 | 0x01;
+
+/// Message Begin and Message End flags with the NFC Forum well-known type name format.
+const NDEF_HEADER: u8 = 0x80 | 0x40 | 0x01;
+
+/// The NFC Forum well-known record type for text.
+const NDEF_TEXT_RECORD_TYPE: u8 = b'T';
+
+/// The IANA language code of the text, which is not meaningful for BCBP data.
+const NDEF_TEXT_LANGUAGE_CODE: &[u8] = b"en";
+
+impl Bcbp {
+    /// Encodes the boarding pass as a single-record NDEF message containing an NFC Forum text record,
+    /// suitable for writing to an NFC tag or for host card emulation.
+    pub fn to_ndef_record(&self) -> Result<Vec<u8>> {
+        let encoded = encoder::to_string(self)?;
+
+        // The payload is a UTF-8 status byte holding the language code length, the code and the text.
+        let mut payload = Vec::with_capacity(1 + NDEF_TEXT_LANGUAGE_CODE.len() + encoded.len());
+        payload.push(NDEF_TEXT_LANGUAGE_CODE.len() as u8);
+        payload.extend_from_slice(NDEF_TEXT_LANGUAGE_CODE);
+        payload.extend_from_slice(encoded.as_bytes());
+
+        let mut record = Vec::with_capacity(payload.len() + 7);
+        if payload.len() <= 0xFF {
+            record.push(NDEF_HEADER_SHORT_RECORD);
+            record.push(1);
+            record.push(payload.len() as u8);
+        } else {
+            record.push(NDEF_HEADER);
+            record.push(1);
+            record.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        }
+        record.push(NDEF_TEXT_RECORD_TYPE);
+        record.extend_from_slice(&payload);
+
+        Ok(record)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt;
+use std::io;
+
+use crate::bcbp::Bcbp;
+use crate::error::Result;
+use crate::ser::encoder;
+
+/// Adapts an `io::Write` to `fmt::Write`, retaining the first error it reports.
+struct IoWriter<'a, W: io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+impl Bcbp {
+    /// Encodes the boarding pass as in `to_string`, writing directly into `output`.
+    ///
+    /// Nothing is written if the boarding pass cannot be encoded. Part of the boarding pass
+    /// may have been written if `output` itself fails, reported as `Error::WriteFailed`.
+    pub fn encode_to<W: fmt::Write + ?Sized>(&self, output: &mut W) -> Result<()> {
+        encoder::encoded_len(self)?;
+        encoder::write(output, self)
+    }
+
+    /// Encodes the boarding pass as in `encode_to`, writing the bytes directly into `output`.
+    ///
+    /// Fields are written individually, so unbuffered destinations should be wrapped in an
+    /// `io::BufWriter`. A boarding pass which cannot be encoded fails with `io::ErrorKind::InvalidInput`.
+    pub fn encode_to_writer<W: io::Write + ?Sized>(&self, output: &mut W) -> io::Result<()> {
+        encoder::encoded_len(self).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        let mut writer = IoWriter { inner: output, error: None };
+        encoder::write(&mut writer, self).map_err(|error| {
+            writer.error.take().unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, error))
+        })
+    }
+}
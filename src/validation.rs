@@ -0,0 +1,383 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use std::fmt;
+
+use crate::bcbp::setters::validate;
+use crate::bcbp::{Bcbp, Leg};
+use crate::de::field::Field;
+
+/// A version of Resolution 792 whose field rules a boarding pass is validated against.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ValidationProfile {
+    V2,
+    V3,
+    V5,
+    V6,
+    V7,
+    V8,
+}
+
+impl ValidationProfile {
+    /// The Version Number character identifying the version.
+    pub fn version_number(self) -> char {
+        match self {
+            ValidationProfile::V2 => '2',
+            ValidationProfile::V3 => '3',
+            ValidationProfile::V5 => '5',
+            ValidationProfile::V6 => '6',
+            ValidationProfile::V7 => '7',
+            ValidationProfile::V8 => '8',
+        }
+    }
+
+    /// Returns the profile for the version identified by `version_number`, if supported.
+    pub fn from_version_number(version_number: char) -> Option<Self> {
+        match version_number {
+            '2' => Some(ValidationProfile::V2),
+            '3' => Some(ValidationProfile::V3),
+            '5' => Some(ValidationProfile::V5),
+            '6' => Some(ValidationProfile::V6),
+            '7' => Some(ValidationProfile::V7),
+            '8' => Some(ValidationProfile::V8),
+            _ => None,
+        }
+    }
+
+    /// Returns the profile to validate a boarding pass declaring `version_number` against,
+    /// the latest profile not after the declared version, if it identifies one.
+    fn for_declared_version(version_number: char) -> Option<Self> {
+        ALL_PROFILES.iter()
+            .rev()
+            .find(|profile| version_number.is_ascii_digit() && profile.version_number() <= version_number)
+            .cloned()
+    }
+}
+
+/// Every supported profile, in order of increasing version.
+const ALL_PROFILES: &[ValidationProfile] = &[
+    ValidationProfile::V2,
+    ValidationProfile::V3,
+    ValidationProfile::V5,
+    ValidationProfile::V6,
+    ValidationProfile::V7,
+    ValidationProfile::V8,
+];
+
+/// The rule violated by a field.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FindingKind {
+    /// A required field is absent or consists entirely of spaces.
+    NotSet,
+    /// The value does not match the data format of the field.
+    InvalidFormat,
+    /// The field is present but not defined in the version validated against.
+    NotDefinedInVersion,
+    /// The Version Number is set but is not a digit identifying a version of Resolution 792.
+    UnrecognizedVersion,
+    /// The airline individual use data appears to begin with a repeated structured message,
+    /// suggesting the Field Size of Structured Message was miscomputed.
+    MisSizedStructuredMessage,
+}
+
+/// The severity of a finding.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Severity {
+    /// The boarding pass may be processed but deviates from the version validated against.
+    Warning,
+    /// The boarding pass does not conform to the version validated against.
+    Error,
+}
+
+/// A violation of a field rule found by `Bcbp::validate`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Finding {
+    field: Field,
+    leg_index: Option<usize>,
+    kind: FindingKind,
+    severity: Severity,
+    since: ValidationProfile,
+}
+
+impl Finding {
+    /// The field violating the rule.
+    pub fn field(&self) -> Field {
+        self.field
+    }
+
+    /// The index of the leg containing the field, or `None` for fields unique to the boarding pass.
+    pub fn leg_index(&self) -> Option<usize> {
+        self.leg_index
+    }
+
+    /// The rule violated.
+    pub fn kind(&self) -> FindingKind {
+        self.kind
+    }
+
+    /// The severity of the violation.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The earliest version in which the rule applies.
+    pub fn since(&self) -> ValidationProfile {
+        self.since
+    }
+
+    /// The item number of the field in the Implementation Guide.
+    pub fn item_number(&self) -> usize {
+        self.field.item_number()
+    }
+
+    /// A description of the violation suitable for display.
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Item {} ({})", self.item_number(), self.field)?;
+        if let Some(leg_index) = self.leg_index {
+            write!(f, " in leg {}", leg_index + 1)?;
+        }
+        match self.kind {
+            FindingKind::NotSet =>
+                write!(f, ": not set, required since version {}", self.since.version_number()),
+            FindingKind::InvalidFormat =>
+                write!(f, ": expected '{}'", self.field.data_format()),
+            FindingKind::NotDefinedInVersion =>
+                write!(f, ": not defined before version {}", self.since.version_number()),
+            FindingKind::UnrecognizedVersion =>
+                write!(f, ": not a recognized version"),
+            FindingKind::MisSizedStructuredMessage =>
+                write!(f, ": airline individual use appears to begin with a structured message"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Finding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Finding", 7)?;
+        state.serialize_field("field", &self.field)?;
+        state.serialize_field("item_number", &self.item_number())?;
+        state.serialize_field("leg_index", &self.leg_index)?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("severity", &self.severity)?;
+        state.serialize_field("since", &self.since)?;
+        state.serialize_field("message", &self.message())?;
+        state.end()
+    }
+}
+
+/// The findings of validating a boarding pass against a version profile.
+///
+/// With the `serde` feature, the report serializes as an object with the `profile`, e.g. `"V6"`,
+/// and an array of `findings`. Each finding is an object with the `field` and `kind` variant
+/// names, the Implementation Guide `item_number`, the zero-based `leg_index` or null,
+/// the `severity` as `"Warning"` or `"Error"`, the `since` profile and a human-readable `message`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ValidationReport {
+    profile: ValidationProfile,
+    findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// The version profile the boarding pass was validated against.
+    pub fn profile(&self) -> ValidationProfile {
+        self.profile
+    }
+
+    /// The findings for the boarding pass followed by those for each leg in order.
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+
+    /// Returns `true` if no finding is an error.
+    pub fn is_conformant(&self) -> bool {
+        self.findings.iter().all(|finding| finding.severity < Severity::Error)
+    }
+}
+
+/// A field required beginning with a version, and the severity of its absence.
+type RequiredField = (Field, ValidationProfile, Severity);
+
+/// Items which must be set in every boarding pass, beginning with the specified version.
+const REQUIRED_FIELDS: &[RequiredField] = &[
+    (Field::PassengerName, ValidationProfile::V2, Severity::Error),
+    (Field::ElectronicTicketIndicator, ValidationProfile::V2, Severity::Error),
+    (Field::VersionNumber, ValidationProfile::V2, Severity::Error),
+];
+
+/// Items which must be set in every leg, beginning with the specified version.
+///
+/// The Selectee Indicator is only required for travel involving the United States,
+/// which cannot be determined from the boarding pass alone, so its absence is only a warning.
+const REQUIRED_LEG_FIELDS: &[RequiredField] = &[
+    (Field::OperatingCarrierPnrCode, ValidationProfile::V2, Severity::Error),
+    (Field::FromCityAirportCode, ValidationProfile::V2, Severity::Error),
+    (Field::ToCityAirportCode, ValidationProfile::V2, Severity::Error),
+    (Field::OperatingCarrierDesignator, ValidationProfile::V2, Severity::Error),
+    (Field::FlightNumber, ValidationProfile::V2, Severity::Error),
+    (Field::DateOfFlight, ValidationProfile::V2, Severity::Error),
+    (Field::CompartmentCode, ValidationProfile::V2, Severity::Error),
+    (Field::CheckInSequenceNumber, ValidationProfile::V2, Severity::Error),
+    (Field::PassengerStatus, ValidationProfile::V2, Severity::Error),
+    (Field::SelecteeIndicator, ValidationProfile::V6, Severity::Warning),
+];
+
+/// Items first defined in the specified version.
+const INTRODUCED_FIELDS: &[(Field, ValidationProfile)] = &[
+    (Field::FastTrack, ValidationProfile::V5),
+];
+
+/// Items of the repeated structured message, in the order they are encoded.
+const STRUCTURED_MESSAGE_FIELDS: &[Field] = &[
+    Field::AirlineNumericCode,
+    Field::DocumentFormSerialNumber,
+    Field::SelecteeIndicator,
+    Field::InternationalDocumentVerification,
+    Field::MarketingCarrierDesignator,
+    Field::FrequentFlyerAirlineDesignator,
+    Field::FrequentFlyerNumber,
+    Field::IdAdIndicator,
+    Field::FreeBaggageAllowance,
+    Field::FastTrack,
+];
+
+/// Returns `true` if `data` plausibly begins with a repeated structured message: a hexadecimal size
+/// covering at least a numeric airline code and document form serial number, both entirely digits,
+/// followed by items which each conform to their data format.
+fn looks_like_structured_message(data: &str) -> bool {
+    let size = data.get(.. 2)
+        .filter(|size| size.bytes().all(|b| b.is_ascii_digit() || (b'A' ..= b'F').contains(&b)))
+        .and_then(|size| usize::from_str_radix(size, 16).ok());
+    let message = match size.and_then(|size| data.get(2 .. 2 + size)) {
+        Some(message) if message.len() >= 13 => message,
+        _ => return false,
+    };
+    if !message[.. 13].bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+
+    let mut offset = 0;
+    STRUCTURED_MESSAGE_FIELDS.iter().all(|&field| {
+        let value = message.get(offset .. (offset + field.len()).min(message.len())).unwrap_or("");
+        offset += field.len();
+        validate(field, value).is_ok()
+    })
+}
+
+/// Accumulates the findings for fields identified dynamically.
+struct Validator {
+    profile: ValidationProfile,
+    findings: Vec<Finding>,
+}
+
+impl Validator {
+    fn push(
+        &mut self,
+        field: Field,
+        leg_index: Option<usize>,
+        kind: FindingKind,
+        severity: Severity,
+        since: ValidationProfile,
+    ) {
+        self.findings.push(Finding { field, leg_index, kind, severity, since });
+    }
+
+    /// Checks the fields present against their data format and the version they were introduced in.
+    fn fields<'a, F>(&mut self, leg_index: Option<usize>, value_of: F)
+    where
+        F: Fn(Field) -> Option<&'a str>,
+    {
+        for &field in Field::all() {
+            if let Some(value) = value_of(field) {
+                if validate(field, value).is_err() {
+                    self.push(field, leg_index, FindingKind::InvalidFormat, Severity::Error, ValidationProfile::V2);
+                }
+                let introduced = INTRODUCED_FIELDS.iter().find(|(introduced, _)| *introduced == field);
+                if let Some(&(_, since)) = introduced {
+                    if since > self.profile {
+                        self.push(field, leg_index, FindingKind::NotDefinedInVersion, Severity::Warning, since);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks that each of `required` is set, adding findings for those that are not.
+    fn required<'a, F>(&mut self, leg_index: Option<usize>, required: &[RequiredField], value_of: F)
+    where
+        F: Fn(Field) -> Option<&'a str>,
+    {
+        for &(field, since, severity) in required {
+            let is_set = value_of(field).is_some_and(|value| !value.trim().is_empty());
+            if since <= self.profile && !is_set {
+                self.push(field, leg_index, FindingKind::NotSet, severity, since);
+            }
+        }
+    }
+
+    fn leg(&mut self, leg_index: usize, leg: &Leg) {
+        self.required(Some(leg_index), REQUIRED_LEG_FIELDS, |field| leg.field(field));
+        self.fields(Some(leg_index), |field| leg.field(field));
+        if leg.airline_individual_use().is_some_and(looks_like_structured_message) {
+            self.push(
+                Field::FieldSizeOfStructuredMessageRepeated,
+                Some(leg_index),
+                FindingKind::MisSizedStructuredMessage,
+                Severity::Warning,
+                ValidationProfile::V2,
+            );
+        }
+    }
+
+    fn bcbp(&mut self, bcbp: &Bcbp) {
+        self.required(None, REQUIRED_FIELDS, |field| bcbp.field(field));
+        // Parsing accepts any character as the version, which is only reported here.
+        let is_unrecognized_version = bcbp.version_number()
+            .is_some_and(|version_number| version_number != ' ' && !version_number.is_ascii_digit());
+        if is_unrecognized_version {
+            self.push(Field::VersionNumber, None, FindingKind::UnrecognizedVersion, Severity::Warning, ValidationProfile::V2);
+        }
+        self.fields(None, |field| bcbp.field(field));
+        for (leg_index, leg) in bcbp.legs().iter().enumerate() {
+            self.leg(leg_index, leg);
+        }
+    }
+}
+
+impl Bcbp {
+    /// Validates the boarding pass against the field rules of the version `profile`,
+    /// independently of how leniently it was parsed.
+    ///
+    /// Returns the findings for the boarding pass followed by those for each leg in order.
+    pub fn validate(&self, profile: &ValidationProfile) -> ValidationReport {
+        let mut validator = Validator { profile: *profile, findings: Vec::new() };
+        validator.bcbp(self);
+        ValidationReport { profile: *profile, findings: validator.findings }
+    }
+
+    /// Validates the boarding pass against the version it declares, reporting fields not yet
+    /// defined in that version and fields it requires which are not set.
+    ///
+    /// Versions without a profile, such as version 4, are validated against the latest
+    /// preceding one. Returns `None` if the Version Number is not set or not a digit
+    /// identifying a supported version.
+    pub fn validate_declared_version(&self) -> Option<ValidationReport> {
+        self.version_number()
+            .and_then(ValidationProfile::for_declared_version)
+            .map(|profile| self.validate(&profile))
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+use serde_json::{json, Value};
+
+use crate::bcbp::Bcbp;
+use crate::error::Result;
+use crate::ser;
+
+/// Returns `value` without surrounding spaces or leading zeroes, retaining at least one character.
+fn without_leading_zeroes(value: &str) -> &str {
+    let value = value.trim();
+    let stripped = value.trim_start_matches('0');
+    if stripped.is_empty() && !value.is_empty() {
+        &value[value.len() - 1 ..]
+    } else {
+        stripped
+    }
+}
+
+impl Bcbp {
+    /// Returns the Google Wallet `flightClass` and `flightObject` fragments describing the first leg.
+    ///
+    /// The fragments omit the issuer-specific `id` and `classId` properties, which must be
+    /// populated by the caller before submission to the Google Wallet API.
+    pub fn to_google_wallet_object(&self) -> Result<Value> {
+        let leg = self.first_leg();
+        let barcode_value = ser::to_string(self)?;
+
+        Ok(json!({
+            "flightClass": {
+                "flightHeader": {
+                    "carrier": {
+                        "carrierIataCode": leg.operating_carrier_designator().trim(),
+                    },
+                    "flightNumber": without_leading_zeroes(leg.flight_number()),
+                },
+                "origin": {
+                    "airportIataCode": leg.from_city_airport_code().trim(),
+                },
+                "destination": {
+                    "airportIataCode": leg.to_city_airport_code().trim(),
+                },
+            },
+            "flightObject": {
+                "passengerName": self.passenger_name().trim(),
+                "reservationInfo": {
+                    "confirmationCode": leg.operating_carrier_pnr_code().trim(),
+                },
+                "boardingAndSeatingInfo": {
+                    "seatNumber": without_leading_zeroes(leg.seat_number()),
+                    "sequenceNumber": without_leading_zeroes(leg.check_in_sequence_number()),
+                },
+                "barcode": {
+                    "type": "PDF_417",
+                    "value": barcode_value,
+                },
+            },
+        }))
+    }
+}
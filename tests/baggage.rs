@@ -0,0 +1,57 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the baggage tag license plate numbers.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn all_baggage_tags() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let tags: Vec<String> = pass_data.all_baggage_tags().map(|tag| tag.to_string()).collect();
+    assert_eq!(tags, vec!["0014123456", "0014123457", "0014123458"]);
+
+    let tag = pass_data.all_baggage_tags().next().unwrap();
+    assert_eq!(tag.tag_type(), '0');
+    assert_eq!(tag.carrier_numeric_code(), "014");
+    assert_eq!(tag.serial_number(), "123456");
+
+    // Non-consecutive fields follow the primary field and blank fields are skipped.
+    let mut pass_data = pass_data;
+    pass_data.set_first_non_consecutive_baggage_tag_license_plate_numbers(Some("1220999999002")).unwrap();
+    pass_data.set_second_non_consecutive_baggage_tag_license_plate_numbers(Some("0220000100000")).unwrap();
+    pass_data.set_baggage_tag_license_plate_numbers(Some("             ")).unwrap();
+    let tags: Vec<String> = pass_data.all_baggage_tags().map(|tag| tag.to_string()).collect();
+    assert_eq!(tags, vec!["1220999999", "0220000100"]);
+}
+
+#[test]
+fn baggage_tag_runs() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let tags: Vec<BaggageTag> = pass_data.baggage_tags().collect();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].tag_type(), '0');
+    assert_eq!(tags[0].carrier_numeric_code(), "014");
+    assert_eq!(tags[0].first_tag_number().as_str(), "0014123456");
+    assert_eq!(tags[0].last_tag_number().as_str(), "0014123458");
+    assert_eq!(tags[0].tag_count(), 3);
+
+    // A count of zero is a single tag, and runs end at the largest serial number.
+    pass_data.set_first_non_consecutive_baggage_tag_license_plate_numbers(Some("1220999998005")).unwrap();
+    pass_data.set_second_non_consecutive_baggage_tag_license_plate_numbers(Some("0220000100000")).unwrap();
+    let tags: Vec<BaggageTag> = pass_data.baggage_tags().collect();
+    assert_eq!(tags.len(), 3);
+    assert_eq!(tags[1].carrier_numeric_code(), "220");
+    assert_eq!(tags[1].last_tag_number().as_str(), "1220999999");
+    assert_eq!(tags[1].tag_count(), 2);
+    assert_eq!(tags[2].first_tag_number(), tags[2].last_tag_number());
+    assert_eq!(tags[2].tag_count(), 1);
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the built-in ECDSA P-256 security data verifier.
+
+#![cfg(feature = "crypto")]
+
+extern crate base64;
+extern crate iata_bcbp;
+extern crate p256;
+
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+
+/// Signs boarding passes with a fixed key, encoding the signature as selected.
+struct TestSigner {
+    signing_key: SigningKey,
+    der: bool,
+}
+
+impl TestSigner {
+    fn new(der: bool) -> Self {
+        TestSigner { signing_key: SigningKey::from_slice(&[0x2A; 32]).unwrap(), der }
+    }
+
+    fn verifier(&self) -> EcdsaP256Verifier {
+        let public_key = self.signing_key.verifying_key().to_encoded_point(true);
+        EcdsaP256Verifier::from_sec1_bytes(public_key.as_bytes()).unwrap()
+    }
+}
+
+impl SecuritySigner for TestSigner {
+    fn type_of_security_data(&self) -> char {
+        '1'
+    }
+
+    fn sign(&self, signed_payload: &[u8]) -> String {
+        let signature: Signature = self.signing_key.sign(signed_payload);
+        if self.der {
+            STANDARD.encode(signature.to_der().as_bytes())
+        } else {
+            STANDARD.encode(signature.to_bytes())
+        }
+    }
+}
+
+#[test]
+fn verify_signed_passes() {
+    for &der in &[true, false] {
+        let signer = TestSigner::new(der);
+        let encoded = to_string_signed(&Bcbp::from_str(PASS_STR).unwrap(), &signer).unwrap();
+        let pass_data = Bcbp::from_str(&encoded).unwrap();
+        assert_eq!(pass_data.verify_security_data(&signer.verifier()), Ok(()));
+        assert_eq!(pass_data.verify_security_data(&signer.verifier().with_type_of_security_data('1')), Ok(()));
+        assert_eq!(
+            pass_data.verify_security_data(&signer.verifier().with_type_of_security_data('2')),
+            Err(Error::InvalidSecurityData)
+        );
+
+        // Tampering with the signed data invalidates the signature.
+        let tampered = Bcbp::from_str(&encoded.replacen("001A", "002A", 1)).unwrap();
+        assert_eq!(tampered.verify_security_data(&signer.verifier()), Err(Error::InvalidSecurityData));
+    }
+}
+
+#[test]
+fn reject_malformed_signatures_and_keys() {
+    let verifier = TestSigner::new(true).verifier();
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    for signature in &["", "not base64!", "AAAA"] {
+        pass_data.set_security_data('1', signature).unwrap();
+        assert_eq!(pass_data.verify_security_data(&verifier), Err(Error::InvalidSecurityData));
+    }
+
+    // The uncompressed point (0, 0) is not on the curve.
+    let mut off_curve = vec![0x04];
+    off_curve.extend_from_slice(&[0; 64]);
+    assert!(EcdsaP256Verifier::from_sec1_bytes(&off_curve).is_none());
+    assert!(EcdsaP256Verifier::from_sec1_bytes(&[]).is_none());
+}
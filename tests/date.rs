@@ -0,0 +1,42 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the resolution of dates encoded in a boarding pass.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+
+#[test]
+fn resolved_flight_date() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let date = pass_data.first_leg().resolved_flight_date(&pass_data).unwrap();
+    assert_eq!(date.year_digit(), 6);
+    assert_eq!(date.day_of_year(), 12);
+    assert_eq!(date.year_near(2019), 2016);
+    assert_eq!(date.year_near(2014), 2016);
+
+    // A flight day before the day of issue rolls over into the following year.
+    pass_data.set_date_of_issue_of_boarding_pass(Some("9360")).unwrap();
+    let date = pass_data.first_leg().resolved_flight_date(&pass_data).unwrap();
+    assert_eq!(date.year_digit(), 0);
+    assert_eq!(date.year_near(2019), 2020);
+
+    pass_data.set_date_of_issue_of_boarding_pass(Some("    ")).unwrap();
+    assert_eq!(pass_data.first_leg().resolved_flight_date(&pass_data), None);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_conversion() {
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let date = pass_data.first_leg().resolved_flight_date(&pass_data).unwrap();
+    let expected = time::Date::from_calendar_date(2016, time::Month::January, 12).unwrap();
+    assert_eq!(date.to_date(2019), Some(expected));
+}
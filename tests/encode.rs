@@ -0,0 +1,320 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering encoding of boarding pass data.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+/// Boarding passes from the IATA Resolution 792 Attachment B examples and real-world data.
+const PASS_STRS: &[&str] = &[
+    "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+    "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+    "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ",
+    "M1TEST/PETER          E24Z5RN AMSBRUKL 1733 019M008A0001 316>503  W0D0742497067621",
+    "M1ASKREN/TEST         EA272SL ORDNRTUA 0881 007F002K0303 15C>3180 K6007BUA              2901624760758980 UA UA EY975897            *30600    09  UAG    ",
+    "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WM6012BLH              2922023642241060 LH                        *30600000K09         ",
+    "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010",
+    "M1Mroz/Martin         EXXXXXX YVRYOWAC 0344 211          072>20B0  8203IAC 250140000000000 0AC AC AC000000000     *20000AC 223                14080003068        0B          N",
+];
+
+/// Signs boarding pass data with the length of the data in decimal.
+struct LengthSigner;
+
+impl SecuritySigner for LengthSigner {
+    fn type_of_security_data(&self) -> char {
+        '1'
+    }
+
+    fn sign(&self, signed_payload: &[u8]) -> String {
+        signed_payload.len().to_string()
+    }
+}
+
+#[test]
+fn round_trip() {
+    for &pass_str in PASS_STRS {
+        let pass_data = Bcbp::from_str(pass_str).unwrap();
+        assert_eq!(to_string(&pass_data).unwrap(), pass_str);
+    }
+}
+
+#[test]
+fn signed() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    // The existing security data is replaced with a signature over the encoded data.
+    assert_eq!(
+        to_string_signed(&pass_data, &LengthSigner).unwrap(),
+        "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^10260"
+    );
+}
+
+#[cfg(feature = "barcode")]
+#[test]
+fn render_qr() {
+    let pass_data = Bcbp::from_str(PASS_STRS[0]).unwrap();
+    let svg = pass_data.to_qr().unwrap();
+    assert!(svg.contains("<svg"));
+    assert!(svg.ends_with("</svg>"));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn google_wallet_object() {
+    let pass_data = Bcbp::from_str(PASS_STRS[0]).unwrap();
+    let wallet_object = pass_data.to_google_wallet_object().unwrap();
+    let flight_class = &wallet_object["flightClass"];
+    let flight_object = &wallet_object["flightObject"];
+    assert_eq!(flight_class["flightHeader"]["carrier"]["carrierIataCode"], "AC");
+    assert_eq!(flight_class["flightHeader"]["flightNumber"], "834");
+    assert_eq!(flight_class["origin"]["airportIataCode"], "YUL");
+    assert_eq!(flight_class["destination"]["airportIataCode"], "FRA");
+    assert_eq!(flight_object["passengerName"], "DESMARAIS/LUC");
+    assert_eq!(flight_object["reservationInfo"]["confirmationCode"], "ABC123");
+    assert_eq!(flight_object["boardingAndSeatingInfo"]["seatNumber"], "1A");
+    assert_eq!(flight_object["boardingAndSeatingInfo"]["sequenceNumber"], "25");
+    assert_eq!(flight_object["barcode"]["value"], PASS_STRS[0]);
+}
+
+#[test]
+fn ndef_record() {
+    let pass_data = Bcbp::from_str(PASS_STRS[3]).unwrap();
+    let record = pass_data.to_ndef_record().unwrap();
+    let text = PASS_STRS[3].as_bytes();
+    assert_eq!(&record[.. 4], &[0xD1, 0x01, (text.len() + 3) as u8, b'T']);
+    assert_eq!(&record[4 .. 7], b"\x02en");
+    assert_eq!(&record[7 ..], text);
+
+    // Long records encode the payload length in four bytes.
+    let pass_data = Bcbp::from_str(PASS_STRS[1]).unwrap();
+    let record = pass_data.to_ndef_record().unwrap();
+    let text = PASS_STRS[1].as_bytes();
+    assert_eq!(&record[.. 2], &[0xC1, 0x01]);
+    assert_eq!(&record[2 .. 6], &((text.len() + 3) as u32).to_be_bytes());
+    assert_eq!(&record[10 ..], text);
+}
+
+#[test]
+fn capacity_estimate() {
+    for pass_str in PASS_STRS {
+        let pass_data = Bcbp::from_str(pass_str).unwrap();
+        assert_eq!(pass_data.encoded_len(), Ok(pass_str.len()));
+
+        // The computed length tracks the encoder as sections are emptied.
+        let mut pass_data = pass_data.without_security_data();
+        pass_data.legs_mut()[0].set_airline_individual_use(None).unwrap();
+        pass_data.set_baggage_tag_license_plate_numbers(None).unwrap();
+        assert_eq!(pass_data.encoded_len(), Ok(to_string(&pass_data).unwrap().len()));
+    }
+
+    // 60 bytes require 2 + 50 data codewords, plus 32 at error correction level 4.
+    let pass_data = Bcbp::from_str(PASS_STRS[0]).unwrap().without_security_data();
+    assert_eq!(pass_data.encoded_len(), Ok(60));
+    assert_eq!(pass_data.fits_in_pdf417(1, 4), Ok(true));
+    assert_eq!(pass_data.fits_in_pdf417(1, 5), Ok(false));
+    assert_eq!(pass_data.fits_in_pdf417(0, 4), Ok(false));
+    assert_eq!(pass_data.fits_in_pdf417(10, 9), Ok(false));
+
+    // The two-leg example with security data exceeds a single column.
+    let pass_data = Bcbp::from_str(PASS_STRS[1]).unwrap();
+    assert_eq!(pass_data.fits_in_pdf417(1, 2), Ok(false));
+    assert_eq!(pass_data.fits_in_pdf417(6, 5), Ok(true));
+}
+
+#[test]
+fn length_fields_computed_from_content() {
+    for pass_str in PASS_STRS {
+        let mut pass_data = Bcbp::from_str(pass_str).unwrap();
+        if pass_data.version_number().is_none() {
+            pass_data.set_version_number(Some('6')).unwrap();
+        }
+        for leg in pass_data.legs_mut() {
+            leg.set_airline_individual_use(Some("ABCDEFGHIJ")).unwrap();
+        }
+        pass_data.set_security_data('1', "XYZ").unwrap();
+
+        // Lengths are consistent with the content if the encoded pass parses back.
+        let encoded = to_string(&pass_data).unwrap();
+        assert!(encoded.ends_with("^103XYZ"));
+        let reparsed_data = Bcbp::from_str(&encoded).unwrap();
+        assert_eq!(reparsed_data.legs(), pass_data.legs());
+        assert_eq!(to_string(&reparsed_data).unwrap(), encoded);
+        assert_eq!(reparsed_data.security_data().declared_len(), Some(3));
+    }
+
+    // Item 6, Item 10 and Item 17 of the Attachment B two-leg example shrink with the content.
+    // Fields preceding others which are present are retained as spaces.
+    let mut pass_data = Bcbp::from_str(PASS_STRS[1]).unwrap().without_security_data();
+    pass_data.set_baggage_tag_license_plate_numbers(None).unwrap();
+    pass_data.legs_mut()[0].set_frequent_flyer_number(None).unwrap();
+    assert_eq!(
+        to_string(&pass_data).unwrap(),
+        "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 140>60B1WW6225BAC 2A0141234567890 1AC AC                  20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ"
+    );
+}
+
+#[test]
+fn conditional_data_requires_version_number() {
+    // A version number is not invented to introduce conditional data added to a pass without one.
+    let mut pass_data = Bcbp::from_str(PASS_STRS[0]).unwrap().without_security_data();
+    assert_eq!(pass_data.version_number(), None);
+    pass_data.set_source_of_check_in(Some('W')).unwrap();
+    assert_eq!(to_string(&pass_data), Err(Error::MissingField(Field::VersionNumber)));
+    assert_eq!(pass_data.encoded_len(), Err(Error::MissingField(Field::VersionNumber)));
+
+    let errors = to_string_checked(&pass_data).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!((errors[0].field(), errors[0].leg_index()), (Field::VersionNumber, Some(0)));
+
+    pass_data.set_version_number(Some('6')).unwrap();
+    let encoded = to_string(&pass_data).unwrap();
+    assert!(encoded.ends_with(" 106>602 W"));
+    assert_eq!(Bcbp::from_str(&encoded).unwrap().version_number(), Some('6'));
+}
+
+#[test]
+fn more_legs_than_permitted() {
+    // The parser accepts every leg count the Number of Legs Encoded field can represent.
+    let leg = "ABC123 YULFRAAC 0834 326J001A0025 100";
+    let pass_data = Bcbp::from_str(&format!("M5DESMARAIS/LUC       E{}", leg.repeat(5))).unwrap();
+    assert_eq!(pass_data.legs().len(), 5);
+    assert_eq!(to_string(&pass_data), Err(Error::LengthOverflow(Field::NumberOfLegsEncoded)));
+    assert_eq!(to_string_mandatory(&pass_data), Err(Error::LengthOverflow(Field::NumberOfLegsEncoded)));
+}
+
+#[test]
+fn mandatory_only() {
+    let pass_data = Bcbp::from_str(PASS_STRS[1]).unwrap();
+    assert_eq!(
+        to_string_mandatory(&pass_data).unwrap(),
+        "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 100DEF456 FRAGVALH 3664 227C012C0002 100"
+    );
+
+    let pass_data = Bcbp::from_str(PASS_STRS[6]).unwrap();
+    assert_eq!(
+        to_string_mandatory(&pass_data).unwrap(),
+        "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 300"
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn json_export() {
+    let pass_data = Bcbp::from_str(PASS_STRS[6]).unwrap();
+    let json = pass_data.to_json();
+    assert_eq!(json["passengerName"], "MROZ/MARTIN");
+    assert_eq!(json["versionNumber"], "2");
+    assert_eq!(json["airlineDesignatorOfBoardingPassIssuer"], "AS");
+    assert!(json.get("securityData").is_none());
+
+    let leg = &json["legs"][0];
+    assert_eq!(leg["operatingCarrierPnrCode"], "XXXXXX");
+    assert_eq!(leg["seatNumber"], "001A");
+    assert_eq!(leg["frequentFlyerNumber"], "XXXXX55200000000");
+    assert_eq!(leg["airlineIndividualUse"], "Z29  00010");
+    assert!(leg.get("fastTrack").is_none());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn json_import() {
+    for pass_str in PASS_STRS {
+        let pass_data = Bcbp::from_str(pass_str).unwrap();
+        let imported_data = Bcbp::from_json(&pass_data.to_json().to_string()).unwrap();
+        assert_eq!(imported_data.to_json(), pass_data.to_json());
+    }
+
+    let pass_data = Bcbp::from_json(r#"{
+        "passengerName": "DESMARAIS/LUC",
+        "electronicTicketIndicator": "E",
+        "legs": [{
+            "operatingCarrierPnrCode": "ABC123", "fromCityAirportCode": "YUL", "toCityAirportCode": "FRA",
+            "operatingCarrierDesignator": "AC", "flightNumber": "0834", "dateOfFlight": "326",
+            "compartmentCode": "J", "seatNumber": "001A", "checkInSequenceNumber": "0025",
+            "passengerStatus": "1", "fastTrack": null
+        }]
+    }"#).unwrap();
+    assert_eq!(to_string(&pass_data).unwrap(), "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100");
+
+    assert_eq!(Bcbp::from_json(r#"{"legs": [{"seatNumber": 12}]}"#), Err(Error::InvalidFieldValue(Field::SeatNumber)));
+    assert_eq!(Bcbp::from_json(r#"{"seat": "012A"}"#), Err(Error::UnknownField));
+    assert_eq!(Bcbp::from_json(r#"{"legs": []}"#), Err(Error::InvalidFieldValue(Field::NumberOfLegsEncoded)));
+    assert_eq!(Bcbp::from_json(r#"{"legs": [{}, {}, {}, {}]}"#).map(|pass| pass.legs().len()), Ok(4));
+    assert_eq!(Bcbp::from_json(r#"{"legs": [{}, {}, {}, {}, {}, {}]}"#), Err(Error::LengthOverflow(Field::NumberOfLegsEncoded)));
+    assert_eq!(Bcbp::from_json("[]"), Err(Error::UnsupportedFormat));
+    assert!(Bcbp::from_json("{").is_err());
+}
+
+#[test]
+fn binary_airline_individual_use() {
+    let mut pass_data = Bcbp::from_str(PASS_STRS[6]).unwrap();
+    assert_eq!(pass_data.first_leg().airline_individual_use_bytes(), Some(&b"Z29  00010"[..]));
+
+    // Control characters are preserved byte-for-byte through encoding and parsing.
+    const DATA: &[u8] = b"\x00\x01\x1F^>\x7F";
+    pass_data.legs_mut()[0].set_airline_individual_use(Some(std::str::from_utf8(DATA).unwrap())).unwrap();
+    let reparsed_data = Bcbp::from_str(&to_string(&pass_data).unwrap()).unwrap();
+    assert_eq!(reparsed_data.first_leg().airline_individual_use_bytes(), Some(DATA));
+}
+
+#[test]
+fn all_build_errors() {
+    let pass_data = Bcbp::from_str(PASS_STRS[2]).unwrap();
+    assert_eq!(to_string_checked(&pass_data), Ok(String::from(PASS_STRS[2])));
+
+    // Every field which cannot be encoded is reported, rather than only the first.
+    let mut pass_data = Bcbp::from_str(&PASS_STRS[2].replace("FRARLGLH 4010", "FR4RLGLH     ")).unwrap();
+    pass_data.legs_mut()[0].set_airline_individual_use(Some(&"X".repeat(256))).unwrap();
+    let errors = to_string_checked(&pass_data).unwrap_err();
+    let errors: Vec<_> = errors.iter().map(|error| (error.field(), error.leg_index(), error.error().clone())).collect();
+    assert_eq!(errors, vec![
+        (Field::FlightNumber, Some(0), Error::MissingField(Field::FlightNumber)),
+        (Field::FromCityAirportCode, Some(0), Error::InvalidFieldValue(Field::FromCityAirportCode)),
+        (Field::FieldSizeOfVariableSizeField, Some(0), Error::LengthOverflow(Field::FieldSizeOfVariableSizeField)),
+    ]);
+
+    let error = to_string_checked(&pass_data).unwrap_err().remove(0);
+    assert_eq!(error.to_string(), "leg 1: Flight Number is not set");
+}
+
+#[test]
+fn encode_to_writer() {
+    for pass_str in PASS_STRS {
+        let pass_data = Bcbp::from_str(pass_str).unwrap();
+        let mut output = String::from("data:");
+        pass_data.encode_to(&mut output).unwrap();
+        assert_eq!(output, format!("data:{}", pass_str));
+
+        let mut output = Vec::new();
+        pass_data.encode_to_writer(&mut output).unwrap();
+        assert_eq!(output, pass_str.as_bytes());
+    }
+
+    // Nothing is written if the boarding pass cannot be encoded.
+    let mut pass_data = Bcbp::from_str(PASS_STRS[1]).unwrap();
+    pass_data.legs_mut()[0].set_airline_individual_use(Some(&"X".repeat(256))).unwrap();
+    let mut output = String::new();
+    assert_eq!(
+        pass_data.encode_to(&mut output),
+        Err(Error::LengthOverflow(Field::FieldSizeOfVariableSizeField))
+    );
+    assert!(output.is_empty());
+    let mut output = Vec::new();
+    let error = pass_data.encode_to_writer(&mut output).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(output.is_empty());
+
+    // Failures of the destination are reported as-is.
+    let pass_data = Bcbp::from_str(PASS_STRS[0]).unwrap();
+    let mut output = [0; 16];
+    let error = pass_data.encode_to_writer(&mut &mut output[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+    assert_eq!(&output, b"M1DESMARAIS/LUC ");
+}
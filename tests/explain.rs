@@ -0,0 +1,92 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering byte-range annotation of boarding pass data.
+
+extern crate iata_bcbp;
+
+use iata_bcbp::*;
+
+#[test]
+fn explain_valid_pass() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let annotations = explain(PASS_STR);
+    assert!(annotations.iter().all(|annotation| annotation.is_valid()));
+
+    let fields: Vec<_> = annotations.iter().map(|annotation| annotation.field()).collect();
+    assert_eq!(fields.len(), 19);
+    assert_eq!(fields[.. 4], [Field::FormatCode, Field::NumberOfLegsEncoded, Field::PassengerName, Field::ElectronicTicketIndicator]);
+    assert_eq!(fields[18], Field::SecurityData);
+
+    // Annotations are contiguous and cover the input.
+    for pair in annotations.windows(2) {
+        assert_eq!(pair[0].range().end, pair[1].range().start);
+    }
+    assert_eq!(annotations.last().unwrap().range().end, PASS_STR.len());
+
+    let seat_number = annotations.iter().find(|annotation| annotation.field() == Field::SeatNumber).unwrap();
+    assert_eq!(seat_number.range(), 48 .. 52);
+    assert_eq!(seat_number.value(), "001A");
+}
+
+#[test]
+fn explain_conditional_sections() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let annotations = explain(PASS_STR);
+    assert!(annotations.iter().all(|annotation| annotation.is_valid()));
+
+    let last = annotations.last().unwrap();
+    assert_eq!(last.field(), Field::AirlineIndividualUse);
+    assert_eq!(last.value(), "Z29  00010");
+    assert_eq!(last.range().end, PASS_STR.len());
+}
+
+#[test]
+fn explain_invalid_pass() {
+    // The pass is explained up to the invalid beginning of security data.
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100+100";
+    let annotations = explain(PASS_STR);
+    let last = annotations.last().unwrap();
+    assert_eq!(last.field(), Field::BeginningOfSecurityData);
+    assert_eq!(last.range(), 60 .. 61);
+    assert_eq!(last.value(), "+");
+    assert!(!last.is_valid());
+    assert!(annotations[.. annotations.len() - 1].iter().all(|annotation| annotation.is_valid()));
+
+    // Truncated fields cover the remaining input.
+    const PASS_STR_TRUNCATED: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J0";
+    let last = explain(PASS_STR_TRUNCATED).pop().unwrap();
+    assert_eq!(last.field(), Field::SeatNumber);
+    assert_eq!(last.range(), 48 .. 49);
+    assert!(!last.is_valid());
+
+    // Invalid data formats are reported without ending the annotation.
+    const PASS_STR_FORMAT: &str = "M1DESMARAIS/LUC       EABC123 Y1LFRAAC 0834 326J001A0025 100";
+    let annotations = explain(PASS_STR_FORMAT);
+    assert_eq!(annotations.len(), 15);
+    assert_eq!(annotations[5].field(), Field::FromCityAirportCode);
+    assert!(!annotations[5].is_valid());
+    assert_eq!(annotations.iter().filter(|annotation| !annotation.is_valid()).count(), 1);
+}
+
+#[test]
+fn token_spans() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010^100";
+    let pass_spans: Vec<_> = spans(PASS_STR).collect();
+    assert_eq!(pass_spans.first(), Some(&(0 .. 1, TokenKind::MandatoryField)));
+    assert_eq!(pass_spans[14], (58 .. 60, TokenKind::LengthField));
+    assert_eq!(pass_spans[15], (60 .. 61, TokenKind::ConditionalField));
+    assert!(pass_spans.contains(&(127 .. 137, TokenKind::AirlineIndividualUse)));
+    assert_eq!(pass_spans.last(), Some(&(139 .. 141, TokenKind::LengthField)));
+
+    // Spans cover the input contiguously, even past the point of failure.
+    const PASS_STR_INVALID: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100+100";
+    let invalid_spans: Vec<_> = spans(PASS_STR_INVALID).collect();
+    for pair in invalid_spans.windows(2) {
+        assert_eq!(pair[0].0.end, pair[1].0.start);
+    }
+    assert_eq!(invalid_spans[invalid_spans.len() - 2], (60 .. 61, TokenKind::Invalid));
+    assert_eq!(invalid_spans[invalid_spans.len() - 1], (61 .. 64, TokenKind::Unknown));
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the public field metadata.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn all_fields_in_item_number_order() {
+    let fields = Field::all();
+    assert_eq!(fields.len(), 43);
+    assert_eq!(fields.first(), Some(&Field::FormatCode));
+    assert_eq!(fields.last(), Some(&Field::FastTrack));
+
+    // Item numbers are unique and strictly ascending.
+    for pair in fields.windows(2) {
+        assert!(pair[0].item_number() < pair[1].item_number());
+    }
+}
+
+#[test]
+fn field_metadata() {
+    assert_eq!(Field::PassengerName.item_number(), 11);
+    assert_eq!(Field::PassengerName.len(), 20);
+    assert_eq!(Field::PassengerName.name(), "Passenger Name");
+    assert_eq!(Field::PassengerName.data_format(), "f");
+
+    assert_eq!(Field::FlightNumber.item_number(), 43);
+    assert_eq!(Field::FlightNumber.len(), 5);
+    assert_eq!(Field::FlightNumber.data_format(), "NNNN[a]");
+
+    assert_eq!(Field::SecurityData.item_number(), 30);
+    assert_eq!(Field::SecurityData.len(), 0);
+
+    assert_eq!(Field::IdAdIndicator.to_string(), "ID/AD Indicator");
+
+    // Metadata is available in constant expressions.
+    const PASSENGER_NAME_LEN: usize = Field::PassengerName.len();
+    const FLIGHT_NUMBER_FORMAT: &str = Field::FlightNumber.data_format();
+    let buffer = [b' '; PASSENGER_NAME_LEN];
+    assert_eq!(buffer.len(), 20);
+    assert_eq!(FLIGHT_NUMBER_FORMAT, "NNNN[a]");
+}
+
+#[test]
+fn field_lookup() {
+    assert_eq!(Field::from_item_number(104), Some(Field::SeatNumber));
+    assert_eq!(Field::from_item_number(253), Some(Field::ElectronicTicketIndicator));
+    assert_eq!(Field::from_item_number(2), None);
+
+    assert_eq!(Field::from_str("Seat Number"), Ok(Field::SeatNumber));
+    assert_eq!(Field::from_str("operating carrier pnr code"), Ok(Field::OperatingCarrierPnrCode));
+    assert_eq!(Field::from_str("Seat"), Err(Error::UnknownField));
+
+    // Every field round-trips through its name and item number.
+    for &field in Field::all() {
+        assert_eq!(Field::from_str(field.name()), Ok(field));
+        assert_eq!(Field::from_item_number(field.item_number()), Some(field));
+    }
+}
+
+#[test]
+fn dynamic_field_access() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.field(Field::PassengerName), Some("MROZ/MARTIN         "));
+    assert_eq!(pass_data.field(Field::VersionNumber), Some("2"));
+    assert_eq!(pass_data.field(Field::SecurityData), None);
+    assert_eq!(pass_data.field(Field::SeatNumber), None);
+
+    let leg = pass_data.first_leg();
+    assert_eq!(leg.field(Field::SeatNumber), Some("001A"));
+    assert_eq!(leg.field(Field::CompartmentCode), Some("U"));
+    assert_eq!(leg.field(Field::SelecteeIndicator), Some("5"));
+    assert_eq!(leg.field(Field::FastTrack), None);
+    assert_eq!(leg.field(Field::PassengerName), None);
+}
+
+#[test]
+fn index_by_field() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(&pass_data[Field::PassengerName], "MROZ/MARTIN         ");
+    assert_eq!(&pass_data.first_leg()[Field::SeatNumber], "001A");
+}
+
+#[test]
+#[should_panic(expected = "Fast Track is not present in the leg")]
+fn index_by_missing_field() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let _ = &pass_data.first_leg()[Field::FastTrack];
+}
+
+#[test]
+fn blank_field_values() {
+    const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.baggage_tag_license_plate_numbers(), Some("             "));
+    assert_eq!(pass_data.field_value(Field::BaggageTagLicensePlateNumbers), None);
+    assert_eq!(pass_data.field_value(Field::AirlineDesignatorOfBoardingPassIssuer), Some("LH "));
+
+    let leg = pass_data.first_leg();
+    assert_eq!(leg.field_value(Field::FrequentFlyerNumber), None);
+    assert_eq!(leg.field_value(Field::InternationalDocumentVerification), None);
+    assert_eq!(leg.field_value(Field::FastTrack), None);
+    assert_eq!(leg.field_value(Field::FlightNumber), Some("4010 "));
+}
+
+#[cfg(feature = "compat")]
+#[test]
+fn field_map() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^108SIGNATUR";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    let pass_fields = pass_data.to_field_map();
+    assert_eq!(pass_fields.len(), 4);
+    assert_eq!(pass_fields[&Field::PassengerName], "DESMARAIS/LUC       ");
+    assert_eq!(pass_fields[&Field::ElectronicTicketIndicator], "E");
+    assert_eq!(pass_fields[&Field::TypeOfSecurityData], "1");
+    assert_eq!(pass_fields[&Field::SecurityData], "SIGNATUR");
+
+    let leg_fields = pass_data.first_leg().to_field_map();
+    assert_eq!(leg_fields.len(), 10);
+    assert_eq!(leg_fields[&Field::FlightNumber], "0834 ");
+    assert_eq!(leg_fields[&Field::PassengerStatus], "1");
+}
+
+#[test]
+fn localized_names() {
+    assert_eq!(Field::SeatNumber.localized_name(Language::English), "Seat Number");
+    assert_eq!(Field::SeatNumber.localized_name(Language::French), "Numéro de siège");
+    assert_eq!(Field::SeatNumber.localized_name(Language::German), "Sitzplatznummer");
+    assert_eq!(Field::SeatNumber.localized_name(Language::Spanish), "Número de asiento");
+
+    // Every field has a distinct name in every language.
+    for &language in &[Language::English, Language::French, Language::German, Language::Spanish] {
+        let mut names: Vec<_> = Field::all().iter().map(|field| field.localized_name(language)).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), Field::all().len());
+    }
+}
+
+#[test]
+fn dump() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let dump = pass_data.dump();
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(lines.len(), 13);
+    assert!(lines[0].starts_with(" 11  Passenger Name "));
+    assert!(lines[0].ends_with("  'DESMARAIS/LUC       '"));
+    assert_eq!(lines[2], "Leg 1");
+    assert!(lines[3].starts_with("  7  Operating Carrier PNR Code "));
+
+    // Values are aligned in a single column.
+    let value_column = lines[0].find('\'').unwrap();
+    assert!(lines.iter().filter(|line| line.contains('\'')).all(|line| line.find('\'') == Some(value_column)));
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering parsing without allocation into fixed-size storage.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn consistent_with_from_str() {
+    const PASS_STRS: &[&str] = &[
+        "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+        "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+        "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010",
+        "M1TEST/PETER          E24Z5RN AMSBRUKL 1733 019M008A0001 316>503  W0D0742497067621^100",
+    ];
+
+    for &pass_str in PASS_STRS {
+        let pass_data = Bcbp::from_str(pass_str).unwrap();
+        let fixed_pass_data = BcbpFixed::<4>::parse(pass_str).unwrap();
+        assert_eq!(fixed_pass_data.leg_count(), pass_data.leg_count());
+        for &field in Field::all() {
+            assert_eq!(fixed_pass_data.field(field), pass_data.field(field), "{}", field);
+            for (leg_index, leg) in pass_data.legs().iter().enumerate() {
+                assert_eq!(fixed_pass_data.leg_field(leg_index, field), leg.field(field), "{}", field);
+            }
+        }
+        assert_eq!(fixed_pass_data.type_of_security_data(), pass_data.security_data().type_of_security_data());
+        assert_eq!(
+            fixed_pass_data.security_data(),
+            pass_data.security_data().security_data().map(str::as_bytes)
+        );
+        assert_eq!(fixed_pass_data.to_bcbp(), pass_data);
+    }
+}
+
+#[test]
+fn invalid_input() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ";
+
+    // Legs beyond the fixed capacity are not stored.
+    assert_eq!(
+        BcbpFixed::<1>::parse(PASS_STR).unwrap_err(),
+        Error::LengthOverflow(Field::NumberOfLegsEncoded)
+    );
+
+    // Errors agree with those of the allocating parser.
+    for input in &[&PASS_STR[.. 100], "M1ABC", "X1", "M0", "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100 "] {
+        match (BcbpFixed::<4>::parse(input).unwrap_err(), Bcbp::from_str(input).unwrap_err()) {
+            (Error::UnexpectedEndOfInput(fixed_failure), Error::UnexpectedEndOfInput(failure)) |
+            (Error::ParseFailed(fixed_failure), Error::ParseFailed(failure)) => {
+                assert_eq!(fixed_failure.field(), failure.field());
+                assert_eq!(fixed_failure.offset(), failure.offset());
+            }
+            (fixed_error, error) =>
+                assert_eq!(fixed_error, error),
+        }
+    }
+}
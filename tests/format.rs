@@ -0,0 +1,185 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering boarding passes as a serde data format.
+
+#![cfg(feature = "serde")]
+
+extern crate iata_bcbp;
+extern crate serde;
+
+use std::collections::BTreeMap;
+
+use iata_bcbp::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Segment<'a> {
+    #[serde(rename = "FromCityAirportCode")]
+    origin: &'a str,
+    #[serde(rename = "ToCityAirportCode")]
+    destination: &'a str,
+    #[serde(rename = "FlightNumber")]
+    flight: u16,
+    #[serde(rename = "CompartmentCode")]
+    compartment: char,
+    #[serde(rename = "FrequentFlyerNumber", default)]
+    frequent_flyer_number: Option<&'a str>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Trip<'a> {
+    #[serde(rename = "PassengerName")]
+    name: &'a str,
+    #[serde(rename = "VersionNumber")]
+    version: Option<u8>,
+    #[serde(rename = "Legs", borrow)]
+    segments: Vec<Segment<'a>>,
+}
+
+#[test]
+fn deserialize_struct() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let trip: Trip = from_str_as(PASS_STR).unwrap();
+    assert_eq!(trip, Trip {
+        name: "DESMARAIS/LUC       ",
+        version: Some(6),
+        segments: vec![
+            Segment {
+                origin: "YUL",
+                destination: "FRA",
+                flight: 834,
+                compartment: 'F',
+                frequent_flyer_number: Some("1234567890123   "),
+            },
+            Segment {
+                origin: "FRA",
+                destination: "GVA",
+                flight: 3664,
+                compartment: 'C',
+                frequent_flyer_number: Some("1234567890123   "),
+            },
+        ],
+    });
+
+    // Fields not present, or entirely spaces, deserialize as absent options.
+    const MANDATORY_PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let trip: Trip = from_str_as(MANDATORY_PASS_STR).unwrap();
+    assert_eq!(trip.version, None);
+    assert_eq!(trip.segments[0].frequent_flyer_number, None);
+
+    // Values which cannot be deserialized as the requested type identify the field.
+    let error = from_str_as::<Trip>(&MANDATORY_PASS_STR.replace("0834", "83A ")).unwrap_err();
+    assert_eq!(error, Error::InvalidFieldValue(Field::FlightNumber));
+    assert!(from_str_as::<Trip>("M1DESMARAIS/LUC").is_err());
+}
+
+#[test]
+fn deserialize_map() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let fields: BTreeMap<String, serde::de::IgnoredAny> = from_str_as(PASS_STR).unwrap();
+    assert_eq!(fields.keys().collect::<Vec<_>>(), vec!["ElectronicTicketIndicator", "Legs", "PassengerName"]);
+
+    #[derive(Deserialize)]
+    struct Pass {
+        #[serde(rename = "Legs")]
+        legs: Vec<BTreeMap<String, String>>,
+    }
+    let pass: Pass = from_str_as(PASS_STR).unwrap();
+    assert_eq!(pass.legs[0].len(), 10);
+    assert_eq!(pass.legs[0]["SeatNumber"], "001A");
+}
+
+#[test]
+fn serialize_struct() {
+    #[derive(Clone, Copy, Serialize)]
+    struct Segment {
+        #[serde(rename = "OperatingCarrierPnrCode")]
+        pnr: &'static str,
+        #[serde(rename = "FromCityAirportCode")]
+        origin: &'static str,
+        #[serde(rename = "ToCityAirportCode")]
+        destination: &'static str,
+        #[serde(rename = "OperatingCarrierDesignator")]
+        carrier: &'static str,
+        #[serde(rename = "FlightNumber")]
+        flight: u16,
+        #[serde(rename = "DateOfFlight")]
+        date: u16,
+        #[serde(rename = "CompartmentCode")]
+        compartment: char,
+        #[serde(rename = "SeatNumber")]
+        seat: &'static str,
+        #[serde(rename = "CheckInSequenceNumber")]
+        sequence: u16,
+        #[serde(rename = "PassengerStatus")]
+        status: char,
+        #[serde(rename = "AirlineNumericCode")]
+        airline_numeric_code: Option<u16>,
+    }
+
+    #[derive(Serialize)]
+    struct Trip {
+        #[serde(rename = "PassengerName")]
+        name: &'static str,
+        #[serde(rename = "ElectronicTicketIndicator")]
+        electronic_ticket: char,
+        #[serde(rename = "VersionNumber")]
+        version: Option<char>,
+        #[serde(rename = "Legs")]
+        segments: Vec<Segment>,
+    }
+
+    let segment = Segment {
+        pnr: "ABC123",
+        origin: "YUL",
+        destination: "FRA",
+        carrier: "AC",
+        flight: 834,
+        date: 326,
+        compartment: 'J',
+        seat: "001A",
+        sequence: 25,
+        status: '1',
+        airline_numeric_code: None,
+    };
+    let mut trip = Trip { name: "DESMARAIS/LUC", electronic_ticket: 'E', version: None, segments: vec![segment] };
+    assert_eq!(to_string_from(&trip).unwrap(), "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100");
+
+    // Numeric fields are zero-padded, and values are validated as by their setters.
+    trip.segments[0].airline_numeric_code = Some(14);
+    assert_eq!(to_string_from(&trip), Err(Error::MissingField(Field::VersionNumber)));
+    trip.version = Some('6');
+    assert!(to_string_from(&trip).unwrap().ends_with(" 109>60003014"));
+    trip.segments = vec![trip.segments[0]; 5];
+    assert_eq!(to_string_from(&trip), Err(Error::LengthOverflow(Field::NumberOfLegsEncoded)));
+    trip.segments.truncate(1);
+    trip.segments[0].origin = "Y1L";
+    assert_eq!(to_string_from(&trip), Err(Error::InvalidFieldValue(Field::FromCityAirportCode)));
+    trip.segments.clear();
+    assert_eq!(to_string_from(&trip), Err(Error::InvalidFieldValue(Field::NumberOfLegsEncoded)));
+    assert_eq!(to_string_from(&[("Name", "DESMARAIS/LUC")]), Err(Error::Custom(String::from("expected a map or structure"))));
+}
+
+#[test]
+fn round_trip() {
+    #[derive(Deserialize, Serialize)]
+    struct Pass {
+        #[serde(flatten)]
+        fields: BTreeMap<String, String>,
+        #[serde(rename = "Legs")]
+        legs: Vec<BTreeMap<String, String>>,
+    }
+
+    const PASS_STRS: &[&str] = &[
+        "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+        "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+        "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010",
+    ];
+    for pass_str in PASS_STRS {
+        let pass: Pass = from_str_as(pass_str).unwrap();
+        assert_eq!(&to_string_from(&pass).unwrap(), pass_str);
+    }
+}
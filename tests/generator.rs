@@ -0,0 +1,108 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering generation of random boarding passes.
+
+#![cfg(feature = "generator")]
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::generator::Generator;
+use iata_bcbp::*;
+
+#[test]
+fn generated_passes_round_trip_and_validate() {
+    let profiles = [
+        ValidationProfile::V2,
+        ValidationProfile::V3,
+        ValidationProfile::V5,
+        ValidationProfile::V6,
+        ValidationProfile::V7,
+        ValidationProfile::V8,
+    ];
+    for &profile in profiles.iter() {
+        for pass_data in Generator::new(7).with_version(profile).take(50) {
+            let encoded = to_string(&pass_data).unwrap();
+            assert_eq!(to_string(&Bcbp::from_str(&encoded).unwrap()).unwrap(), encoded);
+
+            let report = pass_data.validate(&profile);
+            assert!(report.findings().is_empty(), "{}: {:?}", encoded, report.findings());
+            assert_eq!(pass_data.version_number(), Some(profile.version_number()));
+        }
+    }
+}
+
+#[test]
+fn leg_count() {
+    for leg_count in 1 ..= 4 {
+        let pass_data = Generator::new(leg_count as u64).with_leg_count(leg_count).generate();
+        assert_eq!(pass_data.legs().len(), leg_count);
+        assert!(to_string(&pass_data).is_ok());
+    }
+
+    let pass_data = Generator::new(3).with_leg_count(4).generate();
+    for pair in pass_data.legs().windows(2) {
+        assert_eq!(pair[0].to_city_airport_code(), pair[1].from_city_airport_code());
+    }
+}
+
+#[test]
+#[should_panic]
+fn leg_count_out_of_range() {
+    Generator::new(0).with_leg_count(5);
+}
+
+#[test]
+fn deterministic_for_seed() {
+    let first: Vec<Bcbp> = Generator::new(1234).take(10).collect();
+    let second: Vec<Bcbp> = Generator::new(1234).take(10).collect();
+    assert_eq!(first, second);
+    assert_ne!(first, Generator::new(1235).take(10).collect::<Vec<_>>());
+}
+
+#[test]
+fn optional_field_density() {
+    let sparse = Generator::new(5).with_version(ValidationProfile::V5).with_optional_field_density(0.0).generate();
+    assert_eq!(sparse.passenger_description(), None);
+    assert_eq!(sparse.baggage_tag_license_plate_numbers(), None);
+    assert_eq!(sparse.legs()[0].selectee_indicator(), None);
+    assert_eq!(sparse.legs()[0].fast_track(), None);
+
+    let dense = Generator::new(5).with_version(ValidationProfile::V5).with_optional_field_density(1.0).generate();
+    assert!(dense.passenger_description().is_some());
+    assert!(dense.baggage_tag_license_plate_numbers().is_some());
+    assert!(dense.legs()[0].selectee_indicator().is_some());
+    assert!(dense.legs()[0].fast_track().is_some());
+}
+
+#[test]
+fn realistic_data() {
+    const AIRPORTS: &[&str] = &["JFK", "LHR", "YUL", "FRA", "SFO", "NRT", "SIN", "CDG"];
+    const AIRLINES: &[(&str, &str)] = &[("AC", "014"), ("BA", "125"), ("LH", "220"), ("UA", "016")];
+
+    let generator = Generator::new(99).with_realistic_data(true).with_optional_field_density(1.0);
+    let mut seen_airport = false;
+    let mut seen_airline = false;
+    for pass_data in generator.take(100) {
+        let encoded = to_string(&pass_data).unwrap();
+        assert!(pass_data.validate(&ValidationProfile::V6).findings().is_empty(), "{}", encoded);
+        assert!(pass_data.passenger_name().contains('/'), "{}", encoded);
+
+        for leg in pass_data.legs() {
+            seen_airport |= AIRPORTS.contains(&leg.from_city_airport_code());
+
+            // The operating carrier and its airline numeric code are drawn together.
+            let designator = leg.operating_carrier_designator().trim();
+            if let Some(&(_, numeric_code)) = AIRLINES.iter().find(|(airline, _)| *airline == designator) {
+                assert_eq!(leg.airline_numeric_code(), Some(numeric_code), "{}", encoded);
+                seen_airline = true;
+            }
+        }
+    }
+    assert!(seen_airport);
+    assert!(seen_airline);
+}
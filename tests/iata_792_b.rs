@@ -34,6 +34,11 @@ fn example_1_m1_using_mandatory_elements_and_security_fields() {
 
     assert_eq!(pass_data.security_data().type_of_security_data(), Some('1'));
     assert_eq!(pass_data.security_data().security_data(), Some("GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE"));
+    assert!(pass_data.issuance_metadata().is_empty());
+    assert_eq!(pass_data.security_data().declared_len(), Some(100));
+    assert_eq!(pass_data.security_data().actual_len(), Some(100));
+    assert_eq!(pass_data.security_data().signed_range(), Some(0 .. 60));
+    assert!(pass_data.security_data().is_length_consistent());
 }
 
 #[test]
@@ -110,6 +115,9 @@ fn example_2_m2_multiple_legs() {
 
     assert_eq!(pass_data.security_data().type_of_security_data(), Some('1'));
     assert_eq!(pass_data.security_data().security_data(), Some("GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE"));
+    assert_eq!(pass_data.security_data().declared_len(), Some(100));
+    assert_eq!(pass_data.security_data().signed_range(), Some(0 .. 220));
+    assert!(pass_data.security_data().is_length_consistent());
 }
 
 #[test]
@@ -0,0 +1,61 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering incremental parsing of boarding pass data.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn progressive_chunks() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let mut parser = IncrementalParser::new();
+
+    match parser.push(&PASS_STR[.. 50]).unwrap() {
+        ParseProgress::Incomplete { parsed, required_from } => {
+            assert_eq!(required_from, 48);
+            assert_eq!(parsed.last().unwrap().field(), Field::CompartmentCode);
+        }
+        progress => panic!("unexpected progress {:?}", progress),
+    }
+
+    // The conditional section is required in its entirety.
+    match parser.push(&PASS_STR[50 .. 100]).unwrap() {
+        ParseProgress::Incomplete { required_from, .. } => assert_eq!(required_from, 58),
+        progress => panic!("unexpected progress {:?}", progress),
+    }
+
+    assert_eq!(
+        parser.push(&PASS_STR[100 ..]),
+        Ok(ParseProgress::Complete(Bcbp::from_str(PASS_STR).unwrap()))
+    );
+    assert_eq!(parser.buffer(), PASS_STR);
+}
+
+#[test]
+fn invalid_prefix() {
+    let mut parser = IncrementalParser::new();
+    assert!(matches!(parser.push(""), Ok(ParseProgress::Incomplete { required_from: 0, .. })));
+    assert!(matches!(parser.push("M"), Ok(ParseProgress::Incomplete { required_from: 1, .. })));
+    assert!(matches!(parser.push("X"), Err(Error::ParseFailed(_))));
+
+    let mut parser = IncrementalParser::new();
+    assert_eq!(parser.push("S1"), Err(Error::UnsupportedFormat));
+}
+
+#[test]
+fn single_bytes() {
+    // Pushing a byte at a time reports the same progress as pushing the input received so far at once.
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let mut parser = IncrementalParser::new();
+    for end in 1 ..= PASS_STR.len() {
+        let progress = parser.push(&PASS_STR[end - 1 .. end]);
+        assert_eq!(progress, IncrementalParser::new().push(&PASS_STR[.. end]), "after {} bytes", end);
+    }
+    assert_eq!(parser.push(""), Ok(ParseProgress::Complete(Bcbp::from_str(PASS_STR).unwrap())));
+}
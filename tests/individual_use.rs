@@ -0,0 +1,60 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering decoding of the Airline Individual Use section.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+/// Returns the data unchanged, for identifying the decoder used.
+struct Tagged(&'static str);
+
+impl IndividualUseDecoder for Tagged {
+    fn decode(&self, airline_individual_use: &str) -> Option<Vec<(&'static str, String)>> {
+        Some(vec![(self.0, String::from(airline_individual_use))])
+    }
+}
+
+#[test]
+fn decoded_for_issuing_carrier() {
+    // Issued by Alaska Airlines.
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let decoders = IndividualUseDecoders::new()
+        .with_decoder("AS", Tagged("Issuer"))
+        .with_decoder("UA", Tagged("Operating Carrier"));
+
+    pass_data.legs_mut()[0].set_operating_carrier_designator("UA").unwrap();
+    assert_eq!(
+        pass_data.decode_individual_use(0, &decoders),
+        Some(vec![("Issuer", String::from("Z29  00010"))])
+    );
+
+    // Without an issuer, the data is attributed to the operating carrier.
+    pass_data.set_airline_designator_of_boarding_pass_issuer(Some("   ")).unwrap();
+    assert_eq!(
+        pass_data.decode_individual_use(0, &decoders),
+        Some(vec![("Operating Carrier", String::from("Z29  00010"))])
+    );
+    assert_eq!(pass_data.decode_individual_use(1, &decoders), None);
+}
+
+#[test]
+fn registry_is_keyed_by_carrier() {
+    struct Upper;
+    impl IndividualUseDecoder for Upper {
+        fn decode(&self, airline_individual_use: &str) -> Option<Vec<(&'static str, String)>> {
+            Some(vec![("Data", airline_individual_use.to_uppercase())])
+        }
+    }
+
+    let decoders = IndividualUseDecoders::new().with_decoder("AS ", Upper);
+    assert_eq!(decoders.decode("AS", "z29"), Some(vec![("Data", String::from("Z29"))]));
+    assert_eq!(decoders.decode("AC", "*30600000K09         "), None);
+    assert_eq!(IndividualUseDecoders::new().decode("UA", "*30600000K09         "), None);
+}
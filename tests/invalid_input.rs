@@ -6,6 +6,8 @@
 //! Synthetic test cases with invalid boarding pass data.
 
 extern crate iata_bcbp;
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+extern crate serde_json;
 
 use std::str::FromStr;
 
@@ -60,18 +62,8 @@ fn invalid_start_of_security_data() {
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, using a '+' instead of '^' for start of security data.
     const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100+100";
     assert_eq!(
-        Bcbp::from_str(PASS_STR),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0:\n" +
-            "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100+100\n" +
-            "                                                            ^\n" +
-            "expected '^', found +\n" +
-            "\n" +
-            "1: at line 0, in Beginning of Security Data:\n" +
-            "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100+100\n" +
-            "                                                            ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR).unwrap_err().to_string(),
+        "Item 25 (Beginning of Security Data) at offset 60: expected '^', found '+'"
     );
 }
 
@@ -80,18 +72,8 @@ fn invalid_start_of_version_number() {
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, using a '+' instead of '>' for start of version number.
     const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D+6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100";
     assert_eq!(
-        Bcbp::from_str(PASS_STR),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0:\n" +
-            "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D+6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100\n" +
-            "                                                            ^\n" +
-            "expected \'>\', found +\n" +
-            "\n" +
-            "1: at line 0, in Beginning of Version Number:\n" +
-            "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D+6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100\n" +
-            "                                                            ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR).unwrap_err().to_string(),
+        "Item 8 (Beginning of Version Number) at offset 60: expected '>', found '+'"
     );
 }
 
@@ -100,33 +82,22 @@ fn expected_integer() {
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, with leg count 'X'.
     const PASS_STR_1: &str = "MXDESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^100+";
     assert_eq!(
-        Bcbp::from_str(PASS_STR_1),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0, in TakeWhileMN:\n" +
-            "MXDESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^100+\n" +
-            " ^\n" +
-            "\n" +
-            "1: at line 0, in Number of Legs Encoded:\n" +
-            "MXDESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^100+\n" +
-            " ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR_1).unwrap_err().to_string(),
+        "Item 5 (Number of Legs Encoded) at offset 1: invalid leg count 'X'"
     );
 
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, with security data length 'YY'.
     const PASS_STR_2: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1YY";
     assert_eq!(
-        Bcbp::from_str(PASS_STR_2),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0, in TakeWhileMN:\n" +
-            "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1YY\n" +
-            "                                                              ^\n" +
-            "\n" +
-            "1: at line 0, in Length of Security Data:\n" + 
-            "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1YY\n" +
-            "                                                              ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR_2).unwrap_err().to_string(),
+        "Item 29 (Length of Security Data) at offset 62: expected hexadecimal length, found 'YY'"
+    );
+
+    // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, with conditional size 'ZZ'.
+    const PASS_STR_3: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 1ZZ";
+    assert_eq!(
+        Bcbp::from_str(PASS_STR_3).unwrap_err().to_string(),
+        "Item 6 (Field Size of Variable Size Field) at offset 58: expected hexadecimal length, found 'ZZ'"
     );
 }
 
@@ -135,13 +106,8 @@ fn subsection_too_long() {
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, with an 'FF' long conditional.
     const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 1FF>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100";
     assert_eq!(
-        Bcbp::from_str(PASS_STR),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0, in Eof:\n" +
-            "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 1FF>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100\n" +
-            "                                                            ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR).unwrap_err().to_string(),
+        "unexpected end-of-input reading Item 6 (Field Size of Variable Size Field) at offset 58, 91 bytes missing"
     );
 }
 
@@ -150,28 +116,101 @@ fn unexpected_end_of_input() {
     // This is a complete and valid Type 'M' boarding pass from the IATA 792B examples, with a security data extending past end of input.
     const PASS_STR_SEC: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^101";
     assert_eq!(
-        Bcbp::from_str(PASS_STR_SEC),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0, in Eof:\n" +
-            "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^101\n" +
-            "                                                                                                                                                                                                                                ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR_SEC).unwrap_err().to_string(),
+        "unexpected end-of-input reading Item 30 (Security Data) at offset 224, 1 byte missing"
     );
 
     // This is an incomplete type M pass truncated half way through the name field.
     const PASS_STR_NAME: &str = "M2DESMARAIS";
     assert_eq!(
-        Bcbp::from_str(PASS_STR_NAME),
-        Err(Error::ParseFailed(String::new() +
-            "0: at line 0, in Eof:\n" +
-            "M2DESMARAIS\n" +
-            "  ^\n" +
-            "\n" +
-            "1: at line 0, in Passenger Name:\n" +
-            "M2DESMARAIS\n" +
-            "  ^\n" +
-            "\n"
-        ))
+        Bcbp::from_str(PASS_STR_NAME).unwrap_err().to_string(),
+        "unexpected end-of-input reading Item 11 (Passenger Name) at offset 2, 11 bytes missing"
+    );
+}
+
+#[test]
+fn failure_location() {
+    // This is the IATA 792B example pass truncated within the seat number.
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J0";
+    match Bcbp::from_str(PASS_STR) {
+        Err(Error::UnexpectedEndOfInput(failure)) => {
+            assert_eq!(failure.field(), Field::SeatNumber);
+            assert_eq!(failure.offset(), 48);
+            assert_eq!(failure.found(), "0???");
+            assert_eq!(failure.missing_len(), Some(3));
+            assert_eq!(failure.to_string(), "Item 104 (Seat Number) at offset 48: expected 'NNNa', found '0???'");
+
+            assert_eq!(failure.trace().len(), 1);
+            assert_eq!(failure.trace()[0].field(), Field::SeatNumber);
+            assert_eq!(failure.trace()[0].offset(), 48);
+            assert_eq!(failure.trace()[0].kind(), "Eof");
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    // Failures within the security data section trace the field size and the data.
+    const PASS_STR_SEC: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1YY";
+    match Bcbp::from_str(PASS_STR_SEC) {
+        Err(Error::ParseFailed(failure)) => {
+            let frames: Vec<_> = failure.trace().iter()
+                .map(|frame| (frame.field(), frame.offset(), frame.kind()))
+                .collect();
+            assert_eq!(frames, vec![(Field::LengthOfSecurityData, 62, "TakeWhileMN")]);
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn truncated_variable_size_section() {
+    // The unique conditional section declares 0x18 bytes, of which 9 remain in the conditional data.
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 10D>5180 B1029KAC";
+    match Bcbp::from_str(PASS_STR) {
+        Err(Error::UnexpectedEndOfInput(failure)) => {
+            assert_eq!(failure.field(), Field::FieldSizeOfStructuredMessageUnique);
+            assert_eq!(failure.offset(), 62);
+            assert_eq!(failure.missing_len(), Some(15));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    // The security data declares 0x64 bytes, of which 3 are present.
+    const PASS_STR_SEC: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIW";
+    match Bcbp::from_str(PASS_STR_SEC) {
+        Err(Error::UnexpectedEndOfInput(failure)) => {
+            assert_eq!(failure.field(), Field::SecurityData);
+            assert_eq!(failure.missing_len(), Some(97));
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn zero_legs() {
+    // A boarding pass must contain at least one leg.
+    const PASS_STR: &str = "M0DESMARAIS/LUC       E";
+    assert_eq!(
+        Bcbp::from_str(PASS_STR).unwrap_err().to_string(),
+        "Item 5 (Number of Legs Encoded) at offset 1: invalid leg count '0'"
+    );
+}
+
+#[test]
+fn custom_error_is_unconditional() {
+    // The variant exists regardless of the enabled features, so exhaustive matches are stable.
+    let error = Error::Custom(String::from("missing field `Legs`"));
+    assert_eq!(error.to_string(), "missing field `Legs`");
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serialized_errors() {
+    assert_eq!(
+        serde_json::to_string(&Error::TrailingCharacters).unwrap(),
+        r#"{"kind":"TrailingCharacters"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&Error::LengthOverflow(Field::SeatNumber)).unwrap(),
+        r#"{"kind":"LengthOverflow","detail":"SeatNumber"}"#
     );
 }
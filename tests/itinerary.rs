@@ -0,0 +1,107 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the legs of multi-leg boarding passes as an itinerary.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+
+/// Returns the origin of each leg.
+fn origins(legs: &[&Leg]) -> Vec<String> {
+    legs.iter().map(|leg| String::from(leg.from_city_airport_code())).collect()
+}
+
+#[test]
+fn indexed_legs() {
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.leg_count(), 2);
+    assert_eq!(pass_data.leg(0), Some(pass_data.first_leg()));
+    assert_eq!(pass_data.leg(1).map(Leg::from_city_airport_code), Some("FRA"));
+    assert_eq!(pass_data.leg(2), None);
+}
+
+#[test]
+fn legs_sorted() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(origins(&pass_data.legs_sorted()), vec!["YUL", "FRA"]);
+
+    // Encoded out of order.
+    pass_data.legs_mut().swap(0, 1);
+    assert_eq!(origins(&pass_data.legs_sorted()), vec!["YUL", "FRA"]);
+
+    // A flight day before the day of issue falls in the following year.
+    pass_data.set_date_of_issue_of_boarding_pass(Some("6227")).unwrap();
+    pass_data.legs_mut()[1].set_date_of_flight("005").unwrap();
+    assert_eq!(origins(&pass_data.legs_sorted()), vec!["FRA", "YUL"]);
+}
+
+#[test]
+fn connections() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    {
+        let itinerary = pass_data.itinerary();
+        let connections = itinerary.connections();
+        assert_eq!(connections.len(), 1);
+        assert_eq!(connections[0].airport(), "FRA");
+        assert_eq!(connections[0].arriving().flight_number(), "0834 ");
+        assert_eq!(connections[0].departing().flight_number(), "3664 ");
+        assert_eq!(connections[0].day_gap(), Some(1));
+        assert!(!connections[0].is_suspicious());
+    }
+
+    // A gap of more than a day is flagged, including across the end of the year.
+    pass_data.set_date_of_issue_of_boarding_pass(Some("6360")).unwrap();
+    pass_data.legs_mut()[0].set_date_of_flight("363").unwrap();
+    pass_data.legs_mut()[1].set_date_of_flight("002").unwrap();
+    let connections = pass_data.itinerary().connections();
+    assert_eq!(connections[0].day_gap(), Some(4));
+    assert!(connections[0].is_suspicious());
+
+    // Legs which do not share an airport do not connect.
+    pass_data.legs_mut()[0].set_to_city_airport_code("MUC").unwrap();
+    assert!(pass_data.itinerary().connections().is_empty());
+}
+
+#[test]
+fn duplicate_legs() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert!(pass_data.duplicate_legs().is_empty());
+    assert_eq!(pass_data.dedup_legs(), 0);
+
+    // A reissued segment with a different sequence number is still a duplicate.
+    let mut reissued = pass_data.first_leg().clone();
+    reissued.set_check_in_sequence_number("0031").unwrap();
+    pass_data.push_leg(reissued).unwrap();
+    pass_data.push_leg(pass_data.legs()[1].clone()).unwrap();
+    assert_eq!(pass_data.duplicate_legs(), vec![(2, 0), (3, 1)]);
+
+    assert_eq!(pass_data.dedup_legs(), 2);
+    assert_eq!(pass_data.legs().len(), 2);
+    assert_eq!(pass_data.first_leg().check_in_sequence_number(), "0025 ");
+}
+
+#[test]
+fn codeshare() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert!(!pass_data.legs()[0].is_codeshare());
+    assert!(pass_data.legs()[1].is_codeshare());
+
+    // Padding is ignored, and the relationship is unknown if either carrier is not set.
+    let leg = &mut pass_data.legs_mut()[1];
+    leg.set_marketing_carrier_designator(Some("LH")).unwrap();
+    assert!(!leg.is_codeshare());
+    leg.set_marketing_carrier_designator(Some("   ")).unwrap();
+    assert!(!leg.is_codeshare());
+    leg.set_marketing_carrier_designator(None).unwrap();
+    assert!(!leg.is_codeshare());
+    leg.set_marketing_carrier_designator(Some("AC")).unwrap();
+    leg.set_operating_carrier_designator("   ").unwrap();
+    assert!(!leg.is_codeshare());
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the typed domain model.
+
+#![cfg(feature = "model")]
+
+extern crate iata_bcbp;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use iata_bcbp::model::*;
+use iata_bcbp::*;
+
+#[test]
+fn typed_values() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let boarding_pass = BoardingPass::from(&pass_data);
+
+    assert_eq!(boarding_pass.passenger_name.as_deref(), Some("MROZ/MARTIN"));
+    assert!(boarding_pass.electronic_ticket);
+    assert_eq!(boarding_pass.version_number, Some(2));
+    assert_eq!(boarding_pass.passenger_description, None);
+    assert_eq!(boarding_pass.source_of_check_in, Some(Source::ThirdPartyVendor));
+    assert_eq!(boarding_pass.date_of_issue, FlightDate::new(8, 207));
+    assert_eq!(boarding_pass.document_type, Some(DocumentType::BoardingPass));
+    assert_eq!(boarding_pass.issuer.as_deref(), Some("AS"));
+    assert_eq!(boarding_pass.baggage_tag_license_plate_numbers, None);
+
+    let flight = &boarding_pass.flights[0];
+    assert_eq!(flight.origin.as_deref(), Some("SJC"));
+    assert_eq!(flight.flight_number, Some(3317));
+    assert_eq!(flight.flight_number_suffix, None);
+    assert_eq!(flight.date_of_flight.map(|date| date.day_of_year()), Some(207));
+    assert_eq!(flight.resolved_date(boarding_pass.date_of_issue.unwrap()), FlightDate::new(8, 207));
+    assert_eq!(flight.seat.as_deref(), Some("1A"));
+    assert_eq!(flight.check_in_sequence_number, Some(6));
+    assert_eq!(flight.airline_numeric_code, Some(27));
+    assert_eq!(flight.frequent_flyer_number.as_deref(), Some("XXXXX55200000000"));
+    assert_eq!(flight.fast_track, None);
+}
+
+#[test]
+fn round_trip() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^100";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let boarding_pass = BoardingPass::from(&pass_data);
+    assert_eq!(boarding_pass.passenger_description, Some(PassengerDescription::Male));
+    assert_eq!(boarding_pass.baggage_tag_license_plate_numbers.as_deref(), Some("0014123456003"));
+    assert_eq!(boarding_pass.first_non_consecutive_baggage_tag_license_plate_numbers, None);
+
+    // A flight on day 227 of a pass issued on day 228 falls in the following year.
+    assert_eq!(boarding_pass.flights[1].resolved_date(FlightDate::new(6, 228).unwrap()), FlightDate::new(7, 227));
+
+    let converted_data = Bcbp::try_from(&boarding_pass).unwrap();
+    assert_eq!(to_string(&converted_data).unwrap(), PASS_STR);
+
+    // A boarding pass must have at least one flight.
+    assert!(Bcbp::try_from(&BoardingPass::default()).is_err());
+}
+
+#[test]
+fn baggage_tag_positions() {
+    // Only the second non-consecutive baggage tag is set, and keeps its position through the model.
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    pass_data.set_version_number(Some('6')).unwrap();
+    pass_data.set_second_non_consecutive_baggage_tag_license_plate_numbers(Some("0014123456003")).unwrap();
+
+    let boarding_pass = BoardingPass::from(&pass_data);
+    assert_eq!(boarding_pass.baggage_tag_license_plate_numbers, None);
+    assert_eq!(boarding_pass.second_non_consecutive_baggage_tag_license_plate_numbers.as_deref(), Some("0014123456003"));
+
+    let converted_data = Bcbp::try_from(&boarding_pass).unwrap();
+    assert_eq!(converted_data.baggage_tag_license_plate_numbers(), None);
+    assert_eq!(to_string(&converted_data).unwrap(), to_string(&pass_data).unwrap());
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering modification of parsed boarding pass data.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn set_and_re_encode() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    pass_data.set_passenger_name("DESMARAIS/LUCIE").unwrap();
+    assert_eq!(pass_data.passenger_name(), "DESMARAIS/LUCIE     ");
+
+    assert_eq!(
+        to_string(&pass_data).unwrap(),
+        "M1DESMARAIS/LUCIE     EABC123 YULFRAAC 0834 326J001A0025 100"
+    );
+}
+
+#[test]
+fn set_leg_fields() {
+    let mut leg = Leg::default();
+    leg.set_seat_number("012C").unwrap();
+    leg.set_from_city_airport_code("YUL").unwrap();
+    leg.set_fast_track(Some('Y')).unwrap();
+    assert_eq!(leg.seat_number(), "012C");
+    assert_eq!(leg.from_city_airport_code(), "YUL");
+    assert_eq!(leg.fast_track(), Some('Y'));
+
+    // Values are validated against the width and data format of the field.
+    assert_eq!(leg.set_seat_number("012AB"), Err(Error::LengthOverflow(Field::SeatNumber)));
+    assert_eq!(leg.set_from_city_airport_code("Y2L"), Err(Error::InvalidFieldValue(Field::FromCityAirportCode)));
+    assert_eq!(leg.set_date_of_flight("32A"), Err(Error::InvalidFieldValue(Field::DateOfFlight)));
+    assert_eq!(leg.set_operating_carrier_pnr_code("ABCç"), Err(Error::InvalidCharacters));
+    assert_eq!(leg.seat_number(), "012C");
+
+    leg.set_fast_track(None).unwrap();
+    assert_eq!(leg.fast_track(), None);
+
+    // Fields identified dynamically are set through the same setters.
+    leg.set_field(Field::SeatNumber, Some("014D")).unwrap();
+    leg.set_field(Field::FastTrack, Some("N")).unwrap();
+    assert_eq!(leg.field(Field::SeatNumber), Some("014D"));
+    assert_eq!(leg.fast_track(), Some('N'));
+    assert_eq!(leg.set_field(Field::FastTrack, Some("NY")), Err(Error::InvalidFieldValue(Field::FastTrack)));
+    assert_eq!(leg.set_field(Field::SeatNumber, None), Err(Error::MissingField(Field::SeatNumber)));
+    assert_eq!(leg.set_field(Field::PassengerName, Some("MROZ")), Err(Error::UnknownField));
+}
+
+#[test]
+fn flight_number_format() {
+    let mut leg = Leg::default();
+    leg.set_flight_number("0834").unwrap();
+    leg.set_flight_number("0834A").unwrap();
+    assert_eq!(leg.set_flight_number("08A4"), Err(Error::InvalidFieldValue(Field::FlightNumber)));
+    assert_eq!(leg.set_flight_number("08345"), Err(Error::InvalidFieldValue(Field::FlightNumber)));
+    assert_eq!(leg.flight_number(), "0834A");
+}
+
+#[test]
+fn seat_number_format() {
+    let mut leg = Leg::default();
+    leg.set_seat_number("001A").unwrap();
+    leg.set_seat_number("INF ").unwrap();
+    assert_eq!(leg.set_seat_number("1A"), Err(Error::InvalidFieldValue(Field::SeatNumber)));
+    assert_eq!(leg.set_seat_number("0011"), Err(Error::InvalidFieldValue(Field::SeatNumber)));
+    assert_eq!(leg.seat_number(), "INF ");
+}
+
+#[test]
+fn check_in_sequence_number_format() {
+    let mut leg = Leg::default();
+    leg.set_check_in_sequence_number("0025").unwrap();
+    leg.set_check_in_sequence_number("0025*").unwrap();
+    assert_eq!(leg.set_check_in_sequence_number("00A5"), Err(Error::InvalidFieldValue(Field::CheckInSequenceNumber)));
+    assert_eq!(leg.check_in_sequence_number(), "0025*");
+}
+
+#[test]
+fn typed_field_values() {
+    let origin = AirportCode::from_str("YUL").unwrap();
+    let carrier = CarrierDesignator::from_str("AC ").unwrap();
+    let pnr_code = PnrCode::from_str("ABC123").unwrap();
+    let date = JulianDate::from_str("012").unwrap();
+    assert_eq!(carrier.as_str(), "AC");
+    assert_eq!(carrier.to_string(), "AC ");
+    assert_eq!(pnr_code.to_string(), "ABC123 ");
+    assert_eq!(date.day_of_year(), 12);
+
+    let mut leg = Leg::default();
+    leg.set_from_city_airport_code(origin).unwrap();
+    leg.set_operating_carrier_designator(carrier).unwrap();
+    leg.set_operating_carrier_pnr_code(pnr_code).unwrap();
+    leg.set_date_of_flight(date).unwrap();
+    assert_eq!(leg.from_city_airport_code(), "YUL");
+    assert_eq!(leg.operating_carrier_designator(), "AC ");
+    assert_eq!(leg.operating_carrier_pnr_code(), "ABC123 ");
+    assert_eq!(leg.date_of_flight(), "012");
+
+    // Values are validated when parsed.
+    assert_eq!(AirportCode::from_str("yul"), Err(Error::InvalidFieldValue(Field::FromCityAirportCode)));
+    assert_eq!(AirportCode::from_str("YULX"), Err(Error::LengthOverflow(Field::FromCityAirportCode)));
+    assert_eq!(CarrierDesignator::from_str("12"), Err(Error::InvalidFieldValue(Field::OperatingCarrierDesignator)));
+    assert!(CarrierDesignator::from_str("U2").is_ok());
+    assert_eq!(PnrCode::from_str(""), Err(Error::InvalidFieldValue(Field::OperatingCarrierPnrCode)));
+    assert_eq!(JulianDate::from_str("367"), Err(Error::InvalidFieldValue(Field::DateOfFlight)));
+}
+
+#[test]
+fn restructure_legs() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    pass_data.legs_mut()[1].set_seat_number("012D").unwrap();
+    assert_eq!(pass_data.legs()[1].seat_number(), "012D");
+
+    // At most four legs may be encoded.
+    let extra_leg = pass_data.legs()[1].clone();
+    assert_eq!(pass_data.push_leg(extra_leg.clone()), Ok(()));
+    assert_eq!(pass_data.push_leg(extra_leg.clone()), Ok(()));
+    assert_eq!(pass_data.push_leg(extra_leg), Err(Error::LengthOverflow(Field::NumberOfLegsEncoded)));
+    assert_eq!(pass_data.legs().len(), 4);
+
+    let legs = pass_data.into_legs();
+    assert_eq!(legs.len(), 4);
+    assert_eq!(legs[0].from_city_airport_code(), "YUL");
+}
+
+#[test]
+fn default_has_first_leg() {
+    let pass_data = Bcbp::default();
+    assert_eq!(pass_data.legs().len(), 1);
+    assert_eq!(pass_data.first_leg(), &Leg::default());
+}
+
+#[test]
+fn first_leg_of_parsed_pass() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.first_leg(), &pass_data.legs()[0]);
+    assert_eq!(pass_data.first_leg().to_city_airport_code(), "FRA");
+}
+
+#[test]
+fn leg_from_str() {
+    const LEG_STR: &str = "DEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ";
+    let leg = Leg::from_str(LEG_STR).unwrap();
+    assert_eq!(leg.operating_carrier_pnr_code(), "DEF456 ");
+    assert_eq!(leg.from_city_airport_code(), "FRA");
+    assert_eq!(leg.to_city_airport_code(), "GVA");
+    assert_eq!(leg.seat_number(), "012C");
+    assert_eq!(leg.airline_numeric_code(), Some("014"));
+    assert_eq!(leg.free_baggage_allowance(), Some("2PC"));
+    assert_eq!(leg.fast_track(), Some('N'));
+    assert_eq!(leg.airline_individual_use(), Some("WQ"));
+
+    // The leg can be appended to an existing boarding pass.
+    let mut pass_data = Bcbp::from_str("M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 100").unwrap();
+    pass_data.push_leg(leg).unwrap();
+    assert_eq!(
+        to_string(&pass_data).unwrap(),
+        String::from("M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 100") + LEG_STR
+    );
+
+    assert_eq!(Leg::from_str("DEF456 FRAGVALH 3664 227C012C0002 100+"), Err(Error::TrailingCharacters));
+}
+
+#[test]
+fn normalized() {
+    const PASS_STR: &str = "M1Mroz/Martin         EXXXXXX yvryowAC 0344 211          072>20B0  8203IAC 250140000000000 0AC AC AC000000000     *20000AC 223                14080003068        0B          N";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let (normalized_data, changes) = pass_data.normalized();
+    assert_eq!(normalized_data.passenger_name(), "MROZ/MARTIN         ");
+    assert_eq!(normalized_data.first_leg().from_city_airport_code(), "YVR");
+    assert_eq!(changes.len(), 3);
+    assert_eq!(changes[0].field(), Field::PassengerName);
+    assert_eq!(changes[0].leg_index(), None);
+    assert_eq!(changes[0].original(), "Mroz/Martin         ");
+    assert_eq!(changes[0].normalized(), "MROZ/MARTIN         ");
+    assert_eq!(changes[2].field(), Field::ToCityAirportCode);
+    assert_eq!(changes[2].leg_index(), Some(0));
+
+    // A normalized pass is stable.
+    let (renormalized_data, changes) = normalized_data.normalized();
+    assert_eq!(renormalized_data, normalized_data);
+    assert!(changes.is_empty());
+
+    // Unset fields of a default pass are padded with spaces.
+    let (default_data, _) = Bcbp::default().normalized();
+    assert_eq!(default_data.passenger_name(), " ".repeat(20));
+    assert_eq!(default_data.first_leg().compartment_code(), ' ');
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn pseudonymous_id() {
+    use hmac::Hmac;
+    use sha2::Sha256;
+
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let id = pass_data.pseudonymous_id::<Hmac<Sha256>>(b"key");
+    assert_eq!(id.len(), 64);
+    assert!(!id.contains("MROZ"));
+
+    // The identifier depends on the key and the passenger, but not on the flight.
+    assert_ne!(pass_data.pseudonymous_id::<Hmac<Sha256>>(b"other key"), id);
+    let mut other_flight = pass_data.clone();
+    other_flight.legs_mut()[0].set_flight_number("0001").unwrap();
+    assert_eq!(other_flight.pseudonymous_id::<Hmac<Sha256>>(b"key"), id);
+    let mut other_passenger = pass_data.clone();
+    other_passenger.set_passenger_name("MROZ/MARTY").unwrap();
+    assert_ne!(other_passenger.pseudonymous_id::<Hmac<Sha256>>(b"key"), id);
+}
@@ -0,0 +1,49 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering parse outcome observation.
+
+extern crate iata_bcbp;
+
+use std::cell::RefCell;
+
+use iata_bcbp::*;
+
+/// Records a summary of each parse outcome.
+#[derive(Default)]
+struct Recorder {
+    outcomes: RefCell<Vec<String>>,
+}
+
+impl ParseObserver for Recorder {
+    fn parsed(&self, boarding_pass: &Bcbp) {
+        self.outcomes.borrow_mut().push(format!(
+            "version {:?}, {} legs, security data {}",
+            boarding_pass.version_number(),
+            boarding_pass.legs().len(),
+            boarding_pass.security_data().type_of_security_data().is_some()
+        ));
+    }
+
+    fn failed(&self, error: &Error) {
+        self.outcomes.borrow_mut().push(format!("{:?}", error));
+    }
+}
+
+#[test]
+fn observe_outcomes() {
+    let recorder = Recorder::default();
+
+    let pass_data = from_str_observed("M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^100", &recorder);
+    assert!(pass_data.is_ok());
+
+    let pass_data = from_str_observed("M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^100+", &recorder);
+    assert_eq!(pass_data, Err(Error::TrailingCharacters));
+
+    assert_eq!(*recorder.outcomes.borrow(), vec![
+        String::from("version None, 1 legs, security data true"),
+        String::from("TrailingCharacters"),
+    ]);
+}
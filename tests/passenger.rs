@@ -0,0 +1,56 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering passenger and leg state derived from several fields.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+
+#[test]
+fn infant_passenger() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert!(!pass_data.first_leg().is_infant_seating());
+    assert!(!pass_data.is_infant_passenger());
+
+    // An infant is identified by the Passenger Description.
+    pass_data.set_passenger_description(Some('4')).unwrap();
+    assert!(pass_data.is_infant_passenger());
+    pass_data.set_passenger_description(Some('6')).unwrap();
+    assert!(!pass_data.is_infant_passenger());
+
+    // Or by infant seating in any leg.
+    pass_data.legs_mut()[0].set_seat_number("inf").unwrap();
+    assert!(pass_data.first_leg().is_infant_seating());
+    assert!(pass_data.is_infant_passenger());
+}
+
+#[test]
+fn leg_state() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let leg = &mut pass_data.legs_mut()[0];
+    assert!(leg.has_seat_assigned());
+    assert!(leg.is_checked_in());
+    assert!(!leg.is_standby());
+    assert!(!leg.is_boarded());
+
+    leg.set_passenger_status('5').unwrap();
+    assert!(leg.is_checked_in());
+    assert!(leg.is_boarded());
+
+    // Standby passengers are not yet checked in and may not have a seat.
+    leg.set_passenger_status('7').unwrap();
+    leg.set_seat_number("    ").unwrap();
+    assert!(leg.is_standby());
+    assert!(!leg.is_checked_in());
+    assert!(!leg.has_seat_assigned());
+
+    leg.set_seat_number("INF ").unwrap();
+    assert!(!leg.has_seat_assigned());
+}
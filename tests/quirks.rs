@@ -0,0 +1,134 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering lenient parsing with airline-specific quirks.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn known_quirks_are_reported() {
+    const PASS_STR: &str = "M1Mroz/Martin         EXXXXXX YVRYOWAC 0344 211          072>20B0  8203IAC 250140000000000 0AC AC AC000000000     *20000AC 223                14080003068        0B          N";
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &Quirks::known()).unwrap();
+    assert_eq!(pass_data, Bcbp::from_str(PASS_STR).unwrap());
+    assert_eq!(applied, vec![Quirk::ItineraryReceiptDocumentType]);
+
+    // Quirks are only reported when registered for the carrier.
+    let (_, applied) = from_str_with_quirks(PASS_STR, &Quirks::new()).unwrap();
+    assert!(applied.is_empty());
+}
+
+#[test]
+fn missing_version_marker() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 10F0B0WW6225BAC 00";
+    assert!(Bcbp::from_str(PASS_STR).is_err());
+
+    // Tolerated only for the carrier for which the quirk is registered.
+    let quirks = Quirks::new().with_quirk(Some("LH"), Quirk::MissingVersionMarker);
+    assert!(from_str_with_quirks(PASS_STR, &quirks).is_err());
+
+    let quirks = Quirks::new().with_quirk(Some("AC"), Quirk::MissingVersionMarker);
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &quirks).unwrap();
+    assert_eq!(applied, vec![Quirk::MissingVersionMarker]);
+    assert_eq!(pass_data.version_number(), None);
+    assert_eq!(pass_data.source_of_check_in(), Some('W'));
+    assert_eq!(pass_data.date_of_issue_of_boarding_pass(), Some("6225"));
+    assert_eq!(pass_data.airline_designator_of_boarding_pass_issuer(), Some("AC "));
+}
+
+#[test]
+fn non_standard_check_in_sequence_number() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001AA025 100";
+    let quirks = Quirks::new().with_quirk(None, Quirk::NonStandardCheckInSequenceNumber);
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &quirks).unwrap();
+    assert_eq!(pass_data.first_leg().check_in_sequence_number(), "A025 ");
+    assert_eq!(applied, vec![Quirk::NonStandardCheckInSequenceNumber]);
+}
+
+#[test]
+fn truncated_security_data() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW";
+    assert!(Bcbp::from_str(PASS_STR).is_err());
+
+    let quirks = Quirks::new().with_quirk(None, Quirk::TruncatedSecurityData);
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &quirks).unwrap();
+    assert_eq!(applied, vec![Quirk::TruncatedSecurityData]);
+    assert_eq!(pass_data.first_leg().from_city_airport_code(), "YUL");
+    assert_eq!(pass_data.security_data().declared_len(), Some(100));
+    assert_eq!(pass_data.security_data().actual_len(), Some(61));
+    assert_eq!(pass_data.security_data().security_data(), Some("GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW"));
+    assert!(pass_data.security_data().is_truncated());
+    assert!(!pass_data.security_data().is_length_consistent());
+
+    // Complete security data is not reported.
+    const COMPLETE_PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1045GIW";
+    let (pass_data, applied) = from_str_with_quirks(COMPLETE_PASS_STR, &quirks).unwrap();
+    assert!(applied.is_empty());
+    assert!(!pass_data.security_data().is_truncated());
+}
+
+#[test]
+fn four_character_airport_codes() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 CYULEDDFAC 0834 326J001A0025 100";
+    assert!(Bcbp::from_str(PASS_STR).is_err());
+
+    let quirks = Quirks::new().with_quirk(Some("AC"), Quirk::FourCharacterAirportCodes);
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &quirks).unwrap();
+    assert_eq!(applied, vec![Quirk::FourCharacterAirportCodes]);
+    assert_eq!(pass_data.first_leg().from_city_airport_code(), "CYUL");
+    assert_eq!(pass_data.first_leg().to_city_airport_code(), "EDDF");
+    assert_eq!(pass_data.first_leg().operating_carrier_designator(), "AC ");
+    assert_eq!(pass_data.first_leg().flight_number(), "0834 ");
+    assert_eq!(pass_data.first_leg().date_of_flight(), "326");
+
+    // Four-character codes do not fit the three-character fields of a standard pass.
+    let overflow = Error::LengthOverflow(Field::FromCityAirportCode);
+    assert_eq!(to_string(&pass_data), Err(overflow.clone()));
+    assert_eq!(pass_data.encoded_len(), Err(overflow));
+    let errors = to_string_checked(&pass_data).unwrap_err();
+    assert_eq!(errors.iter().map(BuildError::field).collect::<Vec<_>>(), vec![Field::FromCityAirportCode, Field::ToCityAirportCode]);
+
+    // Standard three-character codes are unaffected.
+    const STANDARD_PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let (pass_data, applied) = from_str_with_quirks(STANDARD_PASS_STR, &quirks).unwrap();
+    assert!(applied.is_empty());
+    assert_eq!(pass_data.first_leg().from_city_airport_code(), "YUL");
+}
+
+#[test]
+fn missing_legs() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^1045GIW";
+    assert!(Bcbp::from_str(PASS_STR).is_err());
+
+    let quirks = Quirks::new().with_quirk(Some("AC"), Quirk::MissingLegs);
+    let (pass_data, applied) = from_str_with_quirks(PASS_STR, &quirks).unwrap();
+    assert_eq!(applied, vec![Quirk::MissingLegs]);
+    assert_eq!(pass_data.leg_count(), 1);
+    assert_eq!(pass_data.declared_leg_count(), 2);
+    assert_eq!(pass_data.security_data().security_data(), Some("5GIW"));
+
+    // The declared count no longer applies once legs are added or removed.
+    let mut pushed = pass_data.clone();
+    pushed.push_leg(pass_data.first_leg().clone()).unwrap();
+    assert_eq!(pushed.declared_leg_count(), 2);
+    assert_eq!(pushed.leg_count(), 2);
+    assert_eq!(pushed.dedup_legs(), 1);
+    assert_eq!(pushed.declared_leg_count(), 1);
+
+    let mut pushed = pass_data.clone();
+    pushed.push_leg(Leg::default()).unwrap();
+    pushed.push_leg(Leg::default()).unwrap();
+    assert_eq!(pushed.declared_leg_count(), 3);
+
+    // Legs which are present but malformed are not tolerated.
+    let malformed = PASS_STR.replace("^1045GIW", "DEF456 FRAGVA");
+    assert!(from_str_with_quirks(&malformed, &quirks).is_err());
+
+    let pass_data = Bcbp::from_str("M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100").unwrap();
+    assert_eq!(pass_data.declared_leg_count(), pass_data.leg_count());
+}
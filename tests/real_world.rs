@@ -55,6 +55,16 @@ fn alaska_boarding_pass() {
         assert_eq!(first_leg.fast_track(), None);
         assert_eq!(first_leg.airline_individual_use(), Some("Z29  00010"));
     }
+
+    assert!(!pass_data.issuance_metadata().is_empty());
+    assert_eq!(pass_data.issuance_metadata().version_number(), Some('2'));
+    assert_eq!(pass_data.issuance_metadata().date_of_issue_of_boarding_pass(), Some("8207"));
+
+    assert_eq!(pass_data.security_data().type_of_security_data(), None);
+    assert_eq!(pass_data.security_data().declared_len(), None);
+    assert_eq!(pass_data.security_data().actual_len(), None);
+    assert_eq!(pass_data.security_data().signed_range(), None);
+    assert!(pass_data.security_data().is_length_consistent());
 }
 
 #[test]
@@ -89,6 +99,13 @@ fn air_canada_boarding_pass() {
         assert_eq!(first_leg.check_in_sequence_number(), "     ");
         assert_eq!(first_leg.passenger_status(), '0');
 
+        // Blank mandatory fields are not set.
+        assert_eq!(first_leg.compartment_code_opt(), None);
+        assert_eq!(first_leg.seat_number_opt(), None);
+        assert_eq!(first_leg.check_in_sequence_number_opt(), None);
+        assert_eq!(first_leg.flight_number_opt(), Some("0344 "));
+        assert_eq!(first_leg.passenger_status_opt(), Some('0'));
+
         assert_eq!(first_leg.airline_numeric_code(), Some("014"));
         assert_eq!(first_leg.document_form_serial_number(), Some("0000000000"));
         assert_eq!(first_leg.selectee_indicator(), Some(' '));
@@ -102,3 +119,38 @@ fn air_canada_boarding_pass() {
         assert_eq!(first_leg.airline_individual_use(), Some("*20000AC 223                14080003068        0B          N"));
     }
 }
+
+#[test]
+fn concatenated_passes() {
+    const PASS_STR_1: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    const PASS_STR_2: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    const PASS_STR_3: &str = "M1TEST/PETER          E24Z5RN AMSBRUKL 1733 019M008A0001 316>503  W0D0742497067621";
+
+    let input = format!("{}{}\n{}", PASS_STR_1, PASS_STR_2, PASS_STR_3);
+    assert_eq!(split_passes(&input), vec![PASS_STR_1, PASS_STR_2, PASS_STR_3]);
+
+    // Splitting ends at an incomplete pass.
+    let input = format!("{}{}", PASS_STR_2, &PASS_STR_3[.. 40]);
+    assert_eq!(split_passes(&input), vec![PASS_STR_2]);
+    assert!(split_passes("").is_empty());
+}
+
+#[test]
+fn from_reader() {
+    const PASS_STR: &str = "M1MROZ/MARTIN         EXXXXXX SJCLAXAS 3317 207U001A0006 34D>218 VV8207BAS              2502771980993865 AS AS XXXXX55200000000Z29  00010";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(Bcbp::from_reader(PASS_STR.as_bytes()).unwrap(), pass_data);
+
+    // A single line terminator, as appended by scanners, is accepted.
+    assert_eq!(Bcbp::from_reader(format!("{}\r\n", PASS_STR).as_bytes()).unwrap(), pass_data);
+    let error = Bcbp::from_reader(format!("{}\n\n", PASS_STR).as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.get_ref().unwrap().downcast_ref::<Error>().is_some());
+
+    // Input longer than any boarding pass is not read in its entirety.
+    let input = format!("{}{}", PASS_STR, " ".repeat(1 << 20));
+    let mut reader = input.as_bytes();
+    let error = Bcbp::from_reader(&mut reader).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(!reader.is_empty());
+}
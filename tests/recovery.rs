@@ -0,0 +1,113 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering recovery from legs which fail to parse.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+
+#[test]
+fn valid_pass_is_complete() {
+    let partial = from_str_recovering(PASS_STR).unwrap();
+    assert!(partial.is_complete());
+    assert_eq!(partial.legs().len(), 2);
+    assert_eq!(partial.to_bcbp(), Some(Bcbp::from_str(PASS_STR).unwrap()));
+}
+
+#[test]
+fn failed_second_leg_is_isolated() {
+    let input = PASS_STR.replace("12E2A014", "12EZZ014");
+    assert!(Bcbp::from_str(&input).is_err());
+
+    let partial = from_str_recovering(&input).unwrap();
+    assert!(!partial.is_complete());
+    assert_eq!(partial.number_of_legs_encoded(), 2);
+    assert_eq!(partial.legs().len(), 2);
+    assert!(partial.legs()[0].is_ok());
+
+    let (leg_error, raw) = match &partial.legs()[1] {
+        ParsedLeg::Failed { error, raw } => (error, raw),
+        ParsedLeg::Ok(leg) => panic!("unexpected leg {:?}", leg),
+    };
+    assert_eq!(raw, "DEF456 FRAGVALH 3664 227C012C0002 12EZZ0140987654321 1AC AC 1234567890123    2PCNWQ");
+    assert_eq!(leg_error.leg_index(), 1);
+    match leg_error.error() {
+        Error::ParseFailed(failure) => {
+            assert_eq!(failure.field(), Field::FieldSizeOfStructuredMessageRepeated);
+            assert_eq!(failure.offset(), input.find("ZZ014").unwrap());
+        }
+        error => panic!("unexpected error {:?}", error),
+    }
+    assert_eq!(partial.leg_errors().count(), 1);
+
+    // The security data following the failed leg is still recovered.
+    assert_eq!(partial.trailing_error(), None);
+    assert_eq!(partial.security_data().type_of_security_data(), Some('1'));
+    assert_eq!(partial.security_data().signed_range(), Some(0 .. input.find('^').unwrap()));
+
+    let pass_data = partial.to_bcbp().unwrap();
+    assert_eq!(pass_data.legs().len(), 1);
+    assert_eq!(pass_data.legs()[0].to_city_airport_code(), "FRA");
+    assert_eq!(pass_data.version_number(), Some('6'));
+}
+
+#[test]
+fn failed_first_leg_loses_unique_conditional_items() {
+    let input = PASS_STR.replace("0032A014", "003ZZ014");
+
+    let partial = from_str_recovering(&input).unwrap();
+    assert_eq!(partial.legs()[0].error().map(LegError::leg_index), Some(0));
+    assert_eq!(partial.legs()[1].leg().map(Leg::from_city_airport_code), Some("FRA"));
+    assert_eq!(partial.field(Field::PassengerName), Some("DESMARAIS/LUC       "));
+    assert_eq!(partial.field(Field::VersionNumber), None);
+
+    let pass_data = partial.to_bcbp().unwrap();
+    assert_eq!(pass_data.legs().len(), 1);
+    assert_eq!(pass_data.legs()[0].to_city_airport_code(), "GVA");
+}
+
+#[test]
+fn undelimited_leg_ends_recovery() {
+    let input = PASS_STR.replace(" 14D>6", " 1ZD>6");
+
+    let partial = from_str_recovering(&input).unwrap();
+    assert_eq!(partial.number_of_legs_encoded(), 2);
+    assert_eq!(partial.legs().len(), 1);
+    match &partial.legs()[0] {
+        ParsedLeg::Failed { raw, .. } => assert_eq!(raw, &input["M2DESMARAIS/LUC       E".len() ..]),
+        ParsedLeg::Ok(leg) => panic!("unexpected leg {:?}", leg),
+    }
+    assert_eq!(partial.trailing_error(), None);
+    assert_eq!(partial.security_data().type_of_security_data(), None);
+    assert_eq!(partial.to_bcbp(), None);
+}
+
+#[test]
+fn trailing_data_is_reported() {
+    let input = format!("{}XYZ", PASS_STR);
+    let partial = from_str_recovering(&input).unwrap();
+    assert!(partial.legs().iter().all(|leg| leg.is_ok()));
+    assert_eq!(partial.trailing_error(), Some(&Error::TrailingCharacters));
+    assert!(!partial.is_complete());
+
+    assert_eq!(from_str_recovering("X1DESMARAIS/LUC").unwrap_err(), Error::UnsupportedFormat);
+    assert!(from_str_recovering("M0DESMARAIS/LUC").is_err());
+}
+
+#[test]
+fn dropped_leg_discards_signed_payload() {
+    let input = PASS_STR.replace("12E2A014", "12EZZ014");
+
+    let pass_data = from_str_recovering(&input).unwrap().to_bcbp().unwrap();
+    assert_eq!(pass_data.legs().len(), 1);
+    assert_eq!(pass_data.security_data().type_of_security_data(), Some('1'));
+    assert_eq!(pass_data.security_data().signed_range(), None);
+    assert_eq!(pass_data.security_signed_payload(), None);
+}
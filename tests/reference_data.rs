@@ -0,0 +1,159 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the bundled reference data.
+
+#![cfg(feature = "reference_data")]
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::reference_data::{self, AirlineInfo, AirportInfo};
+use iata_bcbp::*;
+
+#[test]
+fn airport_info() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let leg = &pass_data.legs()[0];
+
+    let from = leg.from_airport_info().unwrap();
+    let to = leg.to_airport_info().unwrap();
+    assert_eq!(format!("{} → {}", from, to), "Montréal (YUL) → Frankfurt (FRA)");
+    assert_eq!(from.code(), "YUL");
+    assert_eq!(from.country(), "Canada");
+    assert_eq!(to.country(), "Germany");
+
+    assert_eq!(AirportInfo::lookup("yul "), Some(from));
+    assert_eq!(AirportInfo::lookup("XXX"), None);
+    assert_eq!(AirportInfo::lookup(""), None);
+}
+
+#[test]
+fn airports_are_sorted_and_unique() {
+    let airports = AirportInfo::all();
+    assert!(airports.windows(2).all(|pair| pair[0].code() < pair[1].code()));
+    for airport in airports {
+        assert_eq!(AirportInfo::lookup(airport.code()), Some(airport));
+        assert!(airport.code().len() == 3 && airport.code().bytes().all(|b| b.is_ascii_uppercase()));
+    }
+}
+
+#[test]
+fn airline_info() {
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.legs()[0].operating_carrier_name(), Some("Air Canada"));
+    assert_eq!(pass_data.legs()[1].operating_carrier_name(), Some("Lufthansa"));
+    assert_eq!(pass_data.legs()[1].marketing_carrier_name(), Some("Air Canada"));
+
+    let lufthansa = AirlineInfo::lookup("DLH").unwrap();
+    assert_eq!(AirlineInfo::lookup("lh "), Some(lufthansa));
+    assert_eq!(lufthansa.iata_designator(), "LH");
+    assert_eq!(lufthansa.to_string(), "Lufthansa");
+    assert_eq!(lufthansa.numeric_code(), "220");
+    assert_eq!(AirlineInfo::lookup_numeric_code("014").map(AirlineInfo::iata_designator), Some("AC"));
+    assert_eq!(AirlineInfo::lookup_numeric_code("000"), None);
+    assert_eq!(AirlineInfo::lookup("ZZ"), None);
+    assert_eq!(AirlineInfo::lookup("A"), None);
+    assert!(!reference_data::DATA_VINTAGE.is_empty());
+}
+
+#[test]
+fn airlines_are_sorted_and_unique() {
+    let airlines = AirlineInfo::all();
+    assert!(airlines.windows(2).all(|pair| pair[0].iata_designator() < pair[1].iata_designator()));
+    for airline in airlines {
+        assert_eq!(AirlineInfo::lookup(airline.iata_designator()), Some(airline));
+        assert_eq!(AirlineInfo::lookup(airline.icao_designator()), Some(airline));
+        assert_eq!(AirlineInfo::lookup_numeric_code(airline.numeric_code()), Some(airline));
+        assert!(airline.numeric_code().len() == 3 && airline.numeric_code().bytes().all(|b| b.is_ascii_digit()));
+    }
+}
+
+#[test]
+fn ticket_number_carrier() {
+    // The Air Canada ticket covers the flight operated by Lufthansa and marketed by Air Canada.
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 12E2A0140987654321 1AC AC 1234567890123    2PCNWQ";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.legs()[0].validate_ticket_carrier(), Ok(()));
+    assert_eq!(pass_data.legs()[1].validate_ticket_carrier(), Ok(()));
+
+    // A numeric code listed for a third airline is only rejected by the separate carrier check.
+    let leg = &mut pass_data.legs_mut()[1];
+    leg.set_marketing_carrier_designator(Some("LH")).unwrap();
+    assert_eq!(leg.validate_ticket_carrier(), Err(Error::InvalidFieldValue(Field::AirlineNumericCode)));
+    assert_eq!(leg.validate_ticket_number(true), Ok(()));
+
+    // Numeric codes which are not listed cannot be checked.
+    leg.set_airline_numeric_code(Some("000")).unwrap();
+    assert_eq!(leg.validate_ticket_carrier(), Ok(()));
+}
+
+#[cfg(feature = "time")]
+fn utc(year: i32, month: time::Month, day: u8, hour: u8, minute: u8) -> time::OffsetDateTime {
+    time::Date::from_calendar_date(year, month, day).unwrap().with_hms(hour, minute, 0).unwrap().assume_utc()
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn airport_utc_offset() {
+    use time::Month::*;
+
+    let offset_hours = |code: &str, instant| AirportInfo::lookup(code).unwrap().utc_offset_at(instant).whole_hours();
+
+    // Montréal advances at 02:00 local time on the second Sunday of March and returns on the first Sunday of November.
+    assert_eq!(offset_hours("YUL", utc(2024, March, 10, 6, 59)), -5);
+    assert_eq!(offset_hours("YUL", utc(2024, March, 10, 7, 0)), -4);
+    assert_eq!(offset_hours("YUL", utc(2024, November, 3, 5, 59)), -4);
+    assert_eq!(offset_hours("YUL", utc(2024, November, 3, 6, 0)), -5);
+
+    // Frankfurt changes at 01:00 UTC on the last Sundays of March and October.
+    assert_eq!(offset_hours("FRA", utc(2024, March, 31, 0, 59)), 1);
+    assert_eq!(offset_hours("FRA", utc(2024, March, 31, 1, 0)), 2);
+    assert_eq!(offset_hours("FRA", utc(2024, October, 27, 1, 0)), 1);
+
+    // Sydney observes daylight saving time over the southern summer, and Phoenix not at all.
+    assert_eq!(offset_hours("SYD", utc(2024, January, 15, 12, 0)), 11);
+    assert_eq!(offset_hours("SYD", utc(2024, April, 6, 16, 0)), 10);
+    assert_eq!(offset_hours("SYD", utc(2024, October, 5, 16, 0)), 11);
+    assert_eq!(offset_hours("PHX", utc(2024, July, 1, 12, 0)), -7);
+
+    // Offsets follow the rules in effect at the instant, such as daylight saving time in Istanbul until 2016.
+    assert_eq!(AirportInfo::lookup("IST").unwrap().time_zone(), "Europe/Istanbul");
+    assert_eq!(offset_hours("IST", utc(2015, January, 15, 12, 0)), 2);
+    assert_eq!(offset_hours("IST", utc(2024, January, 15, 12, 0)), 3);
+
+    // Every airport listed names a time zone known to the database.
+    for airport in AirportInfo::all() {
+        airport.utc_offset_at(utc(2024, January, 1, 0, 0));
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn departure_local_date() {
+    use time::Month::*;
+
+    // Day 326 of 2024 is November 21, which has ended in UTC but not yet in Montréal.
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let leg = pass_data.first_leg();
+    let now = utc(2024, November, 22, 3, 0);
+    assert_eq!(leg.departure_local_date(now), time::Date::from_calendar_date(2024, November, 21).ok());
+    assert_eq!(leg.flight_date_local(now), time::Date::from_calendar_date(2024, November, 21).ok());
+    assert!(leg.is_departure_today(now));
+    assert!(!leg.is_departure_today(utc(2024, November, 22, 6, 0)));
+
+    // The year is that of the nearest date falling on the day of the flight.
+    assert_eq!(leg.flight_date_local(utc(2025, January, 2, 12, 0)), time::Date::from_calendar_date(2024, November, 21).ok());
+
+    // Airports which are not listed have no known local date.
+    let mut other_leg = leg.clone();
+    other_leg.set_from_city_airport_code("XXX").unwrap();
+    assert_eq!(other_leg.departure_local_date(now), None);
+    assert!(!other_leg.is_departure_today(now));
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the security screening items of a leg.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+
+#[test]
+fn screening_summary() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let summary = pass_data.first_leg().screening_summary();
+    assert_eq!(summary.selectee_indicator(), Some(SelecteeIndicator::NotSelectee));
+    assert_eq!(summary.international_document_verification(), None);
+    assert_eq!(summary.fast_track(), None);
+    assert!(!summary.requires_additional_screening());
+
+    let leg = &mut pass_data.legs_mut()[0];
+    leg.set_selectee_indicator(Some('1')).unwrap();
+    leg.set_international_document_verification(Some('2')).unwrap();
+    leg.set_fast_track(Some('Y')).unwrap();
+    let summary = pass_data.first_leg().screening_summary();
+    assert_eq!(summary.selectee_indicator(), Some(SelecteeIndicator::Selectee));
+    assert_eq!(summary.international_document_verification(), Some(DocumentVerification::Completed));
+    assert_eq!(summary.fast_track(), Some(true));
+    assert!(summary.requires_additional_screening());
+
+    assert_eq!(SelecteeIndicator::from('5'), SelecteeIndicator::Other('5'));
+    assert_eq!(char::from(SelecteeIndicator::KnownPassenger), '3');
+}
+
+#[test]
+fn document_verification() {
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.first_leg().document_verification(), None);
+
+    for &(code, verification) in &[
+        ('0', DocumentVerification::NotRequired),
+        ('1', DocumentVerification::Required),
+        ('2', DocumentVerification::Completed),
+        ('X', DocumentVerification::Other('X')),
+    ] {
+        pass_data.legs_mut()[0].set_international_document_verification(Some(code)).unwrap();
+        assert_eq!(pass_data.first_leg().document_verification(), Some(verification));
+        assert_eq!(char::from(verification), code);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering security data verification.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+/// Accepts a single known signature over a single known payload.
+struct ExpectedSignature {
+    type_of_security_data: char,
+    signed_payload: &'static str,
+    signature: &'static str,
+}
+
+impl SecuritySignatureVerifier for ExpectedSignature {
+    fn verify(&self, type_of_security_data: char, signed_payload: &[u8], signature: &str) -> bool {
+        type_of_security_data == self.type_of_security_data &&
+        signed_payload == self.signed_payload.as_bytes() &&
+        signature == self.signature
+    }
+}
+
+#[test]
+fn verify_security_data() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^108SIGNATUR";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    let verifier = ExpectedSignature {
+        type_of_security_data: '1',
+        signed_payload: "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100",
+        signature: "SIGNATUR",
+    };
+    assert_eq!(pass_data.verify_security_data(&verifier), Ok(()));
+
+    let wrong_signature = ExpectedSignature { signature: "SIGNATUS", ..verifier };
+    assert_eq!(pass_data.verify_security_data(&wrong_signature), Err(Error::InvalidSecurityData));
+}
+
+#[test]
+fn verify_missing_security_data() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    let verifier = ExpectedSignature {
+        type_of_security_data: '1',
+        signed_payload: PASS_STR,
+        signature: "",
+    };
+    assert_eq!(pass_data.verify_security_data(&verifier), Err(Error::MissingSecurityData));
+}
+
+#[test]
+fn replace_security_data() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^108SIGNATUR";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    let mut unsigned_pass_data = pass_data.without_security_data();
+    assert_eq!(unsigned_pass_data.security_data().type_of_security_data(), None);
+    assert_eq!(unsigned_pass_data.security_data().security_data(), None);
+    assert_eq!(to_string(&unsigned_pass_data).unwrap(), "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100");
+
+    unsigned_pass_data.set_security_data('2', "RESIGNED").unwrap();
+    assert_eq!(unsigned_pass_data.security_data().declared_len(), Some(8));
+    assert_eq!(to_string(&unsigned_pass_data).unwrap(), "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^208RESIGNED");
+
+    // The replaced security data verifies against the encoded boarding pass data.
+    let verifier = ExpectedSignature {
+        type_of_security_data: '2',
+        signed_payload: "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100",
+        signature: "RESIGNED",
+    };
+    assert_eq!(unsigned_pass_data.verify_security_data(&verifier), Ok(()));
+
+    assert_eq!(unsigned_pass_data.set_security_data('2', "SIGNATURç"), Err(Error::InvalidCharacters));
+}
+
+#[test]
+fn security_signed_payload() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGBN8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.security_signed_payload(), Some("M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100"));
+    assert_eq!(pass_data.security_signed_payload().map(str::len), pass_data.security_data().signed_range().map(|range| range.end));
+
+    // Lower-case data is covered as it appeared, without normalization.
+    let lower_case = Bcbp::from_str(&PASS_STR.replacen("DESMARAIS/LUC", "Desmarais/Luc", 1)).unwrap();
+    assert_eq!(lower_case.security_signed_payload(), Some("M1Desmarais/Luc       EABC123 YULFRAAC 0834 326J001A0025 100"));
+
+    pass_data.set_passenger_name("DESMARAIS/LUCIE").unwrap();
+    assert_eq!(pass_data.security_signed_payload(), None);
+
+    let unsigned = Bcbp::from_str(&PASS_STR[.. 60]).unwrap();
+    assert_eq!(unsigned.security_signed_payload(), None);
+}
+
+#[test]
+fn equality_ignores_signed_payload() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100^108SIGNATUR";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+
+    let mut modified = pass_data.clone();
+    modified.legs_mut();
+    assert_eq!(modified.security_signed_payload(), None);
+    assert_eq!(modified, pass_data);
+
+    let fixed = BcbpFixed::<1>::parse(PASS_STR).unwrap().to_bcbp();
+    assert_eq!(fixed, pass_data);
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering the public test vector corpus.
+
+#![cfg(feature = "test_vectors")]
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::test_vectors::{self, Source};
+use iata_bcbp::*;
+
+#[test]
+fn all_vectors_parse_to_expected_values() {
+    for test_vector in test_vectors::all() {
+        let pass_data = Bcbp::from_str(test_vector.input()).unwrap();
+        assert!(test_vector.is_matched_by(&pass_data), "{}", test_vector.name());
+        assert_eq!(to_string(&pass_data).unwrap(), test_vector.input(), "{}", test_vector.name());
+    }
+
+    let sources: Vec<Source> = test_vectors::all().iter().map(|test_vector| test_vector.source()).collect();
+    assert_eq!(sources.iter().filter(|&&source| source == Source::AttachmentB).count(), 6);
+    assert_eq!(sources.iter().filter(|&&source| source == Source::RealWorld).count(), 2);
+}
+
+#[test]
+fn mismatches_are_reported() {
+    let test_vector = &test_vectors::all()[1];
+    assert_eq!(test_vector.leg_count(), 2);
+
+    let mut pass_data = Bcbp::from_str(test_vector.input()).unwrap();
+    pass_data.legs_mut()[1].set_seat_number("014A").unwrap();
+
+    let mismatches = test_vector.mismatches(&pass_data);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].field(), Field::SeatNumber);
+    assert_eq!(mismatches[0].leg_index(), Some(1));
+    assert_eq!(mismatches[0].value(), "012C");
+    assert_eq!(mismatches[0].actual(&pass_data), Some("014A"));
+    assert!(!test_vector.is_matched_by(&pass_data));
+}
+
+#[test]
+fn real_world_vectors_are_redacted() {
+    for test_vector in test_vectors::all().iter().filter(|test_vector| test_vector.source() == Source::RealWorld) {
+        let pass_data = Bcbp::from_str(test_vector.input()).unwrap();
+        assert!(pass_data.passenger_name().to_uppercase().starts_with("DOE/JANE"));
+    }
+}
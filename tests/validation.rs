@@ -0,0 +1,172 @@
+// Copyright (C) 2019 Martin Mroz
+//
+// This software may be modified and distributed under the terms
+// of the MIT license.  See the LICENSE file for details.
+
+//! Test cases covering validation of parsed boarding pass data.
+
+extern crate iata_bcbp;
+
+use std::str::FromStr;
+
+use iata_bcbp::*;
+
+#[test]
+fn ticket_number() {
+    const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.first_leg().validate_ticket_number(true), Ok(()));
+
+    let pass_data = Bcbp::from_str(&PASS_STR.replace("2364224106", "23642241X6")).unwrap();
+    assert_eq!(
+        pass_data.first_leg().validate_ticket_number(false),
+        Err(Error::InvalidFieldValue(Field::DocumentFormSerialNumber))
+    );
+
+    // Blank fields are accepted as not set unless strict.
+    let mut leg = Leg::default();
+    assert_eq!(leg.validate_ticket_number(true), Ok(()));
+    leg.set_airline_numeric_code(Some("   ")).unwrap();
+    assert_eq!(leg.validate_ticket_number(false), Ok(()));
+    assert_eq!(leg.validate_ticket_number(true), Err(Error::InvalidFieldValue(Field::AirlineNumericCode)));
+    leg.set_airline_numeric_code(Some("2 0")).unwrap();
+    assert_eq!(leg.validate_ticket_number(false), Err(Error::InvalidFieldValue(Field::AirlineNumericCode)));
+}
+
+#[test]
+fn validation_profiles() {
+    const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert!(pass_data.validate(&ValidationProfile::V5).findings().is_empty());
+
+    // The Selectee Indicator is required beginning with version 6.
+    pass_data.legs_mut()[0].set_selectee_indicator(Some(' ')).unwrap();
+    assert!(pass_data.validate(&ValidationProfile::V5).findings().is_empty());
+    let report = pass_data.validate(&ValidationProfile::V6);
+    let findings = report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].field(), Field::SelecteeIndicator);
+    assert_eq!(findings[0].leg_index(), Some(0));
+    assert_eq!(findings[0].kind(), FindingKind::NotSet);
+    assert_eq!(findings[0].since(), ValidationProfile::V6);
+
+    // Fast Track is not defined before version 5.
+    pass_data.legs_mut()[0].set_fast_track(Some('Y')).unwrap();
+    let report = pass_data.validate(&ValidationProfile::V3);
+    let findings = report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].field(), Field::FastTrack);
+    assert_eq!(findings[0].kind(), FindingKind::NotDefinedInVersion);
+
+    let pass_data = Bcbp::from_str(&PASS_STR.replace("FRARLG", "FR4RLG")).unwrap();
+    let report = pass_data.validate(&ValidationProfile::V2);
+    let findings = report.findings();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].field(), Field::FromCityAirportCode);
+    assert_eq!(findings[0].kind(), FindingKind::InvalidFormat);
+
+    assert_eq!(ValidationProfile::from_version_number('6'), Some(ValidationProfile::V6));
+    assert_eq!(ValidationProfile::from_version_number('4'), None);
+}
+
+#[test]
+fn validation_report() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YUL2RAAC 0834 326J001A0025 100";
+    let report = Bcbp::from_str(PASS_STR).unwrap().validate(&ValidationProfile::V6);
+    assert_eq!(report.profile(), ValidationProfile::V6);
+    assert!(!report.is_conformant());
+
+    let messages: Vec<String> = report.findings().iter().map(Finding::message).collect();
+    assert_eq!(messages, vec![
+        "Item 9 (Version Number): not set, required since version 2",
+        "Item 18 (Selectee Indicator) in leg 1: not set, required since version 6",
+        "Item 38 (To City Airport Code) in leg 1: expected 'a'",
+    ]);
+    assert_eq!(report.findings()[0].severity(), Severity::Error);
+    assert_eq!(report.findings()[1].severity(), Severity::Warning);
+    assert_eq!(report.findings()[2].item_number(), 38);
+}
+
+#[cfg(all(feature = "serde", feature = "serde_json"))]
+#[test]
+fn serialized_report() {
+    const PASS_STR: &str = "M1DESMARAIS/LUC       EABC123 YUL2RAAC 0834 326J001A0025 100";
+    let report = Bcbp::from_str(PASS_STR).unwrap().validate(&ValidationProfile::V2);
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        concat!(
+            r#"{"profile":"V2","findings":["#,
+            r#"{"field":"VersionNumber","item_number":9,"leg_index":null,"kind":"NotSet","severity":"Error","#,
+            r#""since":"V2","message":"Item 9 (Version Number): not set, required since version 2"},"#,
+            r#"{"field":"ToCityAirportCode","item_number":38,"leg_index":0,"kind":"InvalidFormat","severity":"Error","#,
+            r#""since":"V2","message":"Item 38 (To City Airport Code) in leg 1: expected 'a'"}]}"#,
+        )
+    );
+}
+
+#[test]
+fn unrecognized_version() {
+    const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>A180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.version_number(), Some('A'));
+    assert_eq!(pass_data.source_of_check_in(), Some('W'));
+
+    let report = pass_data.validate(&ValidationProfile::V5);
+    assert!(report.is_conformant());
+    assert_eq!(report.findings().len(), 1);
+    assert_eq!(report.findings()[0].kind(), FindingKind::UnrecognizedVersion);
+    assert_eq!(report.findings()[0].severity(), Severity::Warning);
+    assert_eq!(report.findings()[0].message(), "Item 9 (Version Number): not a recognized version");
+}
+
+#[test]
+fn declared_version() {
+    const PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let mut pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    let report = pass_data.validate_declared_version().unwrap();
+    assert_eq!(report.profile(), ValidationProfile::V2);
+    assert!(report.findings().is_empty());
+
+    // A pass mislabeled as version 2 containing Fast Track is reported.
+    pass_data.legs_mut()[0].set_fast_track(Some('Y')).unwrap();
+    let report = pass_data.validate_declared_version().unwrap();
+    assert_eq!(report.findings().len(), 1);
+    assert_eq!(report.findings()[0].kind(), FindingKind::NotDefinedInVersion);
+
+    // Version 4 is validated against version 3.
+    pass_data.set_version_number(Some('4')).unwrap();
+    assert_eq!(pass_data.validate_declared_version().unwrap().profile(), ValidationProfile::V3);
+
+    // A pass labeled as version 6 must set the Selectee Indicator.
+    pass_data.set_version_number(Some('6')).unwrap();
+    pass_data.legs_mut()[0].set_selectee_indicator(Some(' ')).unwrap();
+    let report = pass_data.validate_declared_version().unwrap();
+    assert_eq!(report.findings().len(), 1);
+    assert_eq!(report.findings()[0].field(), Field::SelecteeIndicator);
+
+    for version_number in &[None, Some(' '), Some('1'), Some('A')] {
+        pass_data.set_version_number(*version_number).unwrap();
+        assert_eq!(pass_data.validate_declared_version(), None);
+    }
+}
+
+#[test]
+fn mis_sized_structured_message() {
+    // The second leg declares an empty structured message, which then appears as airline individual use.
+    const PASS_STR: &str = "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 226F001A0025 14D>6181WW6225BAC 00141234560032A0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 227C012C0002 130002A0140987654321 1AC AC 1234567890123    2PCNWQ";
+    let pass_data = Bcbp::from_str(PASS_STR).unwrap();
+    assert_eq!(pass_data.legs()[1].airline_numeric_code(), None);
+
+    let report = pass_data.validate(&ValidationProfile::V6);
+    assert!(report.is_conformant());
+    let finding = report.findings().last().unwrap();
+    assert_eq!(finding.field(), Field::FieldSizeOfStructuredMessageRepeated);
+    assert_eq!(finding.leg_index(), Some(1));
+    assert_eq!(finding.kind(), FindingKind::MisSizedStructuredMessage);
+    assert_eq!(finding.severity(), Severity::Warning);
+
+    // Airline individual use which is not shaped like a structured message is not reported.
+    const STAR_ALLIANCE_PASS_STR: &str = "M1TEST/HIDDEN         E8OQ6FU FRARLGLH 4010 012C004D0001 35C>2180WW6012BLH              2922023642241060 LH                        *30600000K09         ";
+    let pass_data = Bcbp::from_str(STAR_ALLIANCE_PASS_STR).unwrap();
+    assert!(pass_data.validate(&ValidationProfile::V6).findings().is_empty());
+}